@@ -35,6 +35,14 @@ pub fn derive_presenter_holder(input: TokenStream) -> TokenStream {
                 &self.presenter
             }
         }
+
+        impl #impl_generics #struct_name #ty_generics #where_clause {
+            /// Presents with the given presenter instead of the default one
+            pub(crate) fn with_presenter(mut self, presenter: #presenter_type) -> Self {
+                self.presenter = presenter;
+                self
+            }
+        }
     };
 
     TokenStream::from(expanded)
@@ -72,6 +80,14 @@ pub fn derive_storage_holder(input: TokenStream) -> TokenStream {
                 &self.storage
             }
         }
+
+        impl #impl_generics #struct_name #ty_generics #where_clause {
+            /// Works on the given storage instead of the default one
+            pub(crate) fn with_storage(mut self, storage: #storage_type) -> Self {
+                self.storage = storage;
+                self
+            }
+        }
     };
 
     TokenStream::from(expanded)
@@ -1,18 +1,47 @@
+use std::env;
 use std::str::FromStr;
 use clap::{Parser, Subcommand};
 use crate::adapters::editors::os_default_editor::OsDefaultEditor;
+use crate::adapters::identities::environment::EnvironmentIdentity;
+use crate::adapters::openers::os_default_opener::OsDefaultOpener;
 use crate::adapters::presenters::stdoutrenderer::TabularTextRenderer;
-use crate::adapters::storages::FileStorage;
+use crate::adapters::presenters::styles::CategoryStyles;
+use crate::adapters::storages::{FileBoardRegistry, FileStorage};
 use crate::adapters::time_providers::simple::SimpleTimeProvider;
+use crate::application::domain::custom_field::FieldAssignment;
+use crate::application::domain::filter::IssueFilter;
+use crate::application::domain::lanes::LaneGrouping;
+use crate::application::issue::{Assignee, IssueRef, Priority, ShortId, Tag};
 use crate::application::State;
-use crate::application::usecase::add::AddUseCase;
+use crate::application::domain::board_name::BoardName;
+use crate::application::usecase::add::{AddUseCase, NewIssue};
+use crate::application::usecase::archive::ArchiveUseCase;
+use crate::application::usecase::assign::AssignUseCase;
+use crate::application::usecase::boards::{BoardsAction, BoardsUseCase};
+use crate::application::usecase::checklist::{ChecklistChange, ChecklistUseCase};
 use crate::application::usecase::delete::DeleteUseCase;
+use crate::application::usecase::dependency::{DependencyAction, DependencyUseCase};
 use crate::application::usecase::due::DueUseCase;
 use crate::application::usecase::edit::EditUseCase;
+use crate::application::usecase::estimate::EstimateUseCase;
 use crate::application::usecase::get::GetUseCase;
+use crate::application::usecase::link::LinkUseCase;
+use crate::application::usecase::open::OpenUseCase;
+use crate::application::usecase::block::{BlockAction, BlockUseCase};
+use crate::application::usecase::parent::ParentUseCase;
+use crate::application::usecase::field::FieldUseCase;
 use crate::application::usecase::flush::FlushUseCase;
 use crate::application::usecase::prio::{BottomPriority, DownPriority, PriorityUseCase, TopPriority, UpPriority};
+use crate::application::usecase::priority_level::PriorityLevelUseCase;
 use crate::application::usecase::r#move::MoveUseCase;
+use crate::application::usecase::comment::CommentUseCase;
+use crate::application::usecase::note::NoteUseCase;
+use crate::application::usecase::recur::RecurUseCase;
+use crate::application::usecase::report::ReportUseCase;
+use crate::application::usecase::show::ShowUseCase;
+use crate::application::usecase::tag::{TagChange, TagUseCase};
+use crate::application::usecase::track::{TrackAction, TrackUseCase};
+use crate::application::usecase::transfer::TransferUseCase;
 use crate::application::usecase::undo::UndoUseCase;
 
 /// Kanban issue tracking. It manages your tasks and quickly gets out of the way.
@@ -21,64 +50,212 @@ use crate::application::usecase::undo::UndoUseCase;
 pub(crate) struct RootCli {
     #[clap(subcommand)]
     pub(crate) command: Option<Command>,
+
+    /// Only show issues that have the tag. Can be given multiple times.
+    #[arg(short, long = "tag")]
+    pub(crate) tags: Vec<Tag>,
+
+    /// The board to work on. Defaults to the default board, see `ka boards default`
+    // clap would prefer `From<&str>` over `FromStr`, which validates the name
+    #[arg(short, long, global = true, value_parser = BoardName::from_str)]
+    pub(crate) board: Option<BoardName>,
+
+    /// Only show issues assigned to you. Your name is taken from the KANBAN_USER environment variable
+    #[arg(long)]
+    pub(crate) mine: bool,
+
+    /// Also show the issues that wait for a later date
+    #[arg(long)]
+    pub(crate) all: bool,
+
+    /// Only show issues with the value of a custom field, e.g. `customer=acme`, or without a value
+    /// with `customer=`. Can be given multiple times.
+    #[arg(long = "field")]
+    pub(crate) fields: Vec<FieldAssignment>,
+
+    /// Split the columns into lanes by tag, assignee, priority, due date (`due`) or a custom field
+    /// (`field:<name>`). The columns are the states already, so there are no lanes by state.
+    /// Issues keep their indexes
+    #[arg(long)]
+    pub(crate) lanes: Option<LaneGrouping>,
 }
 
 impl RootCli {
     pub(crate) fn execute(self) {
+        if let Some(board) = &self.board {
+            if !BoardsUseCase::<FileBoardRegistry, TabularTextRenderer<SimpleTimeProvider>>::default().can_select(board) {
+                return;
+            }
+        }
+
+        let registry = FileBoardRegistry {
+            selected: self.board.clone(),
+            ..Default::default()
+        };
+        let storage = registry.active_storage();
+        let styles = CategoryStyles::default().with_overrides(&env::var("KANBAN_STYLES").unwrap_or_default());
+        let presenter = TabularTextRenderer::<SimpleTimeProvider>::default().with_styles(styles);
+
         match self.command {
-            Some(Command::Add{description, state, due}) => {
-                AddUseCase::<FileStorage, TabularTextRenderer<SimpleTimeProvider>, SimpleTimeProvider>::default().execute(
-                    &description,
-                    state.unwrap_or(State::Open),
-                    due);
+            Some(Command::Add{description, template: Some(template), force, ..}) => {
+                AddUseCase::<FileStorage, TabularTextRenderer<SimpleTimeProvider>, SimpleTimeProvider>::default().with_storage(storage).with_presenter(presenter).execute_template(&template, &description, force);
+            },
+            Some(Command::Add{description, state, due, wait, tags, estimate, assignee, priority, template: None, force}) => {
+                AddUseCase::<FileStorage, TabularTextRenderer<SimpleTimeProvider>, SimpleTimeProvider>::default().with_storage(storage).with_presenter(presenter).execute(NewIssue {
+                    description,
+                    state,
+                    due_date: due,
+                    wait_until: wait,
+                    tags,
+                    estimate,
+                    assignee,
+                    priority,
+                }, force);
             },
             Some(Command::Delete{index}) => {
-                DeleteUseCase::<FileStorage, TabularTextRenderer<SimpleTimeProvider>>::default().execute(&index);
+                DeleteUseCase::<FileStorage, TabularTextRenderer<SimpleTimeProvider>>::default().with_storage(storage).with_presenter(presenter).execute(&index);
             },
-            Some(Command::Move{indices, state}) => {
-                MoveUseCase::<FileStorage, TabularTextRenderer<SimpleTimeProvider>>::default().execute(&indices, state);
+            Some(Command::Move{indices, state, force}) => {
+                MoveUseCase::<FileStorage, TabularTextRenderer<SimpleTimeProvider>, SimpleTimeProvider>::default().with_storage(storage).with_presenter(presenter).execute(&indices, state, force);
             },
             Some(Command::Edit{index}) => {
-                EditUseCase::<FileStorage, TabularTextRenderer<SimpleTimeProvider>, OsDefaultEditor>::default().execute(index);
+                EditUseCase::<FileStorage, TabularTextRenderer<SimpleTimeProvider>, OsDefaultEditor, SimpleTimeProvider>::default().with_storage(storage).with_presenter(presenter).execute(index);
             },
             Some(Command::Prio{
                      command: PrioCommand::Top,
                      index
                  }) => {
-                PriorityUseCase::<FileStorage, TabularTextRenderer<SimpleTimeProvider>, TopPriority>::default().execute(index);
+                PriorityUseCase::<FileStorage, TabularTextRenderer<SimpleTimeProvider>, TopPriority>::default().with_storage(storage).with_presenter(presenter).execute(index);
             },
             Some(Command::Prio{
                      command: PrioCommand::Bottom,
                      index
                  }) => {
-                PriorityUseCase::<FileStorage, TabularTextRenderer<SimpleTimeProvider>, BottomPriority>::default().execute(index);
+                PriorityUseCase::<FileStorage, TabularTextRenderer<SimpleTimeProvider>, BottomPriority>::default().with_storage(storage).with_presenter(presenter).execute(index);
             },
             Some(Command::Prio{
                      command: PrioCommand::Up,
                      index
                  }) => {
-                PriorityUseCase::<FileStorage, TabularTextRenderer<SimpleTimeProvider>, UpPriority>::default().execute(index);
+                PriorityUseCase::<FileStorage, TabularTextRenderer<SimpleTimeProvider>, UpPriority>::default().with_storage(storage).with_presenter(presenter).execute(index);
             },
             Some(Command::Prio{
                      command: PrioCommand::Down,
                      index
                  }) => {
-                PriorityUseCase::<FileStorage, TabularTextRenderer<SimpleTimeProvider>, DownPriority>::default().execute(index);
+                PriorityUseCase::<FileStorage, TabularTextRenderer<SimpleTimeProvider>, DownPriority>::default().with_storage(storage).with_presenter(presenter).execute(index);
             },
             Some(Command::Undo) => {
-                UndoUseCase::<FileStorage, TabularTextRenderer<SimpleTimeProvider>>::default().execute();
+                UndoUseCase::<FileStorage, TabularTextRenderer<SimpleTimeProvider>, FileBoardRegistry, SimpleTimeProvider>::default().with_storage(storage).with_presenter(presenter).execute();
             },
             Some(Command::Flush) => {
-                FlushUseCase::<FileStorage, TabularTextRenderer<SimpleTimeProvider>>::default().execute();
+                FlushUseCase::<FileStorage, TabularTextRenderer<SimpleTimeProvider>>::default().with_storage(storage).with_presenter(presenter).execute();
             },
             Some(Command::Due {
                      index,
                      date
                  }) => {
-                DueUseCase::<FileStorage, TabularTextRenderer<SimpleTimeProvider>, SimpleTimeProvider>::default().execute(index, date.as_deref());
+                DueUseCase::<FileStorage, TabularTextRenderer<SimpleTimeProvider>, SimpleTimeProvider>::default().with_storage(storage).with_presenter(presenter).execute(index, date.as_deref());
+            }
+            Some(Command::Tag {
+                     index,
+                     changes
+                 }) => {
+                TagUseCase::<FileStorage, TabularTextRenderer<SimpleTimeProvider>>::default().with_storage(storage).with_presenter(presenter).execute(index, &changes);
+            }
+            Some(Command::Check {
+                     index,
+                     command
+                 }) => {
+                ChecklistUseCase::<FileStorage, TabularTextRenderer<SimpleTimeProvider>>::default().with_storage(storage).with_presenter(presenter).execute(index, &command.into());
+            }
+            Some(Command::Depend {
+                     index,
+                     blockers,
+                     remove
+                 }) => {
+                let action = if remove { DependencyAction::Remove } else { DependencyAction::Add };
+                DependencyUseCase::<FileStorage, TabularTextRenderer<SimpleTimeProvider>>::default().with_storage(storage).with_presenter(presenter).execute(index, &blockers, action);
+            }
+            Some(Command::Note {
+                     index,
+                     text
+                 }) => {
+                NoteUseCase::<FileStorage, TabularTextRenderer<SimpleTimeProvider>, OsDefaultEditor, SimpleTimeProvider>::default().with_storage(storage).with_presenter(presenter).execute(index, text.as_deref());
+            }
+            Some(Command::Show { index }) => {
+                ShowUseCase::<FileStorage, TabularTextRenderer<SimpleTimeProvider>>::default().with_storage(storage).with_presenter(presenter).execute(index);
+            }
+            Some(Command::Link { index, target, label }) => {
+                LinkUseCase::<FileStorage, TabularTextRenderer<SimpleTimeProvider>>::default().with_storage(storage).with_presenter(presenter).execute(index, &target, label.as_deref());
+            }
+            Some(Command::Open { index, number }) => {
+                OpenUseCase::<FileStorage, TabularTextRenderer<SimpleTimeProvider>, OsDefaultOpener>::default().with_storage(storage).with_presenter(presenter).execute(index, number);
+            }
+            Some(Command::Block { index, reason }) => {
+                BlockUseCase::<FileStorage, TabularTextRenderer<SimpleTimeProvider>, SimpleTimeProvider>::default().with_storage(storage).with_presenter(presenter).execute(index, BlockAction::Block(reason));
+            }
+            Some(Command::Unblock { index }) => {
+                BlockUseCase::<FileStorage, TabularTextRenderer<SimpleTimeProvider>, SimpleTimeProvider>::default().with_storage(storage).with_presenter(presenter).execute(index, BlockAction::Unblock);
+            }
+            Some(Command::Parent { index, parent }) => {
+                ParentUseCase::<FileStorage, TabularTextRenderer<SimpleTimeProvider>>::default().with_storage(storage).with_presenter(presenter).execute(index, parent);
+            }
+            Some(Command::Children { index }) => {
+                ParentUseCase::<FileStorage, TabularTextRenderer<SimpleTimeProvider>>::default().with_storage(storage).with_presenter(presenter).children(index);
+            }
+            Some(Command::Set { index, fields }) => {
+                FieldUseCase::<FileStorage, TabularTextRenderer<SimpleTimeProvider>, SimpleTimeProvider>::default().with_storage(storage).with_presenter(presenter).execute(index, &fields);
+            }
+            Some(Command::Comment { index, text }) => {
+                CommentUseCase::<FileStorage, TabularTextRenderer<SimpleTimeProvider>, SimpleTimeProvider>::default().with_storage(storage).with_presenter(presenter).execute(index, &text);
+            }
+            Some(Command::Recur { index, rule }) => {
+                RecurUseCase::<FileStorage, TabularTextRenderer<SimpleTimeProvider>>::default().with_storage(storage).with_presenter(presenter).execute(index, rule.as_deref());
+            }
+            Some(Command::Estimate { index, estimate }) => {
+                EstimateUseCase::<FileStorage, TabularTextRenderer<SimpleTimeProvider>>::default().with_storage(storage).with_presenter(presenter).execute(index, estimate);
+            }
+            Some(Command::Start { index, move_forward }) => {
+                TrackUseCase::<FileStorage, TabularTextRenderer<SimpleTimeProvider>, SimpleTimeProvider>::default().with_storage(storage).with_presenter(presenter).execute(TrackAction::Start {
+                    issue: index,
+                    move_forward,
+                });
+            }
+            Some(Command::Stop) => {
+                TrackUseCase::<FileStorage, TabularTextRenderer<SimpleTimeProvider>, SimpleTimeProvider>::default().with_storage(storage).with_presenter(presenter).execute(TrackAction::Stop);
+            }
+            Some(Command::Report) => {
+                ReportUseCase::<FileStorage, TabularTextRenderer<SimpleTimeProvider>, SimpleTimeProvider>::default().with_storage(storage).with_presenter(presenter).execute();
+            }
+            Some(Command::Boards { command }) => {
+                BoardsUseCase::<FileBoardRegistry, TabularTextRenderer<SimpleTimeProvider>>::default().with_presenter(presenter).execute(
+                    command.map(BoardsAction::from).unwrap_or(BoardsAction::List));
+            }
+            Some(Command::Assign { index, assignee }) => {
+                AssignUseCase::<FileStorage, TabularTextRenderer<SimpleTimeProvider>>::default().with_storage(storage).with_presenter(presenter).execute(index, assignee);
+            }
+            Some(Command::Priority { index, priority }) => {
+                PriorityLevelUseCase::<FileStorage, TabularTextRenderer<SimpleTimeProvider>>::default().with_storage(storage).with_presenter(presenter).execute(index, priority);
+            }
+            Some(Command::Archive { indices, older_than }) => {
+                ArchiveUseCase::<FileStorage, TabularTextRenderer<SimpleTimeProvider>, SimpleTimeProvider>::default().with_storage(storage).with_presenter(presenter).execute(&indices, older_than);
+            }
+            Some(Command::Unarchive { index }) => {
+                ArchiveUseCase::<FileStorage, TabularTextRenderer<SimpleTimeProvider>, SimpleTimeProvider>::default().with_storage(storage).with_presenter(presenter).unarchive(index);
+            }
+            Some(Command::Archived { text }) => {
+                ArchiveUseCase::<FileStorage, TabularTextRenderer<SimpleTimeProvider>, SimpleTimeProvider>::default().with_storage(storage).with_presenter(presenter).search(text.as_deref());
+            }
+            Some(Command::Transfer { index, to }) => {
+                TransferUseCase::<FileStorage, TabularTextRenderer<SimpleTimeProvider>, FileBoardRegistry, SimpleTimeProvider>::default().with_storage(storage).with_presenter(presenter).with_registry(registry).execute(index, &to);
             }
             None => {
-                GetUseCase::<FileStorage, TabularTextRenderer<SimpleTimeProvider>>::default().execute()
+                GetUseCase::<FileStorage, TabularTextRenderer<SimpleTimeProvider>, EnvironmentIdentity, SimpleTimeProvider>::default().with_storage(storage).with_presenter(presenter).execute(&IssueFilter {
+                    tags: self.tags,
+                    fields: self.fields,
+                    ..Default::default()
+                }, self.lanes, self.mine, self.all)
             },
         }
     }
@@ -88,41 +265,76 @@ impl RootCli {
 pub(crate) enum Command {
     /// Add new issue
     Add {
-        /// A text that describes the issue
+        /// A text that describes the issue. With a template, the text that replaces `{}` in the
+        /// descriptions of the template
         description: String,
 
-        /// Initial state of the ticket
+        /// Initial state (column) of the ticket. Defaults to the first column of the board
         state: Option<State>,
 
         /// Assign due date for the new issue
         #[arg(short, long)]
         due: Option<String>,
+
+        /// Hide the new issue from the board until this date
+        #[arg(short, long)]
+        wait: Option<String>,
+
+        /// Tag to attach to the new issue. Can be given multiple times.
+        #[arg(short, long = "tag")]
+        tags: Vec<Tag>,
+
+        /// Estimated size of the new issue (e.g. in story points)
+        #[arg(short, long)]
+        estimate: Option<u32>,
+
+        /// The person who owns the new issue
+        #[arg(short, long, value_parser = Assignee::from_str)]
+        assignee: Option<Assignee>,
+
+        /// Priority level of the new issue: critical, high, normal or low
+        #[arg(short, long, default_value = "normal")]
+        priority: Priority,
+
+        /// Add the issues of a template of the board file instead of a single issue
+        #[arg(long, conflicts_with_all = ["state", "due", "wait", "tags", "estimate", "assignee", "priority"])]
+        template: Option<String>,
+
+        /// Add the issue even if the column is at its WIP limit
+        #[arg(short, long)]
+        force: bool,
     },
-    /// Deletes an issue. This makes the indexes reassigned!
+    /// Deletes an issue. This makes the indexes reassigned, but `@id` short ids stay the same!
     Delete {
-        // Index of the issue to delete
-        index: Vec<usize>,
+        // Index (or `@id`) of the issue to delete
+        index: Vec<IssueRef>,
     },
     /// Move issue to a new state
     Move {
-        // New state to apply to the issue
+        // New state (column) to apply to the issue
         state: State,
 
-        // One or multiple indices of issues that you move
-        indices: Vec<usize>,
+        // One or multiple indices (or `@id`s) of issues that you move
+        indices: Vec<IssueRef>,
+
+        /// Move the issues even if the column would exceed its WIP limit, or the move is not among
+        /// the allowed transitions of the board
+        #[arg(short, long)]
+        force: bool,
     },
-    /// Edit the description of an issue with $EDITOR (defaults to vim)
+    /// Edit the issue with $EDITOR (defaults to vim). The first line is the description, the rest
+    /// is the body of the issue
     Edit {
-        // Index of the issue to edit
-        index: usize,
+        // Index (or `@id`) of the issue to edit
+        index: IssueRef,
     },
     /// Change priority (order) of issues
     Prio {
         /// Action (and direction) to take on the issue
         command: PrioCommand,
 
-        /// Index of the issue to be moved
-        index: usize,
+        /// Index (or `@id`) of the issue to be moved
+        index: IssueRef,
     },
     /// Undo last action that changed the state
     Undo,
@@ -131,13 +343,302 @@ pub(crate) enum Command {
 
     /// Set a due date for an already existing issue.
     Due {
-        /// Index of the issue for which due date is to be set.
-        index: usize,
+        /// Index (or `@id`) of the issue for which due date is to be set.
+        index: IssueRef,
 
         /// Date in the format of `yyyy-mm-dd`  or `mm--dd` or `dd` or "tomorrow" or "tom" or
         ///
         /// You can also use "m" "tu", "w", "th", "f", "sa", "su" for the next occurrence of that weekday (excluding today).
         date: Option<String>,
+    },
+
+    /// Add or remove tags of an issue
+    Tag {
+        /// Index (or `@id`) of the issue to be tagged
+        index: IssueRef,
+
+        /// `+tag` (or `tag`) adds a tag, `-tag` removes it
+        #[arg(required = true, allow_hyphen_values = true)]
+        changes: Vec<TagChange>,
+    },
+
+    /// Manage the checklist of an issue
+    Check {
+        /// Index (or `@id`) of the issue
+        index: IssueRef,
+
+        #[command(subcommand)]
+        command: ChecklistCommand,
+    },
+
+    /// Record that an issue is blocked by other issues. A blocked issue cannot be moved to done
+    /// until its blockers are done.
+    Depend {
+        /// Index (or `@id`) of the blocked issue
+        index: IssueRef,
+
+        /// Indices (or `@id`s) of the issues that block it
+        #[arg(required = true)]
+        blockers: Vec<IssueRef>,
+
+        /// Remove the dependencies instead of adding them
+        #[arg(short, long)]
+        remove: bool,
+    },
+
+    /// Set the body (multi-line notes) of an issue
+    Note {
+        /// Index (or `@id`) of the issue
+        index: IssueRef,
+
+        /// The new body. If not given, the body is edited with $EDITOR. An empty text removes the body
+        text: Option<String>,
+    },
+
+    /// Show every detail of an issue, including its body and activity log
+    Show {
+        /// Index (or `@id`) of the issue
+        index: IssueRef,
+    },
+
+    /// Add a link (a URL or a file path) to an issue. The board shows the number of links
+    Link {
+        /// Index (or `@id`) of the issue
+        index: IssueRef,
+
+        /// URL or file path
+        target: String,
+
+        /// Short name of the link, e.g. PR
+        #[arg(short, long)]
+        label: Option<String>,
+    },
+
+    /// Open a link of an issue with the default application of the system
+    Open {
+        /// Index (or `@id`) of the issue
+        index: IssueRef,
+
+        /// Number of the link, as listed by `show`. Defaults to the first link
+        number: Option<usize>,
+    },
+
+    /// Flag an issue as blocked, e.g. because it waits on someone else. Blocked issues are never overdue
+    Block {
+        /// Index (or `@id`) of the issue
+        index: IssueRef,
+
+        /// Why the issue is blocked, shown by `show`
+        reason: String,
+    },
+
+    /// Remove the blocked flag of an issue
+    Unblock {
+        /// Index (or `@id`) of the issue
+        index: IssueRef,
+    },
+
+    /// Make an issue a child of another issue, e.g. an epic. Without a parent, the issue is
+    /// detached from its parent
+    Parent {
+        /// Index (or `@id`) of the child issue
+        index: IssueRef,
+
+        /// Index (or `@id`) of the parent issue
+        parent: Option<IssueRef>,
+    },
+
+    /// Show the children of an issue
+    Children {
+        /// Index (or `@id`) of the parent issue
+        index: IssueRef,
+    },
+
+    /// Set custom fields of an issue, e.g. `customer=acme`. The fields are declared in the board file
+    Set {
+        /// Index (or `@id`) of the issue
+        index: IssueRef,
+
+        /// Values of the fields, as `name=value`. `name=` unsets the field
+        #[arg(required = true)]
+        fields: Vec<FieldAssignment>,
+    },
+
+    /// Add a comment to the activity log of an issue
+    Comment {
+        /// Index (or `@id`) of the issue
+        index: IssueRef,
+
+        /// Text of the comment
+        text: String,
+    },
+
+    /// Make an issue recurring. When it is moved to done, its next occurrence is added with a new due date
+    Recur {
+        /// Index (or `@id`) of the issue
+        index: IssueRef,
+
+        /// "every monday", "every 3 days", "every 2 weeks" or "monthly on 1". If not given, the issue stops recurring
+        rule: Option<String>,
+    },
+
+    /// Set the estimate of an issue. Headers of the columns show the total of their estimates
+    Estimate {
+        /// Index (or `@id`) of the issue
+        index: IssueRef,
+
+        /// Estimated size (e.g. in story points). If not given, the estimate is removed
+        estimate: Option<u32>,
+    },
+
+    /// Start tracking the time worked on an issue. The issue tracked before is stopped
+    Start {
+        /// Index (or `@id`) of the issue
+        index: IssueRef,
+
+        /// Move the issue to the next column if it is in the first one
+        #[arg(short = 'm', long = "move")]
+        move_forward: bool,
+    },
+
+    /// Stop tracking the time of the tracked issue
+    Stop,
+
+    /// Show the tracked time per issue and per day
+    Report,
+
+    /// Assign an issue to a person. The board shows the initials of the assignee
+    Assign {
+        /// Index (or `@id`) of the issue
+        index: IssueRef,
+
+        /// Name of the person. If not given, the issue becomes unassigned
+        #[arg(value_parser = Assignee::from_str)]
+        assignee: Option<Assignee>,
+    },
+
+    /// Set the priority level of an issue: critical, high, normal or low. Unlike `prio`, it does
+    /// not change the order of the issues
+    Priority {
+        /// Index (or `@id`) of the issue
+        index: IssueRef,
+
+        priority: Priority,
+    },
+
+    /// Move issues out of the board to its archive. Without indices, the done issues are archived
+    Archive {
+        /// Indices (or `@id`s) of the issues to archive
+        indices: Vec<IssueRef>,
+
+        /// Only archive the done issues that have been done for at least this many days. Defaults
+        /// to `archiveAfterDays` of the board
+        #[arg(long, conflicts_with = "indices")]
+        older_than: Option<u32>,
+    },
+
+    /// Move an archived issue back to the top of its column
+    Unarchive {
+        /// Index in the archive (see `ka archived`), or `@id` of the issue
+        index: IssueRef,
+    },
+
+    /// List the archived issues
+    Archived {
+        /// Only list the issues that contain the text in their description, body or tags
+        text: Option<String>,
+    },
+
+    /// Manage the boards. Without a subcommand, the boards are listed
+    Boards {
+        #[command(subcommand)]
+        command: Option<BoardsCommand>,
+    },
+
+    /// Move an issue to another board. It gets a new `@id` there
+    Transfer {
+        /// Index (or `@id`) of the issue
+        index: IssueRef,
+
+        /// Name of the board to move the issue to
+        #[arg(long, value_parser = BoardName::from_str)]
+        to: BoardName,
+    },
+}
+
+#[derive(Subcommand, Clone)]
+pub(crate) enum BoardsCommand {
+    /// List the boards
+    List,
+    /// Create a new, empty board
+    Create {
+        #[arg(value_parser = BoardName::from_str)]
+        name: BoardName,
+    },
+    /// Rename a board
+    Rename {
+        #[arg(value_parser = BoardName::from_str)]
+        from: BoardName,
+        #[arg(value_parser = BoardName::from_str)]
+        to: BoardName,
+    },
+    /// Delete a board with its issues and history. This cannot be undone
+    Delete {
+        #[arg(value_parser = BoardName::from_str)]
+        name: BoardName,
+
+        /// Delete the board even if it still has issues
+        #[arg(short, long)]
+        force: bool,
+    },
+    /// Use the board when no board is given with `--board`
+    Default {
+        #[arg(value_parser = BoardName::from_str)]
+        name: BoardName,
+    },
+}
+
+impl From<BoardsCommand> for BoardsAction {
+    fn from(command: BoardsCommand) -> Self {
+        match command {
+            BoardsCommand::List => BoardsAction::List,
+            BoardsCommand::Create { name } => BoardsAction::Create(name),
+            BoardsCommand::Rename { from, to } => BoardsAction::Rename { from, to },
+            BoardsCommand::Delete { name, force } => BoardsAction::Delete { name, force },
+            BoardsCommand::Default { name } => BoardsAction::SetDefault(name),
+        }
+    }
+}
+
+#[derive(Subcommand, Clone)]
+pub(crate) enum ChecklistCommand {
+    /// Add a new item to the end of the checklist
+    Add {
+        /// A text that describes the item
+        text: String,
+    },
+    /// Check off an item. Items are numbered from 1
+    Done {
+        item: usize,
+    },
+    /// Uncheck an item
+    Undone {
+        item: usize,
+    },
+    /// Remove an item from the checklist
+    Remove {
+        item: usize,
+    },
+}
+
+impl From<ChecklistCommand> for ChecklistChange {
+    fn from(command: ChecklistCommand) -> Self {
+        match command {
+            ChecklistCommand::Add { text } => ChecklistChange::Add(text),
+            ChecklistCommand::Done { item } => ChecklistChange::Done(item),
+            ChecklistCommand::Undone { item } => ChecklistChange::Undone(item),
+            ChecklistCommand::Remove { item } => ChecklistChange::Remove(item),
+        }
     }
 }
 
@@ -173,13 +674,123 @@ impl FromStr for PrioCommand {
 impl FromStr for State {
     type Err = String;
 
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // Whether the state exists depends on the columns of the board, which is checked when the
+        // state is used.
+        match s.trim() {
+            // This error message is presented by clap directly
+            "" => Err(String::from("state cannot be empty")),
+            s => Ok(State::from(s)),
+        }
+    }
+}
+
+impl FromStr for Tag {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.trim().is_empty() || s.trim().contains(char::is_whitespace) {
+            // This error message is presented by clap directly
+            return Err(String::from("tag has to be a single, non-empty word"));
+        }
+
+        Ok(Tag::from(s))
+    }
+}
+
+impl FromStr for TagChange {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+
+        if let Some(tag) = s.strip_prefix('-') {
+            Ok(TagChange::Remove(Tag::from_str(tag)?))
+        } else {
+            Ok(TagChange::Add(Tag::from_str(s.strip_prefix('+').unwrap_or(s))?))
+        }
+    }
+}
+
+impl FromStr for BoardName {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // This error message is presented by clap directly
+        BoardName::try_from(s).map_err(|e| e.to_string())
+    }
+}
+
+impl FromStr for Assignee {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim() {
+            // This error message is presented by clap directly
+            "" => Err(String::from("assignee cannot be empty")),
+            s => Ok(Assignee::from(s)),
+        }
+    }
+}
+
+impl FromStr for Priority {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().trim() {
+            "critical" => Ok(Priority::Critical),
+            "high" => Ok(Priority::High),
+            "normal" => Ok(Priority::Normal),
+            "low" => Ok(Priority::Low),
+            // This error message is presented by clap directly
+            _ => Err(String::from("priority has to be critical, high, normal or low")),
+        }
+    }
+}
+
+impl FromStr for LaneGrouping {
+    type Err = String;
+
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s.to_lowercase().trim() {
-               "open" => Ok(State::Open),
-               "review" => Ok(State::Review),
-               "done" => Ok(State::Done),
+            "tag" => Ok(LaneGrouping::Tag),
+            "assignee" => Ok(LaneGrouping::Assignee),
+            "priority" => Ok(LaneGrouping::Priority),
+            "due" => Ok(LaneGrouping::Due),
+            grouping => match grouping.strip_prefix("field:") {
+                Some(name) => Ok(LaneGrouping::Field(name.trim().to_string())),
                 // This error message is presented by clap directly
-               _ => Err(String::from("unknown state")),
+                None => Err(String::from("lanes can be grouped by tag, assignee, priority, due or field:<name>")),
+            },
         }
     }
-}
\ No newline at end of file
+}
+
+impl FromStr for FieldAssignment {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // Whether the field exists depends on the board, which is checked when the field is used.
+        match s.split_once('=') {
+            Some((name, value)) if !name.trim().is_empty() => Ok(FieldAssignment::new(name, Some(value))),
+            // This error message is presented by clap directly
+            _ => Err(String::from("expected `name=value`, or `name=` to unset the field")),
+        }
+    }
+}
+
+impl FromStr for IssueRef {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+
+        // This error message is presented by clap directly
+        let error = || String::from("expected an index (e.g. `3`) or a short id (e.g. `@12`)");
+
+        match s.strip_prefix('@') {
+            Some(id) => id.parse().map(|id| IssueRef::ShortId(ShortId(id))).map_err(|_| error()),
+            None => s.parse().map(IssueRef::Index).map_err(|_| error()),
+        }
+    }
+}
@@ -40,6 +40,6 @@ impl Editor for OsDefaultEditor {
         let mut file = File::open(tempfile_path)?;
         file.read_to_string(&mut contents)?;
 
-        Ok(String::from(contents.replace("\n", " ").trim()))
+        Ok(String::from(contents.trim_end()))
     }
 }
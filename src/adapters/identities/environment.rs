@@ -0,0 +1,15 @@
+use std::env;
+use crate::application::issue::Assignee;
+use crate::application::ports::identity::Identity;
+
+/// The user is configured with the `KANBAN_USER` environment variable
+#[derive(Default)]
+pub(crate) struct EnvironmentIdentity {}
+
+impl Identity for EnvironmentIdentity {
+    fn current_user(&self) -> Option<Assignee> {
+        env::var("KANBAN_USER").ok()
+            .filter(|user| !user.trim().is_empty())
+            .map(|user| Assignee::from(user.as_str()))
+    }
+}
@@ -1,6 +1,8 @@
 pub mod presenters;
 pub mod storages;
 pub mod editors;
+pub mod openers;
 pub mod controllers;
 pub mod time_providers;
+pub mod identities;
 
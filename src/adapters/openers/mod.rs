@@ -0,0 +1 @@
+pub mod os_default_opener;
\ No newline at end of file
@@ -0,0 +1,68 @@
+use std::io::{self, Error};
+use std::path::Path;
+use std::process::{Command, Stdio};
+use home::home_dir;
+use crate::application::ports::opener::Opener;
+
+
+/// Opens links with the opener of the operating system: `open` on macOS, the URL handler of
+/// `rundll32` on Windows and `xdg-open` elsewhere. The link is passed as a single argument, without
+/// going through a shell, and a leading `~` stands for the home directory.
+#[derive(Default)]
+pub(crate) struct OsDefaultOpener {}
+
+impl Opener for OsDefaultOpener {
+    fn open(&self, target: &str) -> Result<(), Error> {
+        let mut command = if cfg!(target_os = "macos") {
+            Command::new("open")
+        } else if cfg!(target_os = "windows") {
+            let mut command = Command::new("rundll32");
+            command.arg("url.dll,FileProtocolHandler");
+            command
+        } else {
+            Command::new("xdg-open")
+        };
+
+        let status = command
+            .arg(expand_home(target, home_dir().as_deref()))
+            .stdin(Stdio::null())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .status()?;
+
+        if !status.success() {
+            return Err(io::Error::other("Opener returned non-zero exit status"));
+        }
+
+        Ok(())
+    }
+}
+
+/// The target with a leading `~` replaced by `home`, like a shell would do it
+fn expand_home(target: &str, home: Option<&Path>) -> String {
+    let rest = target.strip_prefix('~')
+        .filter(|rest| rest.is_empty() || rest.starts_with(['/', '\\']));
+
+    match (rest, home) {
+        (Some(rest), Some(home)) => format!("{}{}", home.display(), rest),
+        _ => target.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+    use assert2::check;
+    use crate::adapters::openers::os_default_opener::expand_home;
+
+    #[test]
+    fn test_expand_home() {
+        let home = Some(Path::new("/home/alice"));
+
+        check!(expand_home("~/designs/login.pdf", home) == "/home/alice/designs/login.pdf");
+        check!(expand_home("~", home) == "/home/alice");
+        check!(expand_home("~bob/designs", home) == "~bob/designs");
+        check!(expand_home("https://example.com/~alice", home) == "https://example.com/~alice");
+        check!(expand_home("~/designs/login.pdf", None) == "~/designs/login.pdf");
+    }
+}
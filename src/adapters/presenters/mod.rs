@@ -1,2 +1,3 @@
 pub mod stdoutrenderer;
 pub mod nil_presenter;
+pub mod styles;
\ No newline at end of file
@@ -1,22 +1,41 @@
 #[cfg(test)]
 pub(crate) mod test {
     use std::cell::{RefCell};
+    use uuid::Uuid;
     use crate::application::Issue;
     use crate::application::domain::error::DomainError;
+    use crate::application::domain::filter::IssueFilter;
+    use crate::application::domain::lanes::LaneGrouping;
+    use crate::application::domain::board_name::BoardName;
     use crate::application::domain::historized_board::HistorizedBoard;
+    use crate::application::domain::time_tracking::TimeReport;
     use crate::application::ports::presenter::Presenter;
 
 
     pub(crate) struct NilPresenter {
         pub(crate) errors_presented: RefCell<Vec<DomainError>>,
+        pub(crate) warnings_presented: RefCell<Vec<DomainError>>,
         pub(crate) last_board_rendered: RefCell<Option<HistorizedBoard<Issue>>>,
+        pub(crate) last_filter_used: RefCell<Option<IssueFilter>>,
+        pub(crate) last_lanes_used: RefCell<Option<LaneGrouping>>,
+        pub(crate) last_issue_rendered: RefCell<Option<Uuid>>,
+        pub(crate) last_time_report: RefCell<Option<TimeReport>>,
+        pub(crate) last_archive_rendered: RefCell<Option<Vec<usize>>>,
+        pub(crate) boards_presented: RefCell<Vec<BoardName>>,
     }
 
     impl Default for NilPresenter {
         fn default() -> Self {
             Self {
                 errors_presented: RefCell::new(Vec::default()),
+                warnings_presented: RefCell::new(Vec::default()),
                 last_board_rendered: RefCell::new(None),
+                last_filter_used: RefCell::new(None),
+                last_lanes_used: RefCell::new(None),
+                last_issue_rendered: RefCell::new(None),
+                last_time_report: RefCell::new(None),
+                last_archive_rendered: RefCell::new(None),
+                boards_presented: RefCell::new(vec![]),
             }
         }
     }
@@ -26,9 +45,35 @@ pub(crate) mod test {
             self.last_board_rendered.swap(&RefCell::new(Some(board.clone())));
         }
 
+        fn render_filtered_board(&self, board: &HistorizedBoard<Issue>, filter: &IssueFilter, lanes: Option<LaneGrouping>) {
+            self.render_board(board);
+            self.last_filter_used.swap(&RefCell::new(Some(filter.clone())));
+            self.last_lanes_used.swap(&RefCell::new(lanes));
+        }
+
+        fn render_issue(&self, _board: &HistorizedBoard<Issue>, id: Uuid) {
+            self.last_issue_rendered.swap(&RefCell::new(Some(id)));
+        }
+
+        fn render_time_report(&self, _board: &HistorizedBoard<Issue>, report: &TimeReport) {
+            self.last_time_report.swap(&RefCell::new(Some(report.clone())));
+        }
+
+        fn render_archive(&self, _board: &HistorizedBoard<Issue>, positions: &[usize]) {
+            self.last_archive_rendered.swap(&RefCell::new(Some(positions.to_vec())));
+        }
+
+        fn render_boards(&self, boards: &[BoardName], _default_board: &BoardName) {
+            self.boards_presented.swap(&RefCell::new(boards.to_vec()));
+        }
+
         fn render_error(&self, err: &DomainError) {
             self.errors_presented.borrow_mut().push(err.clone_for_testing());
         }
+
+        fn render_warning(&self, warning: &DomainError) {
+            self.warnings_presented.borrow_mut().push(warning.clone_for_testing());
+        }
     }
 
 }
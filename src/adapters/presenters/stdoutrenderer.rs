@@ -1,18 +1,34 @@
 use std::collections::HashMap;
-use crate::application::issue::State;
 use crate::application::ports::presenter::Presenter;
 use colored::{ColoredString, Colorize};
 use crate::adapters::presenters::stdoutrenderer::MaybeFormattedString::{Formatted, NonFormatted};
+use crate::adapters::presenters::styles::CategoryStyles;
 use crate::application::domain::error::DomainError;
-use crate::application::domain::issue::IssueCategory;
-use crate::application::Issue;
+use crate::application::domain::filter::IssueFilter;
+use crate::application::domain::issue::{Entity, Priority};
+use crate::application::domain::lanes::{Lane, LaneGrouping};
+use crate::application::{Issue, State};
+use crate::application::domain::column::Column;
 use crate::application::ports::time::{TodayProvider};
 use itertools::Itertools;
+use uuid::Uuid;
 use crate::application::domain::historized_board::HistorizedBoard;
+use crate::application::domain::time_tracking::TimeReport;
+use crate::application::domain::board_name::BoardName;
+use time::{Date, Duration};
 
 #[derive(Default)]
 pub(crate) struct TabularTextRenderer<T: TodayProvider> {
     time_provider: T,
+    styles: CategoryStyles,
+}
+
+impl<T: TodayProvider> TabularTextRenderer<T> {
+    /// Shows the categories of the issues, e.g. overdue, with the given styles
+    pub(crate) fn with_styles(mut self, styles: CategoryStyles) -> Self {
+        self.styles = styles;
+        self
+    }
 }
 
 #[derive(Debug)]
@@ -36,95 +52,419 @@ impl<T: TodayProvider> Presenter for TabularTextRenderer<T> {
 
 
     fn render_board(&self, board: &HistorizedBoard<Issue>) {
-        let result = self.format_board(board);
+        let filter = IssueFilter {
+            hide_waiting_on: Some(self.time_provider.today()),
+            ..Default::default()
+        };
+        self.render_filtered_board(board, &filter, None)
+    }
+
+    fn render_filtered_board(&self, board: &HistorizedBoard<Issue>, filter: &IssueFilter, lanes: Option<LaneGrouping>) {
+        let result = self.format_board(board, filter, lanes);
 
         println!("{}", result)
     }
 
+    fn render_issue(&self, board: &HistorizedBoard<Issue>, id: Uuid) {
+        println!("{}", self.format_issue(board, id))
+    }
+
+    fn render_time_report(&self, board: &HistorizedBoard<Issue>, report: &TimeReport) {
+        println!("{}", Self::format_time_report(board, report))
+    }
+
+    fn render_archive(&self, board: &HistorizedBoard<Issue>, positions: &[usize]) {
+        println!("{}", Self::format_archive(board, positions))
+    }
+
+    fn render_boards(&self, boards: &[BoardName], default_board: &BoardName) {
+        println!("{}", Self::format_boards(boards, default_board))
+    }
+
     fn render_error(&self, err: &DomainError) {
         println!("{}", err)
     }
+
+    fn render_warning(&self, warning: &DomainError) {
+        println!("Warning: {}", warning)
+    }
+}
+
+/// An issue of the board with its index on the whole board
+type IndexedIssue<'a> = (usize, &'a Entity<Issue>);
+
+/// Tracked time in hours and minutes, e.g. "1h 25m"
+fn format_duration(duration: Duration) -> String {
+    let minutes = duration.whole_minutes();
+    match (minutes / 60, minutes % 60) {
+        (0, minutes) => format!("{}m", minutes),
+        (hours, minutes) => format!("{}h {}m", hours, minutes),
+    }
+}
+
+/// How long an issue has been in its state: days for long periods, hours and minutes otherwise
+fn format_time_in_state(duration: Duration) -> String {
+    match duration {
+        duration if duration.whole_days() == 1 => String::from("1 day"),
+        duration if duration.whole_days() > 1 => format!("{} days", duration.whole_days()),
+        duration => format_duration(duration),
+    }
+}
+
+/// The index of the issue, followed by its short id if it has one.
+/// The index changes as the board changes, the short id stays.
+fn format_label(index: usize, issue: &Issue) -> String {
+    match issue.short_id {
+        Some(short_id) => format!("{} {}", index, short_id),
+        None => index.to_string(),
+    }
 }
 
 impl<T: TodayProvider> TabularTextRenderer<T> {
-    fn format_board(&self, board: &HistorizedBoard<Issue>) -> String {
-        self.build_formatted_text_chunks(board)
+    /// Detail view of an issue, including its body and activity log
+    fn format_issue(&self, board: &HistorizedBoard<Issue>, id: Uuid) -> String {
+        let issue = board.get(id);
+        let mut lines = vec![
+            format!("{}: {}", format_label(board.position(id), issue), issue.description),
+            format!("State: {} (for {})", issue.state, format_time_in_state(issue.current_state_period().duration(self.time_provider.now()))),
+            format!("Created: {}", issue.time_created),
+        ];
+
+        if issue.priority != Priority::Normal {
+            lines.push(format!("Priority: {}", issue.priority));
+        }
+
+        if let Some(due) = issue.due_date {
+            lines.push(format!("Due: {}", due));
+        }
+
+        if let Some(wait_until) = issue.wait_until {
+            lines.push(format!("Waits until: {}", wait_until));
+        }
+
+        if let Some(recurrence) = issue.recurrence {
+            lines.push(format!("Recurs: {}", recurrence));
+        }
+
+        if let Some(estimate) = issue.estimate {
+            lines.push(format!("Estimate: {}", estimate));
+        }
+
+        if !issue.work_log.is_empty() {
+            let running = if issue.is_tracked() { " (running)" } else { "" };
+            lines.push(format!("Tracked: {}{}", format_duration(issue.tracked_time(self.time_provider.now())), running));
+        }
+
+        if let Some(assignee) = &issue.assignee {
+            lines.push(format!("Assignee: {}", assignee));
+        }
+
+        if !issue.tags.is_empty() {
+            lines.push(format!("Tags: {}", issue.tags.iter().join(", ")));
+        }
+
+        if !issue.blocked_by.is_empty() {
+            lines.push(format!("Blocked by: {}", issue.blocked_by.iter().join(", ")));
+        }
+
+        if let Some(reason) = &issue.blocked_reason {
+            lines.push(format!("Blocked: {}", reason));
+        }
+
+        if let Some(parent) = issue.parent {
+            lines.push(format!("Parent: {}", parent));
+        }
+
+        if let Some((done, all)) = board.children_progress(id) {
+            lines.push(format!("Children: {}/{} done", done, all));
+        }
+
+        if !issue.fields.is_empty() {
+            lines.push(String::from("Fields:"));
+            lines.extend(issue.fields.iter().map(|(name, value)| format!("  {}: {}", name, value)));
+        }
+
+        if !issue.checklist.is_empty() {
+            let (done, all) = issue.checklist_progress();
+            lines.push(format!("Checklist [{}/{}]:", done, all));
+            lines.extend(issue.checklist.iter()
+                .enumerate()
+                .map(|(index, item)| format!("  {}. [{}] {}", index + 1, if item.done { "x" } else { " " }, item.text)));
+        }
+
+        if !issue.links.is_empty() {
+            lines.push(String::from("Links:"));
+            lines.extend(issue.links.iter()
+                .enumerate()
+                .map(|(index, link)| format!("  {}. {}", index + 1, link)));
+        }
+
+        if let Some(body) = &issue.body {
+            lines.push(String::default());
+            lines.push(body.clone());
+        }
+
+        if !issue.activity.is_empty() {
+            lines.push(String::default());
+            lines.push(String::from("Activity:"));
+            lines.extend(issue.activity.iter().map(|event| format!("  {}", event)));
+        }
+
+        lines.join("\n")
+    }
+
+    fn format_boards(boards: &[BoardName], default_board: &BoardName) -> String {
+        boards.iter()
+            .map(|board| if board == default_board {
+                format!("{} (default)", board)
+            } else {
+                board.to_string()
+            })
+            .join("\n")
+    }
+
+    /// Archived issues, with their position in the archive and the column they were archived from
+    fn format_archive(board: &HistorizedBoard<Issue>, positions: &[usize]) -> String {
+        if positions.is_empty() {
+            return String::from("No archived issues found");
+        }
+
+        positions.iter()
+            .map(|&position| {
+                let issue = &board.get_archived_entities()[position];
+                let tags = issue.tags.iter()
+                    .map(|tag| format!(" +{}", tag))
+                    .join("");
+
+                format!("{}: {}{} ({})", format_label(position, issue), issue.description, tags, issue.state)
+            })
+            .join("\n")
+    }
+
+    /// Tracked time per issue and per day
+    fn format_time_report(board: &HistorizedBoard<Issue>, report: &TimeReport) -> String {
+        if report.per_issue.is_empty() {
+            return String::from("No time has been tracked");
+        }
+
+        let mut lines = vec![String::from("Per issue:")];
+        lines.extend(report.per_issue.iter().map(|(id, duration)| {
+            let issue = board.get(*id);
+            format!("  {}: {}\t{}", format_label(board.position(*id), issue), issue.description, format_duration(*duration))
+        }));
+
+        lines.push(String::from("Per day:"));
+        lines.extend(report.per_day.iter()
+            .map(|(day, duration)| format!("  {}\t{}", day, format_duration(*duration))));
+
+        lines.push(format!("Total: {}", format_duration(report.total())));
+
+        lines.join("\n")
+    }
+
+    /// Line below the board naming the tracked issue, with the time of its running timer
+    fn format_tracking(&self, board: &HistorizedBoard<Issue>) -> Option<MaybeFormattedString> {
+        let id = board.find_tracked_issue()?;
+        let issue = board.get(id);
+        let elapsed = issue.work_log.iter()
+            .find(|interval| interval.is_running())
+            .map(|interval| interval.duration(self.time_provider.now()))
+            .unwrap_or_default();
+
+        Some(Formatted(
+            format!("Tracking {}: {} ({})", format_label(board.position(id), issue), issue.description, format_duration(elapsed)).cyan()
+        ))
+    }
+
+    /// Line below the board with the number of issues that are hidden because they wait for a
+    /// later date
+    fn format_waiting(board: &HistorizedBoard<Issue>, filter: &IssueFilter) -> Option<MaybeFormattedString> {
+        let today = filter.hide_waiting_on?;
+        let other_filters = IssueFilter {
+            hide_waiting_on: None,
+            ..filter.clone()
+        };
+        let count = board.entities().iter()
+            .filter(|issue| issue.is_waiting(today) && other_filters.matches(issue))
+            .count();
+
+        match count {
+            0 => None,
+            1 => Some(Formatted("1 scheduled issue is hidden, use --all to show it".dimmed())),
+            count => Some(Formatted(format!("{} scheduled issues are hidden, use --all to show them", count).dimmed())),
+        }
+    }
+
+    /// Name of the column, with the number of its issues against its WIP limit, if it has one,
+    /// and the total of their estimates. Columns over their limit are highlighted.
+    fn format_header(board: &HistorizedBoard<Issue>, state: &State) -> MaybeFormattedString {
+        let limit = board.columns().find(state).and_then(Column::wip_limit);
+        let count = board.issue_count_in(state);
+
+        let mut header = match limit {
+            Some(limit) => format!("{} ({}/{})", state, count, limit),
+            None => state.to_string(),
+        };
+
+        if let Some(total) = board.estimate_total_in(state) {
+            header.push_str(&format!(" [{} pts]", total));
+        }
+
+        match limit {
+            Some(limit) if count > limit => Formatted(header.red().bold()),
+            _ => Formatted(header.bold()),
+        }
+    }
+
+    fn format_board(&self, board: &HistorizedBoard<Issue>, filter: &IssueFilter, lanes: Option<LaneGrouping>) -> String {
+        self.build_formatted_text_chunks(board, filter, lanes)
             .into_iter()
             .map(|t| t.to_string())
             .join("\n")
     }
 
-    fn build_formatted_text_chunks<'a>(&'a self, board: &'a HistorizedBoard<Issue>) -> impl Iterator<Item = MaybeFormattedString> + 'a  {
+    /// Groups the issues of a column by their lane, in the order of the lanes. Issues keep their
+    /// order within a lane. Without grouping, the issues are in a single lane without a name.
+    fn split_into_lanes<'a>(issues: Vec<IndexedIssue<'a>>, lanes: Option<&LaneGrouping>, today: Date) -> Vec<(Option<Lane>, Vec<IndexedIssue<'a>>)> {
+        match lanes {
+            None => vec![(None, issues)],
+            Some(lanes) => issues.into_iter()
+                .into_group_map_by(|(_, issue)| lanes.lane_of(issue, today))
+                .into_iter()
+                .sorted_by(|(a, _), (b, _)| a.cmp(b))
+                .map(|(lane, issues)| (Some(lane), issues))
+                .collect(),
+        }
+    }
+
+    /// Line of an issue on the board, with its display category (e.g. overdue)
+    fn format_issue_line(&self, board: &HistorizedBoard<Issue>, index: usize, issue: &Entity<Issue>, today: Date) -> MaybeFormattedString {
+        let label = format_label(index, issue);
+
+        let priority = issue.priority.marker()
+            .map(|marker| format!("{} ", marker))
+            .unwrap_or_default();
+
+        let progress = match issue.checklist_progress() {
+            (_, 0) => String::default(),
+            (done, all) => format!(" [{}/{}]", done, all),
+        };
+
+        let children = board.children_progress(issue.id)
+            .map(|(done, all)| format!(" ({}/{} done)", done, all))
+            .unwrap_or_default();
+
+        let links = match issue.links.len() {
+            0 => String::default(),
+            1 => String::from(" [1 link]"),
+            count => format!(" [{} links]", count),
+        };
+
+        let tags = issue.tags.iter()
+            .map(|tag| format!(" +{}", tag))
+            .join("");
+
+        let assignee = issue.assignee.as_ref()
+            .map(|assignee| format!(" ({})", assignee.initials()))
+            .unwrap_or_default();
+
+        let blockers = board.open_blockers(issue.id);
+        let blocked = if blockers.is_empty() {
+            String::default()
+        } else {
+            format!(" (blocked by {})", blockers.iter().join(", "))
+        };
+
+        let flagged = if issue.is_flagged_blocked() { " [blocked]" } else { "" };
+
+        let waiting = match issue.wait_until {
+            Some(wait_until) if issue.is_waiting(today) => format!(" (waits until {})", wait_until),
+            _ => String::default(),
+        };
+
+        // Fields shown on the board are in their own columns, in the order the board declares them
+        let fields = board.fields().iter()
+            .filter(|field| field.show)
+            .filter_map(|field| issue.fields.get(field.name()).map(|value| format!("\t{}: {}", field.name(), value)))
+            .join("");
+
+        let text = if let Some(due) = &issue.due_date {
+            format!("{}: {}{}{}{}{}{}{}{}{}{}{}\t{}", label, priority, issue.description, progress, children, links, tags, assignee, flagged, blocked, waiting, fields, due)
+        } else {
+            format!("{}: {}{}{}{}{}{}{}{}{}{}{}", label, priority, issue.description, progress, children, links, tags, assignee, flagged, blocked, waiting, fields)
+        };
+
+        let style = self.styles.of(&board.category_of(issue, today));
+        if style.is_plain() {
+            NonFormatted(text)
+        } else {
+            Formatted(style.apply(&text))
+        }
+    }
+
+    fn build_formatted_text_chunks<'a>(&'a self, board: &'a HistorizedBoard<Issue>, filter: &IssueFilter, lanes: Option<LaneGrouping>) -> impl Iterator<Item = MaybeFormattedString> + 'a  {
         let mut issues_categorised_by_state = board.entities().iter()
             .enumerate()
-            .map(|(index, issue) | (issue.state, (index, issue)))
+            .filter(|(_, issue)| filter.matches(issue))
+            .map(|(index, issue) | (issue.state.clone(), (index, issue)))
             .fold(HashMap::new(), |mut acc, (state, issue_ref) | {
                 acc.entry(state).or_insert_with(Vec::new).push(issue_ref);
                 acc
             });
 
+        let done_state = board.columns().done_state();
         let mut done_issues_truncated = false;
 
         // Keep only the first 4 issues of DONE
-        if let Some(done_issues) = issues_categorised_by_state.get_mut(&State::Done) {
+        if let Some(done_issues) = issues_categorised_by_state.get_mut(&done_state) {
             if done_issues.len() > 4 {
                 done_issues_truncated = true;
                 done_issues.drain(4..);
             }
         }
 
+        // Issues with a state that is not a column of the board (e.g. the column was removed from
+        // the board file) are displayed after the columns, so that they do not disappear.
+        let unknown_states = issues_categorised_by_state.iter()
+            .filter(|(state, _)| board.columns().find(state).is_none())
+            .sorted_by_key(|(_, issues)| issues.first().map(|(index, _)| *index))
+            .map(|(state, _)| state.clone())
+            .collect::<Vec<_>>();
+
         let today = self.time_provider.today();
+        let tracking = self.format_tracking(board);
+        let waiting = Self::format_waiting(board, filter);
 
-        vec![
-            State::Open,
-            State::Review,
-            State::Done,
-        ]
-            .into_iter()
+        board.columns().iter()
+            .map(|column| column.state())
+            .chain(unknown_states)
             .map(move |tab| {
                 let today = today; // capture to force closure to be FnOnce
                 vec![
                     // Header
-                    Formatted((match &tab {
-                        State::Open => "Open",
-                        State::Review => "Review",
-                        State::Done => "Done",
-                    }).bold()),
+                    Self::format_header(board, &tab),
                 ].into_iter().chain(
                     // Display the issues
-                    issues_categorised_by_state
-                        // State by state
-                        .remove(&tab)
-                        .unwrap_or(Vec::default())
+                    Self::split_into_lanes(
+                        issues_categorised_by_state
+                            // State by state
+                            .remove(&tab)
+                            .unwrap_or(Vec::default()),
+                        lanes.as_ref(),
+                        today,
+                    )
                         .into_iter()
-
-                        // make it to a string with display category (e.g. overdue)
-                        .map(move | (index, issue)|
-                            {
-                                (
-                                    if let Some(due) = &issue.due_date {
-                                        format!("{}: {}\t{}", index, issue.description, due)
-                                    } else {
-                                        format!("{}: {}", index, issue.description)
-                                    },
-                                    issue.category(today)
-                                )
-                            }
+                        .flat_map(move |(lane, issues)|
+                            // Lane header, followed by the issues of the lane
+                            lane.map(|lane| Formatted(format!("  {}", lane).italic()))
+                                .into_iter()
+                                .chain(issues.into_iter()
+                                    .map(move |(index, issue)| self.format_issue_line(board, index, issue, today)))
                         )
-
-                        // apply display category
-                        .map(|(text, category)|
-                            match category {
-                                IssueCategory::Overdue => Formatted(text.red()),
-                                IssueCategory::Normal => NonFormatted(text),
-                                IssueCategory::DueToday => Formatted(text.yellow()),
-                            }
-                        )
-
                 ).chain(
                     std::iter::once(
                         NonFormatted(
-                            if tab == State::Done && done_issues_truncated {
+                            if tab == done_state && done_issues_truncated {
                                 String::from("...")
                             } else {
                                 String::default()
@@ -133,29 +473,42 @@ impl<T: TodayProvider> TabularTextRenderer<T> {
                     )
                 )
             }).flatten()
+            .chain(waiting)
+            .chain(tracking)
     }
 }
 
 
 #[cfg(test)]
 mod test {
+    use std::collections::BTreeMap;
     use std::ops::Deref;
-    use crate::adapters::presenters::stdoutrenderer::TabularTextRenderer;
+    use crate::adapters::presenters::stdoutrenderer::{format_time_in_state, TabularTextRenderer};
     use crate::adapters::time_providers::fake::{FakeTodayProvider, DEFAULT_FAKE_TODAY};
     use crate::application::{Issue, State};
     use crate::application::issue::Description;
     use assert2::check;
     use colored::Colorize;
     use crate::adapters::presenters::stdoutrenderer::MaybeFormattedString::{Formatted, NonFormatted};
+    use crate::application::domain::filter::IssueFilter;
+    use crate::application::domain::lanes::LaneGrouping;
     use crate::application::domain::historized_board::HistorizedBoard;
-    use time::macros::date;
+    use crate::application::issue::{Assignee, ChecklistItem, Link, Priority, ShortId, Tag};
+    use time::Duration;
+    use time::macros::{date, datetime};
+    use crate::application::domain::activity::{ActivityEvent, ActivityKind};
+    use crate::application::domain::date_parse::Recurrence;
+    use crate::application::domain::time_tracking::{TimeReport, WorkInterval};
+    use crate::application::domain::board_name::BoardName;
+    use crate::adapters::presenters::styles::CategoryStyles;
+    use crate::application::domain::column::{Column, Columns};
 
     #[test]
     fn test_format_empty_board() {
         let board = HistorizedBoard::default();
         let text_renderer = TabularTextRenderer::<FakeTodayProvider>::default();
 
-        let mut formatted_chunks = text_renderer.build_formatted_text_chunks(&board);
+        let mut formatted_chunks = text_renderer.build_formatted_text_chunks(&board, &IssueFilter::default(), None);
         [
             Formatted("Open".bold()),
             NonFormatted(String::default()),
@@ -177,13 +530,14 @@ mod test {
         let board = HistorizedBoard::default()
             .with_issue(Issue {
                 description: Description::from("An issue in done"),
-                state: State::Done,
+                state: State::from("Done"),
                 time_created: DEFAULT_FAKE_TODAY,
                 due_date: None,
+                ..Default::default()
             });
         let text_renderer = TabularTextRenderer::<FakeTodayProvider>::default();
 
-        let mut formatted_chunks = text_renderer.build_formatted_text_chunks(&board);
+        let mut formatted_chunks = text_renderer.build_formatted_text_chunks(&board, &IssueFilter::default(), None);
         [
             Formatted("Open".bold()),
             NonFormatted(String::default()),
@@ -200,12 +554,454 @@ mod test {
         check!(formatted_chunks.next() == None, "Expected not to have any more formatted output");
     }
 
+    #[test]
+    fn test_format_board_with_custom_columns() {
+        let board = HistorizedBoard::default()
+            .with_columns(&["Backlog", "In Progress", "Done"])
+            .with_issue(Issue {
+                description: Description::from("An issue in progress"),
+                state: State::from("in progress"),
+                time_created: DEFAULT_FAKE_TODAY,
+                due_date: None,
+                ..Default::default()
+            })
+            .with_issue(Issue {
+                description: Description::from("An issue of a removed column"),
+                state: State::from("Review"),
+                time_created: DEFAULT_FAKE_TODAY,
+                due_date: None,
+                ..Default::default()
+            });
+        let text_renderer = TabularTextRenderer::<FakeTodayProvider>::default();
+
+        let mut formatted_chunks = text_renderer.build_formatted_text_chunks(&board, &IssueFilter::default(), None);
+        [
+            Formatted("Backlog".bold()),
+            NonFormatted(String::default()),
+            Formatted("In Progress".bold()),
+            NonFormatted(String::from("0: An issue in progress")),
+            NonFormatted(String::default()),
+            Formatted("Done".bold()),
+            NonFormatted(String::default()),
+            Formatted("Review".bold()),
+            NonFormatted(String::from("1: An issue of a removed column")),
+            NonFormatted(String::default()),
+        ].into_iter().for_each(|expected| {
+            let chunk = formatted_chunks.next().expect("Expected more chunks of formatted output");
+            check!(chunk == expected);
+        });
+
+        check!(formatted_chunks.next() == None, "Expected not to have any more formatted output");
+    }
+
+    #[test]
+    fn test_format_headers_with_wip_limits() {
+        let board = HistorizedBoard::default()
+            .with_4_typical_issues()
+            .with_wip_limit("Open", 1)
+            .with_wip_limit("Review", 3);
+        let text_renderer = TabularTextRenderer::<FakeTodayProvider>::default();
+
+        let chunks = text_renderer.build_formatted_text_chunks(&board, &IssueFilter::default(), None).collect::<Vec<_>>();
+
+        check!(chunks[0] == Formatted("Open (2/1)".red().bold()), "Expected column over its limit to be highlighted");
+        check!(chunks.contains(&Formatted("Review (1/3)".bold())));
+        check!(chunks.contains(&Formatted("Done".bold())));
+    }
+
+    #[test]
+    fn test_format_headers_with_estimate_totals() {
+        let board = HistorizedBoard::default()
+            .with_issue(Issue {
+                description: Description::from("Review the login"),
+                state: State::from("Review"),
+                estimate: Some(5),
+                ..Default::default()
+            })
+            .with_issue(Issue {
+                description: Description::from("Review the logout"),
+                state: State::from("Review"),
+                estimate: Some(3),
+                ..Default::default()
+            })
+            .with_issue(Issue {
+                description: Description::from("Not estimated"),
+                state: State::from("Review"),
+                ..Default::default()
+            })
+            .with_4_typical_issues()
+            .with_wip_limit("Review", 3);
+        let text_renderer = TabularTextRenderer::<FakeTodayProvider>::default();
+
+        let chunks = text_renderer.build_formatted_text_chunks(&board, &IssueFilter::default(), None).collect::<Vec<_>>();
+
+        check!(chunks[0] == Formatted("Open".bold()), "Expected no total for columns without estimates");
+        check!(chunks.contains(&Formatted("Review (4/3) [8 pts]".red().bold())));
+    }
+
+    #[test]
+    fn test_format_board_filtered_by_tag() {
+        let board = HistorizedBoard::default()
+            .with_issue(Issue {
+                description: Description::from("Fix login"),
+                state: State::from("Open"),
+                time_created: DEFAULT_FAKE_TODAY,
+                due_date: Some(date!(2025-03-01)),
+                tags: vec![Tag::from("backend"), Tag::from("urgent")],
+                short_id: Some(ShortId(12)),
+                checklist: vec![
+                    ChecklistItem { text: String::from("reproduce"), done: true },
+                    ChecklistItem::new("write a test"),
+                ],
+                links: vec![Link::new("https://example.com/pr/42", Some("PR"))],
+                ..Default::default()
+            })
+            .with_issue(Issue {
+                description: Description::from("Restyle the login page"),
+                state: State::from("Open"),
+                time_created: DEFAULT_FAKE_TODAY,
+                due_date: None,
+                tags: vec![Tag::from("frontend")],
+                short_id: Some(ShortId(13)),
+                checklist: vec![],
+                ..Default::default()
+            });
+        let text_renderer = TabularTextRenderer::<FakeTodayProvider>::default();
+
+        let mut formatted_chunks = text_renderer.build_formatted_text_chunks(&board, &IssueFilter {
+            tags: vec![Tag::from("backend")],
+            ..Default::default()
+        }, None);
+        [
+            Formatted("Open".bold()),
+            NonFormatted(String::from("0 @12: Fix login [1/2] [1 link] +backend +urgent\t2025-03-01")),
+            NonFormatted(String::default()),
+            Formatted("Review".bold()),
+            NonFormatted(String::default()),
+            Formatted("Done".bold()),
+            NonFormatted(String::default()),
+        ].into_iter().for_each(|expected| {
+            let chunk = formatted_chunks.next().expect("Expected more chunks of formatted output");
+            check!(chunk == expected);
+        });
+
+        check!(formatted_chunks.next() == None, "Expected not to have any more formatted output");
+    }
+
+    #[test]
+    fn test_format_blocked_issue() {
+        let mut board = HistorizedBoard::default()
+            .with_issue(Issue { description: Description::from("Deploy"), state: State::from("Open"), time_created: DEFAULT_FAKE_TODAY, short_id: Some(ShortId(3)), ..Default::default() })
+            .with_issue(Issue { description: Description::from("Open blocker"), state: State::from("Open"), time_created: DEFAULT_FAKE_TODAY, short_id: Some(ShortId(2)), ..Default::default() })
+            .with_issue(Issue { description: Description::from("Done blocker"), state: State::from("Done"), time_created: DEFAULT_FAKE_TODAY, short_id: Some(ShortId(1)), ..Default::default() });
+        let id = board.find_entity_id_by_index(0).unwrap();
+        board.get_mut(id).blocked_by = vec![ShortId(1), ShortId(2)];
+        let text_renderer = TabularTextRenderer::<FakeTodayProvider>::default();
+
+        let mut formatted_chunks = text_renderer.build_formatted_text_chunks(&board, &IssueFilter::default(), None);
+
+        check!(formatted_chunks.nth(1) == Some(NonFormatted(String::from("0 @3: Deploy (blocked by @2)"))));
+    }
+
+    #[test]
+    fn test_format_categories_by_due_policy() {
+        let mut board = HistorizedBoard::default()
+            .with_issue(Issue { description: Description::from("Due soon"), state: State::from("Open"), time_created: DEFAULT_FAKE_TODAY, due_date: Some(date!(2025-02-25)), ..Default::default() })
+            .with_issue(Issue { description: Description::from("Long in review"), state: State::from("Review"), time_created: date!(2025-02-10), ..Default::default() })
+            .with_issue(Issue { description: Description::from("Done long ago"), state: State::from("Done"), time_created: date!(2024-02-02), due_date: Some(date!(2024-03-01)), ..Default::default() })
+            .with_issue(Issue { description: Description::from("Blocked in review"), state: State::from("Review"), time_created: date!(2025-02-10), blocked_reason: Some(String::from("waiting for API keys")), ..Default::default() });
+        board.set_due_soon_days(Some(3));
+        board.set_columns(Columns::new(vec![
+            Column::new("Open"),
+            Column::new("Review").with_stale_after_days(Some(5)),
+            Column::new("Done"),
+        ]).unwrap());
+        let text_renderer = TabularTextRenderer::<FakeTodayProvider> {
+            styles: CategoryStyles::default().with_overrides("due-soon=green bold"),
+            ..Default::default()
+        };
+
+        let chunks = text_renderer.build_formatted_text_chunks(&board, &IssueFilter::default(), None).collect::<Vec<_>>();
+
+        check!(chunks.contains(&Formatted("0: Due soon\t2025-02-25".green().bold())));
+        check!(chunks.contains(&Formatted("1: Long in review".red())));
+        check!(chunks.contains(&NonFormatted(String::from("2: Done long ago\t2024-03-01"))), "Expected done issues never to be overdue");
+        check!(chunks.contains(&Formatted("3: Blocked in review [blocked]".bright_black())), "Expected blocked issues never to be overdue");
+    }
+
+    #[test]
+    fn test_format_waiting_issues() {
+        let board = HistorizedBoard::default()
+            .with_issue(Issue { description: Description::from("Renew certificate"), state: State::from("Open"), time_created: DEFAULT_FAKE_TODAY, wait_until: Some(date!(2025-06-01)), ..Default::default() })
+            .with_issue(Issue { description: Description::from("Fix login"), state: State::from("Open"), time_created: DEFAULT_FAKE_TODAY, ..Default::default() });
+        let text_renderer = TabularTextRenderer::<FakeTodayProvider>::default();
+        let hiding_filter = IssueFilter {
+            hide_waiting_on: Some(DEFAULT_FAKE_TODAY),
+            ..Default::default()
+        };
+
+        let hidden = text_renderer.build_formatted_text_chunks(&board, &hiding_filter, None).collect::<Vec<_>>();
+        let all = text_renderer.build_formatted_text_chunks(&board, &IssueFilter::default(), None).collect::<Vec<_>>();
+
+        check!(!hidden.contains(&NonFormatted(String::from("0: Renew certificate (waits until 2025-06-01)"))));
+        check!(hidden.last() == Some(&Formatted("1 scheduled issue is hidden, use --all to show it".dimmed())));
+        check!(all.contains(&NonFormatted(String::from("0: Renew certificate (waits until 2025-06-01)"))));
+        check!(all.last() == Some(&NonFormatted(String::default())), "Expected no hidden issues line");
+    }
+
+    #[test]
+    fn test_format_assignee_initials() {
+        let board = HistorizedBoard::default()
+            .with_issue(Issue {
+                description: Description::from("Fix login"),
+                state: State::from("Open"),
+                time_created: DEFAULT_FAKE_TODAY,
+                tags: vec![Tag::from("backend")],
+                short_id: Some(ShortId(3)),
+                assignee: Some(Assignee::from("alice.jones")),
+                ..Default::default()
+            });
+        let text_renderer = TabularTextRenderer::<FakeTodayProvider>::default();
+
+        let mut formatted_chunks = text_renderer.build_formatted_text_chunks(&board, &IssueFilter::default(), None);
+
+        check!(formatted_chunks.nth(1) == Some(NonFormatted(String::from("0 @3: Fix login +backend (AJ)"))));
+    }
+
+    #[test]
+    fn test_format_shown_fields() {
+        let board = HistorizedBoard::default()
+            .with_issue(Issue {
+                description: Description::from("Fix login"),
+                state: State::from("Open"),
+                time_created: DEFAULT_FAKE_TODAY,
+                due_date: Some(date!(2025-03-01)),
+                fields: BTreeMap::from([
+                    (String::from("customer"), String::from("acme")),
+                    (String::from("ticket"), String::from("1234")),
+                ]),
+                ..Default::default()
+            })
+            .with_custom_fields();
+        let text_renderer = TabularTextRenderer::<FakeTodayProvider>::default();
+
+        let mut formatted_chunks = text_renderer.build_formatted_text_chunks(&board, &IssueFilter::default(), None);
+
+        check!(formatted_chunks.nth(1) == Some(NonFormatted(String::from("0: Fix login\tcustomer: acme\t2025-03-01"))), "Expected only the fields shown on the board");
+    }
+
+    #[test]
+    fn test_format_priority_markers() {
+        let board = [Priority::Critical, Priority::High, Priority::Normal, Priority::Low].into_iter()
+            .fold(HistorizedBoard::default(), |board, priority| board.with_issue(Issue {
+                description: Description::from(priority.to_string().as_str()),
+                state: State::from("Open"),
+                time_created: DEFAULT_FAKE_TODAY,
+                priority,
+                ..Default::default()
+            }));
+        let text_renderer = TabularTextRenderer::<FakeTodayProvider>::default();
+
+        let chunks = text_renderer.build_formatted_text_chunks(&board, &IssueFilter::default(), None).collect::<Vec<_>>();
+
+        check!(chunks[1..5] == [
+            NonFormatted(String::from("0: !! critical")),
+            NonFormatted(String::from("1: ! high")),
+            NonFormatted(String::from("2: normal")),
+            NonFormatted(String::from("3: ~ low")),
+        ]);
+    }
+
+    #[test]
+    fn test_format_board_in_lanes() {
+        let board = HistorizedBoard::default()
+            .with_issue(Issue { description: Description::from("Fix login"), state: State::from("Open"), time_created: DEFAULT_FAKE_TODAY, assignee: Some(Assignee::from("bob")), ..Default::default() })
+            .with_issue(Issue { description: Description::from("Update the docs"), state: State::from("Open"), time_created: DEFAULT_FAKE_TODAY, ..Default::default() })
+            .with_issue(Issue { description: Description::from("Restyle the login page"), state: State::from("Open"), time_created: DEFAULT_FAKE_TODAY, assignee: Some(Assignee::from("alice")), ..Default::default() })
+            .with_issue(Issue { description: Description::from("Fix logout"), state: State::from("Open"), time_created: DEFAULT_FAKE_TODAY, assignee: Some(Assignee::from("bob")), ..Default::default() })
+            .with_issue(Issue { description: Description::from("Review the login"), state: State::from("Review"), time_created: DEFAULT_FAKE_TODAY, assignee: Some(Assignee::from("bob")), ..Default::default() });
+        let text_renderer = TabularTextRenderer::<FakeTodayProvider>::default();
+
+        let mut formatted_chunks = text_renderer.build_formatted_text_chunks(&board, &IssueFilter::default(), Some(LaneGrouping::Assignee));
+        [
+            Formatted("Open".bold()),
+            Formatted("  alice".italic()),
+            NonFormatted(String::from("2: Restyle the login page (A)")),
+            Formatted("  bob".italic()),
+            NonFormatted(String::from("0: Fix login (B)")),
+            NonFormatted(String::from("3: Fix logout (B)")),
+            Formatted("  unassigned".italic()),
+            NonFormatted(String::from("1: Update the docs")),
+            NonFormatted(String::default()),
+            Formatted("Review".bold()),
+            Formatted("  bob".italic()),
+            NonFormatted(String::from("4: Review the login (B)")),
+            NonFormatted(String::default()),
+            Formatted("Done".bold()),
+            NonFormatted(String::default()),
+        ].into_iter().for_each(|expected| {
+            let chunk = formatted_chunks.next().expect("Expected more chunks of formatted output");
+            check!(chunk == expected);
+        });
+
+        check!(formatted_chunks.next() == None, "Expected not to have any more formatted output");
+    }
+
+    #[test]
+    fn test_format_issue_details() {
+        let mut board = HistorizedBoard::default()
+            .with_issue(Issue {
+                description: Description::from("Fix login"),
+                state: State::from("Open"),
+                time_created: DEFAULT_FAKE_TODAY,
+                due_date: Some(date!(2025-03-01)),
+                wait_until: Some(date!(2025-02-20)),
+                tags: vec![Tag::from("backend"), Tag::from("urgent")],
+                short_id: Some(ShortId(12)),
+                checklist: vec![
+                    ChecklistItem { text: String::from("reproduce"), done: true },
+                    ChecklistItem::new("write a test"),
+                ],
+                blocked_by: vec![ShortId(3)],
+                blocked_reason: Some(String::from("waiting for API keys")),
+                parent: Some(ShortId(9)),
+                recurrence: Some(Recurrence::MonthDay(1)),
+                estimate: Some(3),
+                assignee: Some(Assignee::from("alice")),
+                priority: Priority::High,
+                work_log: vec![
+                    WorkInterval { start: datetime!(2025-02-21 09:00 UTC), end: Some(datetime!(2025-02-21 09:25 UTC)) },
+                    WorkInterval::started_at(datetime!(2025-02-22 09:00 UTC)),
+                ],
+                body: Some(String::from("Steps:\n  1. open the page")),
+                activity: vec![
+                    ActivityEvent::new(datetime!(2025-02-22 10:00 UTC), ActivityKind::Created),
+                    ActivityEvent::new(datetime!(2025-02-22 14:30 UTC), ActivityKind::Comment(String::from("waiting on infra"))),
+                ],
+                links: vec![
+                    Link::new("https://example.com/pr/42", Some("PR")),
+                    Link::new("~/designs/login.pdf", None),
+                ],
+                fields: BTreeMap::from([
+                    (String::from("customer"), String::from("acme")),
+                    (String::from("ticket"), String::from("1234")),
+                ]),
+            });
+        let id = board.find_entity_id_by_index(0).unwrap();
+        let text_renderer = TabularTextRenderer::<FakeTodayProvider>::default();
+
+        check!(text_renderer.format_issue(&board, id) == "0 @12: Fix login
+State: Open (for 0m)
+Created: 2025-02-22
+Priority: high
+Due: 2025-03-01
+Waits until: 2025-02-20
+Recurs: monthly on 1
+Estimate: 3
+Tracked: 1h 25m (running)
+Assignee: alice
+Tags: backend, urgent
+Blocked by: @3
+Blocked: waiting for API keys
+Parent: @9
+Fields:
+  customer: acme
+  ticket: 1234
+Checklist [1/2]:
+  1. [x] reproduce
+  2. [ ] write a test
+Links:
+  1. PR: https://example.com/pr/42
+  2. ~/designs/login.pdf
+
+Steps:
+  1. open the page
+
+Activity:
+  2025-02-22 10:00 created
+  2025-02-22 14:30 comment: waiting on infra");
+
+        board.get_mut(id).body = None;
+        board.get_mut(id).activity = vec![];
+        check!(!text_renderer.format_issue(&board, id).ends_with("\n"));
+    }
+
+    #[test]
+    fn test_format_time_in_state() {
+        check!(format_time_in_state(Duration::minutes(135)) == "2h 15m");
+        check!(format_time_in_state(Duration::hours(26)) == "1 day");
+        check!(format_time_in_state(Duration::days(5)) == "5 days");
+    }
+
+    #[test]
+    fn test_format_tracked_issue_below_board() {
+        let board = HistorizedBoard::default()
+            .with_issue(Issue {
+                description: Description::from("Fix login"),
+                state: State::from("Review"),
+                short_id: Some(ShortId(3)),
+                work_log: vec![
+                    WorkInterval { start: datetime!(2025-02-21 09:00 UTC), end: Some(datetime!(2025-02-21 12:00 UTC)) },
+                    WorkInterval::started_at(datetime!(2025-02-22 09:35 UTC)),
+                ],
+                ..Default::default()
+            });
+        let text_renderer = TabularTextRenderer::<FakeTodayProvider>::default();
+
+        let formatted_chunks = text_renderer.build_formatted_text_chunks(&board, &IssueFilter::default(), None);
+
+        check!(formatted_chunks.last() == Some(Formatted("Tracking 0 @3: Fix login (25m)".cyan())));
+    }
+
+    #[test]
+    fn test_format_time_report() {
+        let board = HistorizedBoard::default()
+            .with_issue(Issue {
+                description: Description::from("Fix login"),
+                short_id: Some(ShortId(3)),
+                work_log: vec![
+                    WorkInterval { start: datetime!(2025-02-21 09:00 UTC), end: Some(datetime!(2025-02-21 10:25 UTC)) },
+                    WorkInterval { start: datetime!(2025-02-22 08:00 UTC), end: Some(datetime!(2025-02-22 08:10 UTC)) },
+                ],
+                ..Default::default()
+            });
+        let report = TimeReport::new(&board, datetime!(2025-02-22 10:00 UTC));
+
+        check!(TabularTextRenderer::<FakeTodayProvider>::format_time_report(&board, &report) == "Per issue:
+  0 @3: Fix login\t1h 35m
+Per day:
+  2025-02-21\t1h 25m
+  2025-02-22\t10m
+Total: 1h 35m");
+        check!(TabularTextRenderer::<FakeTodayProvider>::format_time_report(&board, &TimeReport::new(&HistorizedBoard::default(), datetime!(2025-02-22 10:00 UTC))) == "No time has been tracked");
+    }
+
+    #[test]
+    fn test_format_archive() {
+        let mut board = HistorizedBoard::default();
+        board.set_archived_entities(vec![
+            Issue { description: Description::from("Fix login"), state: State::from("Done"), tags: vec![Tag::from("backend")], short_id: Some(ShortId(3)), ..Default::default() },
+            Issue { description: Description::from("Restyle the login page"), state: State::from("Review"), short_id: Some(ShortId(5)), ..Default::default() },
+        ]);
+
+        check!(TabularTextRenderer::<FakeTodayProvider>::format_archive(&board, &[0, 1]) == "0 @3: Fix login +backend (Done)
+1 @5: Restyle the login page (Review)");
+        check!(TabularTextRenderer::<FakeTodayProvider>::format_archive(&board, &[]) == "No archived issues found");
+    }
+
+    #[test]
+    fn test_format_boards() {
+        let boards = [BoardName::main(), BoardName::try_from("personal").unwrap(), BoardName::try_from("work").unwrap()];
+
+        check!(TabularTextRenderer::<FakeTodayProvider>::format_boards(&boards, &BoardName::try_from("work").unwrap()) == "main\npersonal\nwork (default)");
+    }
+
     #[test]
     fn test_formatted_text_chunks() {
         let board = given_board();
         let text_renderer = TabularTextRenderer::<FakeTodayProvider>::default();
 
-        let mut formatted_chunks = text_renderer.build_formatted_text_chunks(&board);
+        let mut formatted_chunks = text_renderer.build_formatted_text_chunks(&board, &IssueFilter::default(), None);
 
         [
             Formatted("Open".bold()),
@@ -233,29 +1029,33 @@ mod test {
         let board = HistorizedBoard::new(
             (0..5).into_iter().rev().map(|n| Issue {
                 description: Description::from(format!("Done issue number {}", n).deref()),
-                state: State::Done,
+                state: State::from("Done"),
                 time_created: DEFAULT_FAKE_TODAY,
-                due_date: None
+                due_date: None,
+                ..Default::default()
             })
                 .chain(
                     vec![
                         Issue {
                             description: Description::from("An open issue overdue"),
-                            state: State::Open,
+                            state: State::from("Open"),
                             time_created: date!(2024-02-02),
                             due_date: None,
+                            ..Default::default()
                         },
                         Issue {
                             description: Description::from("An open issue not overdue"),
-                            state: State::Open,
+                            state: State::from("Open"),
                             time_created: date!(2025-02-09),
                             due_date: Some(date!(2025-03-24)),
+                            ..Default::default()
                         },
                         Issue {
                             description: Description::from("An issue in review"),
-                            state: State::Review,
+                            state: State::from("Review"),
                             time_created: DEFAULT_FAKE_TODAY,
                             due_date: None,
+                            ..Default::default()
                         },
 
                     ].into_iter()
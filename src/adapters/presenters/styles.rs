@@ -0,0 +1,154 @@
+use std::str::FromStr;
+use colored::{Color, ColoredString, Colorize};
+use crate::application::domain::issue::IssueCategory;
+
+/// Looks of a line of text: a color and text attributes, e.g. `bright red bold`
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub(crate) struct Style {
+    color: Option<Color>,
+    bold: bool,
+    italic: bool,
+    underline: bool,
+}
+
+impl Style {
+    pub fn color(color: Color) -> Self {
+        Self {
+            color: Some(color),
+            ..Default::default()
+        }
+    }
+
+    /// Whether the style leaves the text as it is
+    pub fn is_plain(&self) -> bool {
+        *self == Self::default()
+    }
+
+    pub fn apply(&self, text: &str) -> ColoredString {
+        let mut styled = match self.color {
+            Some(color) => text.color(color),
+            None => text.normal(),
+        };
+        if self.bold {
+            styled = styled.bold();
+        }
+        if self.italic {
+            styled = styled.italic();
+        }
+        if self.underline {
+            styled = styled.underline();
+        }
+        styled
+    }
+}
+
+impl FromStr for Style {
+    type Err = String;
+
+    /// Parses the words of a style: the attributes `bold`, `italic` and `underline`, and a color
+    /// name. `none` is the plain style.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut style = Self::default();
+        let mut color_words = Vec::new();
+
+        for word in s.split_whitespace().map(str::to_lowercase) {
+            match word.as_str() {
+                "none" => {},
+                "bold" => style.bold = true,
+                "italic" => style.italic = true,
+                "underline" => style.underline = true,
+                _ => color_words.push(word),
+            }
+        }
+
+        if !color_words.is_empty() {
+            let color_name = color_words.join(" ");
+            let color = Color::from_str(&color_name).map_err(|_| format!("unknown color `{}`", color_name))?;
+            style.color = Some(color);
+        }
+
+        Ok(style)
+    }
+}
+
+/// The style of the issue lines of each category
+#[derive(Debug, PartialEq, Clone)]
+pub(crate) struct CategoryStyles {
+    pub normal: Style,
+    pub overdue: Style,
+    pub due_today: Style,
+    pub due_soon: Style,
+    pub blocked: Style,
+}
+
+impl Default for CategoryStyles {
+    fn default() -> Self {
+        Self {
+            normal: Style::default(),
+            overdue: Style::color(Color::Red),
+            due_today: Style::color(Color::Yellow),
+            due_soon: Style::color(Color::Blue),
+            blocked: Style::color(Color::BrightBlack),
+        }
+    }
+}
+
+impl CategoryStyles {
+    /// Overrides the styles by a comma separated list of `category=style`, e.g.
+    /// `overdue=magenta bold, due-soon=none`. Unknown categories and invalid styles are ignored,
+    /// so that a typo does not make the board unreadable.
+    pub fn with_overrides(mut self, overrides: &str) -> Self {
+        for (category, style) in overrides.split(',').filter_map(|entry| entry.split_once('=')) {
+            let Ok(style) = style.parse::<Style>() else {
+                continue;
+            };
+
+            match category.trim().to_lowercase().as_str() {
+                "normal" => self.normal = style,
+                "overdue" => self.overdue = style,
+                "due-today" => self.due_today = style,
+                "due-soon" => self.due_soon = style,
+                "blocked" => self.blocked = style,
+                _ => {},
+            }
+        }
+
+        self
+    }
+
+    pub fn of(&self, category: &IssueCategory) -> &Style {
+        match category {
+            IssueCategory::Normal => &self.normal,
+            IssueCategory::Overdue => &self.overdue,
+            IssueCategory::DueToday => &self.due_today,
+            IssueCategory::DueSoon => &self.due_soon,
+            IssueCategory::Blocked => &self.blocked,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert2::{check, let_assert};
+    use colored::Color;
+    use crate::adapters::presenters::styles::{CategoryStyles, Style};
+
+    #[test]
+    fn test_parse_style() {
+        let_assert!(Ok(style) = "Bright Red bold underline".parse::<Style>());
+        check!(style == Style { color: Some(Color::BrightRed), bold: true, italic: false, underline: true });
+        check!("none".parse::<Style>() == Ok(Style::default()));
+        check!("blinking".parse::<Style>() == Err(String::from("unknown color `blinking`")));
+    }
+
+    #[test]
+    fn test_override_styles() {
+        let styles = CategoryStyles::default().with_overrides("overdue=magenta bold, due-soon=none,due-today=blinking,unknown=red");
+
+        check!(styles == CategoryStyles {
+            overdue: Style { color: Some(Color::Magenta), bold: true, ..Default::default() },
+            due_soon: Style::default(),
+            ..CategoryStyles::default()
+        });
+    }
+}
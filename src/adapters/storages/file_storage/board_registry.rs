@@ -0,0 +1,218 @@
+use std::fs;
+use std::path::PathBuf;
+use home::home_dir;
+use crate::adapters::storages::file_storage::serde_resources::StoredSettings;
+use crate::adapters::storages::file_storage::FileStorage;
+use crate::adapters::storages::IssueStorage;
+use crate::application::Issue;
+use crate::application::domain::board_name::BoardName;
+use crate::application::domain::historized_board::HistorizedBoard;
+use crate::application::ports::board_registry::BoardRegistry;
+
+const BOARDS_DIRECTORY: &str = ".kanban-boards";
+const BOARD_EXTENSION: &str = "yaml";
+/// Board names cannot start with a dot, so the settings never clash with a board
+const SETTINGS_FILE: &str = ".settings.yaml";
+
+/// The main board is stored in `~/.kanban`, like before there were multiple boards. The other
+/// boards are stored next to each other in `~/.kanban-boards`.
+#[derive(Clone)]
+pub struct FileBoardRegistry {
+    pub home: PathBuf,
+
+    /// The board selected for this run with `--board`
+    pub selected: Option<BoardName>,
+}
+
+impl Default for FileBoardRegistry {
+    fn default() -> Self {
+        FileBoardRegistry {
+            home: home_dir().expect("Failed to get home directory"),
+            selected: None,
+        }
+    }
+}
+
+impl FileBoardRegistry {
+    pub fn board_path(&self, name: &BoardName) -> PathBuf {
+        if name.is_main() {
+            self.home.join(".kanban")
+        } else {
+            self.boards_directory().join(format!("{}.{}", name, BOARD_EXTENSION))
+        }
+    }
+
+    fn boards_directory(&self) -> PathBuf {
+        self.home.join(BOARDS_DIRECTORY)
+    }
+
+    fn storage(&self, name: &BoardName) -> FileStorage {
+        FileStorage {
+            source: self.board_path(name),
+        }
+    }
+
+    /// The storage of the board that the commands work on, see [BoardRegistry::active_board]
+    pub fn active_storage(&self) -> FileStorage {
+        self.storage(&self.active_board())
+    }
+
+    fn load_settings(&self) -> StoredSettings {
+        fs::read_to_string(self.boards_directory().join(SETTINGS_FILE))
+            .ok()
+            .filter(|content| !content.is_empty())
+            .map(|content| serde_yaml::from_str(&content).expect("unexpected settings file format"))
+            .unwrap_or_default()
+    }
+
+    fn save_settings(&self, settings: &StoredSettings) {
+        let content = serde_yaml::to_string(settings)
+            .expect("Internal error: cannot serialize settings");
+
+        fs::create_dir_all(self.boards_directory()).expect("cannot create boards directory");
+        fs::write(self.boards_directory().join(SETTINGS_FILE), content).expect("cannot write settings");
+    }
+}
+
+impl BoardRegistry for FileBoardRegistry {
+    fn boards(&self) -> Vec<BoardName> {
+        let other_boards = fs::read_dir(self.boards_directory())
+            .into_iter()
+            .flatten()
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.path())
+            .filter(|path| path.extension().is_some_and(|extension| extension == BOARD_EXTENSION))
+            // Archives and other files whose name is not a valid board name are skipped
+            .filter_map(|path| path.file_stem().and_then(|stem| stem.to_str()).and_then(|stem| BoardName::try_from(stem).ok()))
+            .filter(|name| !name.is_main());
+
+        let mut boards = std::iter::once(BoardName::main())
+            .chain(other_boards)
+            .collect::<Vec<_>>();
+        boards.sort();
+        boards
+    }
+
+    fn default_board(&self) -> BoardName {
+        self.load_settings().default_board
+            .and_then(|name| BoardName::try_from(name.as_str()).ok())
+            .unwrap_or_default()
+    }
+
+    fn set_default_board(&self, name: &BoardName) {
+        self.save_settings(&StoredSettings {
+            default_board: Some(name.to_string()).filter(|_| !name.is_main()),
+        });
+    }
+
+    fn active_board(&self) -> BoardName {
+        self.selected.clone().unwrap_or_else(|| self.default_board())
+    }
+
+    fn load(&self, name: &BoardName) -> HistorizedBoard<Issue> {
+        self.storage(name).load()
+    }
+
+    fn save(&self, name: &BoardName, board: &HistorizedBoard<Issue>) {
+        fs::create_dir_all(self.boards_directory()).expect("cannot create boards directory");
+        self.storage(name).save(board)
+    }
+
+    fn rename(&self, from: &BoardName, to: &BoardName) {
+        self.storage(from).rename(&self.storage(to));
+
+        if self.default_board() == *from {
+            self.set_default_board(to);
+        }
+    }
+
+    fn delete(&self, name: &BoardName) {
+        self.storage(name).delete();
+
+        if self.default_board() == *name {
+            self.set_default_board(&BoardName::main());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert2::check;
+    use crate::adapters::storages::file_storage::board_registry::FileBoardRegistry;
+    use crate::application::Issue;
+    use crate::application::domain::board_name::BoardName;
+    use crate::application::domain::historized_board::HistorizedBoard;
+    use crate::application::issue::Description;
+    use crate::application::ports::board_registry::BoardRegistry;
+
+    #[test]
+    fn test_boards_are_stored_in_their_own_files() {
+        let home = tempfile::tempdir().unwrap();
+        let registry = FileBoardRegistry {
+            home: home.path().to_path_buf(),
+            selected: None,
+        };
+        let work = BoardName::try_from("work").unwrap();
+
+        check!(registry.boards() == vec![BoardName::main()]);
+
+        registry.save(&work, &HistorizedBoard::default().with_issue(Issue {
+            description: Description::from("Fix login"),
+            ..Default::default()
+        }));
+        registry.save(&BoardName::try_from("personal").unwrap(), &HistorizedBoard::default());
+
+        check!(home.path().join(".kanban-boards/work.yaml").exists());
+        check!(registry.boards() == vec![BoardName::main(), BoardName::try_from("personal").unwrap(), work.clone()]);
+        check!(registry.load(&work).entities().len() == 1);
+        check!(registry.load(&BoardName::main()).entities().is_empty());
+    }
+
+    #[test]
+    fn test_archive_follows_rename_and_delete() {
+        let home = tempfile::tempdir().unwrap();
+        let registry = FileBoardRegistry {
+            home: home.path().to_path_buf(),
+            selected: None,
+        };
+        let mut board = HistorizedBoard::default();
+        board.set_archived_entities(vec![Issue {
+            description: Description::from("Fix login"),
+            ..Default::default()
+        }]);
+
+        registry.save(&BoardName::try_from("work").unwrap(), &board);
+        registry.rename(&BoardName::try_from("work").unwrap(), &BoardName::try_from("office").unwrap());
+
+        check!(registry.boards() == vec![BoardName::main(), BoardName::try_from("office").unwrap()], "Expected the archive not to be a board");
+        check!(registry.load(&BoardName::try_from("office").unwrap()).get_archived_entities().len() == 1);
+
+        registry.delete(&BoardName::try_from("office").unwrap());
+        check!(home.path().join(".kanban-boards").read_dir().unwrap().count() == 0);
+    }
+
+    #[test]
+    fn test_default_board_follows_rename_and_delete() {
+        let home = tempfile::tempdir().unwrap();
+        let registry = FileBoardRegistry {
+            home: home.path().to_path_buf(),
+            selected: None,
+        };
+        let work = BoardName::try_from("work").unwrap();
+        let office = BoardName::try_from("office").unwrap();
+
+        check!(registry.default_board() == BoardName::main());
+
+        registry.save(&work, &HistorizedBoard::default());
+        registry.set_default_board(&work);
+        check!(registry.default_board() == work);
+
+        registry.rename(&work, &office);
+        check!(registry.boards() == vec![BoardName::main(), office.clone()]);
+        check!(registry.default_board() == office);
+
+        registry.delete(&office);
+        check!(registry.boards() == vec![BoardName::main()]);
+        check!(registry.default_board() == BoardName::main());
+    }
+}
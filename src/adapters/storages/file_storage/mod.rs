@@ -1,4 +1,6 @@
 mod storage;
 mod serde_resources;
+mod board_registry;
 
 pub use storage::FileStorage;
+pub use board_registry::FileBoardRegistry;
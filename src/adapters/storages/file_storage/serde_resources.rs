@@ -1,19 +1,51 @@
+use std::collections::BTreeMap;
 use serde::{Deserialize, Serialize};
 use std::ops::Deref;
 use crate::application::{Issue, State};
+use crate::application::domain::board_name::BoardName;
+use crate::application::domain::activity::{ActivityEvent, ActivityKind};
 use crate::application::domain::historized_board::HistorizedBoard;
-use crate::application::domain::history::{DeleteHistoryElement, DeleteHistoryElements, DueHistoryElement, EditHistoryElement, FlushHistoryElement, MoveHistoryElement, MoveHistoryElements, PrioHistoryElement, UndoableHistoryElement};
-use crate::application::issue::Description;
+use crate::application::domain::date_parse::Recurrence;
+use crate::application::domain::history::{AddTemplateHistoryElement, ArchiveHistoryElement, ArchiveHistoryElements, AssignHistoryElement, ChecklistHistoryElement, CommentHistoryElement, DeleteHistoryElement, DependencyHistoryElement, DeleteHistoryElements, DueHistoryElement, EditHistoryElement, EstimateHistoryElement, FieldsHistoryElement, FlushHistoryElement, LinkHistoryElement, BlockHistoryElement, ParentHistoryElement, MoveHistoryElement, MoveHistoryElements, PrioHistoryElement, PriorityLevelHistoryElement, RecurHistoryElement, TagHistoryElement, TransferHistoryElement, UnarchiveHistoryElement, UndoableHistoryElement};
+use crate::application::domain::template::{Template, TemplateIssue};
+use crate::application::domain::custom_field::{CustomField, FieldType};
+use crate::application::domain::column::{Column, Columns};
+use crate::application::domain::time_tracking::WorkInterval;
+use crate::application::issue::{Assignee, ChecklistItem, Description, Link, Priority, ShortId, Tag, DEFAULT_STALE_AFTER_DAYS};
 
 #[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct StoredBoard {
+    /// Boards created before columns were configurable have the default columns
+    #[serde(default = "default_columns")]
+    columns: Vec<StoredColumn>,
+
+    /// Short id of the next added issue. Boards created before short ids existed do not have it.
+    #[serde(default)]
+    next_short_id: u32,
+
     #[serde(default)]
     issues: Vec<StoredIssue>,
 
     #[serde(default)]
     deleted_issues: Vec<StoredIssue>,
 
+    /// Done issues are archived when they have been done for this many days
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    archive_after_days: Option<u32>,
+
+    /// Issues due within this many days are due soon
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    due_soon_days: Option<u32>,
+
+    /// Recipes for issues that are added over and over
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    templates: Vec<StoredTemplate>,
+
+    /// Metadata that the issues of the board can have
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    fields: Vec<StoredCustomField>,
+
     #[serde(default)]
     history: Vec<StoredUndoableHistoryElement>,
 }
@@ -21,8 +53,14 @@ pub struct StoredBoard {
 impl From<&HistorizedBoard<Issue>> for StoredBoard {
     fn from(b: &HistorizedBoard<Issue>) -> Self {
         Self {
+            columns: b.columns().iter().map(StoredColumn::from).collect(),
+            next_short_id: b.short_id_counter(),
             issues: b.entities().into_iter().map(|e| StoredIssue::from(e.deref())).collect(),
             deleted_issues: b.get_deleted_entities().into_iter().map(|e| StoredIssue::from(e.deref())).collect(),
+            archive_after_days: b.archive_after_days(),
+            due_soon_days: b.due_soon_days(),
+            templates: b.templates().iter().map(StoredTemplate::from).collect(),
+            fields: b.fields().iter().map(StoredCustomField::from).collect(),
             history: b.history.stack.iter().map(|x| x.into()).collect(),
         }
     }
@@ -30,11 +68,24 @@ impl From<&HistorizedBoard<Issue>> for StoredBoard {
 
 impl Into<HistorizedBoard<Issue>> for StoredBoard {
     fn into(self) -> HistorizedBoard<Issue> {
-        HistorizedBoard::new(
+        let mut board = HistorizedBoard::new(
             self.issues.into_iter().map(|x| x.into()).collect(),
             self.deleted_issues.into_iter().map(|x| x.into()).collect(),
             self.history.into_iter().map(|x| x.into()).collect(),
-        )
+        );
+
+        let columns = Columns::new(self.columns.into_iter().map(|x| x.into()).collect())
+            .expect("invalid columns in board file");
+        board.set_columns(columns);
+        board.set_archive_after_days(self.archive_after_days);
+        board.set_due_soon_days(self.due_soon_days);
+        board.set_templates(self.templates.into_iter().map(|x| x.into()).collect());
+        board.set_fields(self.fields.into_iter().map(|x| x.into()).collect());
+
+        board.set_short_id_counter(self.next_short_id);
+        board.assign_missing_short_ids();
+
+        board
     }
 }
 
@@ -50,6 +101,70 @@ pub struct StoredIssue {
 
     /// Date when the issue is due
     due_date: Option<time::Date>,
+
+    /// Date until which the issue is hidden from the board
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    wait_until: Option<time::Date>,
+
+    /// Tags of the issue
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    tags: Vec<String>,
+
+    /// Stable short id of the issue
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    short_id: Option<u32>,
+
+    /// Checklist of the issue
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    checklist: Vec<StoredChecklistItem>,
+
+    /// Short ids of the issues that block the issue
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    blocked_by: Vec<u32>,
+
+    /// Why the issue is blocked
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    blocked_reason: Option<String>,
+
+    /// Short id of the parent of the issue
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    parent: Option<u32>,
+
+    /// Multi-line notes of the issue
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    body: Option<String>,
+
+    /// What happened to the issue
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    activity: Vec<StoredActivityEvent>,
+
+    /// Recurrence rule of the issue, in its textual form (e.g. `every monday`)
+    #[serde(default, with = "stored_recurrence", skip_serializing_if = "Option::is_none")]
+    recurrence: Option<Recurrence>,
+
+    /// Estimated size of the issue
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    estimate: Option<u32>,
+
+    /// Tracked periods of work on the issue
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    work_log: Vec<StoredWorkInterval>,
+
+    /// Name of the person who owns the issue
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    assignee: Option<String>,
+
+    /// Priority level of the issue
+    #[serde(default, skip_serializing_if = "StoredPriority::is_normal")]
+    priority: StoredPriority,
+
+    /// URLs and file paths related to the issue
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    links: Vec<StoredLink>,
+
+    /// Values of the custom fields of the issue, by the name of the field
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    fields: BTreeMap<String, String>,
 }
 
 impl Into<Issue> for StoredIssue {
@@ -59,6 +174,22 @@ impl Into<Issue> for StoredIssue {
             state: self.state.into(),
             time_created: self.time_created,
             due_date: self.due_date,
+            wait_until: self.wait_until,
+            tags: self.tags.iter().map(|t| Tag::from(t.as_str())).collect(),
+            short_id: self.short_id.map(ShortId),
+            checklist: self.checklist.into_iter().map(|x| x.into()).collect(),
+            blocked_by: self.blocked_by.into_iter().map(ShortId).collect(),
+            blocked_reason: self.blocked_reason,
+            parent: self.parent.map(ShortId),
+            body: self.body,
+            activity: self.activity.into_iter().map(|x| x.into()).collect(),
+            recurrence: self.recurrence,
+            estimate: self.estimate,
+            work_log: self.work_log.into_iter().map(|x| x.into()).collect(),
+            assignee: self.assignee.as_deref().map(Assignee::from),
+            priority: self.priority.into(),
+            links: self.links.into_iter().map(|x| x.into()).collect(),
+            fields: self.fields,
         }
     }
 }
@@ -67,9 +198,231 @@ impl From<&Issue> for StoredIssue {
     fn from(issue: &Issue) -> Self {
         Self {
             description: issue.description.to_string(),
-            state: issue.state.into(),
+            state: issue.state.clone().into(),
             time_created: issue.time_created,
             due_date: issue.due_date,
+            wait_until: issue.wait_until,
+            tags: issue.tags.iter().map(|t| t.to_string()).collect(),
+            short_id: issue.short_id.map(|ShortId(id)| id),
+            checklist: issue.checklist.iter().map(StoredChecklistItem::from).collect(),
+            blocked_by: issue.blocked_by.iter().map(|&ShortId(id)| id).collect(),
+            blocked_reason: issue.blocked_reason.clone(),
+            parent: issue.parent.map(|ShortId(id)| id),
+            body: issue.body.clone(),
+            activity: issue.activity.iter().map(StoredActivityEvent::from).collect(),
+            recurrence: issue.recurrence,
+            estimate: issue.estimate,
+            work_log: issue.work_log.iter().map(StoredWorkInterval::from).collect(),
+            assignee: issue.assignee.as_ref().map(Assignee::to_string),
+            priority: issue.priority.into(),
+            links: issue.links.iter().map(StoredLink::from).collect(),
+            fields: issue.fields.clone(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum StoredPriority {
+    Low,
+    #[default]
+    Normal,
+    High,
+    Critical,
+}
+
+impl StoredPriority {
+    fn is_normal(&self) -> bool {
+        *self == StoredPriority::Normal
+    }
+}
+
+impl From<StoredPriority> for Priority {
+    fn from(value: StoredPriority) -> Self {
+        match value {
+            StoredPriority::Low => Priority::Low,
+            StoredPriority::Normal => Priority::Normal,
+            StoredPriority::High => Priority::High,
+            StoredPriority::Critical => Priority::Critical,
+        }
+    }
+}
+
+impl From<Priority> for StoredPriority {
+    fn from(priority: Priority) -> Self {
+        match priority {
+            Priority::Low => StoredPriority::Low,
+            Priority::Normal => StoredPriority::Normal,
+            Priority::High => StoredPriority::High,
+            Priority::Critical => StoredPriority::Critical,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct StoredWorkInterval {
+    #[serde(with = "time::serde::rfc3339")]
+    start: time::OffsetDateTime,
+    /// Missing, when the timer is running
+    #[serde(default, with = "time::serde::rfc3339::option", skip_serializing_if = "Option::is_none")]
+    end: Option<time::OffsetDateTime>,
+}
+
+impl From<StoredWorkInterval> for WorkInterval {
+    fn from(value: StoredWorkInterval) -> Self {
+        WorkInterval {
+            start: value.start,
+            end: value.end,
+        }
+    }
+}
+
+impl From<&WorkInterval> for StoredWorkInterval {
+    fn from(interval: &WorkInterval) -> Self {
+        Self {
+            start: interval.start,
+            end: interval.end,
+        }
+    }
+}
+
+/// Recurrences are stored in the same textual form, in which they are given on the command line.
+mod stored_recurrence {
+    use serde::{de, Deserialize, Deserializer, Serializer};
+    use crate::application::domain::date_parse::Recurrence;
+
+    pub fn serialize<S: Serializer>(recurrence: &Option<Recurrence>, serializer: S) -> Result<S::Ok, S::Error> {
+        match recurrence {
+            Some(recurrence) => serializer.serialize_some(&recurrence.to_string()),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<Recurrence>, D::Error> {
+        Option::<String>::deserialize(deserializer)?
+            .map(|text| text.parse().map_err(de::Error::custom))
+            .transpose()
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct StoredActivityEvent {
+    #[serde(with = "time::serde::rfc3339")]
+    time: time::OffsetDateTime,
+    event: StoredActivityKind,
+}
+
+impl From<StoredActivityEvent> for ActivityEvent {
+    fn from(value: StoredActivityEvent) -> Self {
+        ActivityEvent::new(value.time, value.event.into())
+    }
+}
+
+impl From<&ActivityEvent> for StoredActivityEvent {
+    fn from(event: &ActivityEvent) -> Self {
+        Self {
+            time: event.time,
+            event: (&event.kind).into(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub enum StoredActivityKind {
+    Created,
+    Moved {
+        from: StoredState,
+        to: StoredState,
+    },
+    TransitionForced {
+        from: StoredState,
+        to: StoredState,
+    },
+    DueChanged {
+        from: Option<time::Date>,
+        to: Option<time::Date>,
+    },
+    Edited,
+    Comment(String),
+    Blocked(String),
+    Unblocked,
+}
+
+impl From<StoredActivityKind> for ActivityKind {
+    fn from(value: StoredActivityKind) -> Self {
+        match value {
+            StoredActivityKind::Created => ActivityKind::Created,
+            StoredActivityKind::Moved { from, to } => ActivityKind::Moved { from: from.into(), to: to.into() },
+            StoredActivityKind::TransitionForced { from, to } => ActivityKind::TransitionForced { from: from.into(), to: to.into() },
+            StoredActivityKind::DueChanged { from, to } => ActivityKind::DueChanged { from, to },
+            StoredActivityKind::Edited => ActivityKind::Edited,
+            StoredActivityKind::Comment(text) => ActivityKind::Comment(text),
+            StoredActivityKind::Blocked(reason) => ActivityKind::Blocked(reason),
+            StoredActivityKind::Unblocked => ActivityKind::Unblocked,
+        }
+    }
+}
+
+impl From<&ActivityKind> for StoredActivityKind {
+    fn from(kind: &ActivityKind) -> Self {
+        match kind {
+            ActivityKind::Created => StoredActivityKind::Created,
+            ActivityKind::Moved { from, to } => StoredActivityKind::Moved { from: from.clone().into(), to: to.clone().into() },
+            ActivityKind::TransitionForced { from, to } => StoredActivityKind::TransitionForced { from: from.clone().into(), to: to.clone().into() },
+            ActivityKind::DueChanged { from, to } => StoredActivityKind::DueChanged { from: *from, to: *to },
+            ActivityKind::Edited => StoredActivityKind::Edited,
+            ActivityKind::Comment(text) => StoredActivityKind::Comment(text.clone()),
+            ActivityKind::Blocked(reason) => StoredActivityKind::Blocked(reason.clone()),
+            ActivityKind::Unblocked => StoredActivityKind::Unblocked,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct StoredChecklistItem {
+    text: String,
+    done: bool,
+}
+
+impl From<StoredChecklistItem> for ChecklistItem {
+    fn from(value: StoredChecklistItem) -> Self {
+        ChecklistItem {
+            text: value.text,
+            done: value.done,
+        }
+    }
+}
+
+impl From<&ChecklistItem> for StoredChecklistItem {
+    fn from(item: &ChecklistItem) -> Self {
+        Self {
+            text: item.text.clone(),
+            done: item.done,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct StoredLink {
+    target: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    label: Option<String>,
+}
+
+impl From<StoredLink> for Link {
+    fn from(value: StoredLink) -> Self {
+        Link {
+            target: value.target,
+            label: value.label,
+        }
+    }
+}
+
+impl From<&Link> for StoredLink {
+    fn from(link: &Link) -> Self {
+        Self {
+            target: link.target.clone(),
+            label: link.label.clone(),
         }
     }
 }
@@ -77,12 +430,19 @@ impl From<&Issue> for StoredIssue {
 #[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
 pub struct StoredMoveHistoryElements {
     pub moves: Vec<StoredMoveHistoryElement>,
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub created_occurrences: usize,
+}
+
+fn is_zero(number: &usize) -> bool {
+    *number == 0
 }
 
 impl From<&MoveHistoryElements> for StoredMoveHistoryElements {
     fn from(value: &MoveHistoryElements) -> Self {
         Self {
             moves: value.moves.iter().map(|x| x.into()).collect(),
+            created_occurrences: value.created_occurrences,
         }
     }
 }
@@ -91,6 +451,7 @@ impl Into<MoveHistoryElements> for StoredMoveHistoryElements {
     fn into(self) -> MoveHistoryElements {
         MoveHistoryElements {
             moves: self.moves.into_iter().map(|x| x.into()).collect(),
+            created_occurrences: self.created_occurrences,
         }
     }
 }
@@ -105,14 +466,19 @@ pub struct StoredMoveHistoryElement {
     /// It can happen that moving changes priorities.
     /// If it does, then new_index is different from original_index.
     pub new_index: usize,
+
+    /// The move broke the allowed transitions of the board
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub forced: bool,
 }
 
 impl From<&MoveHistoryElement> for StoredMoveHistoryElement {
     fn from(value: &MoveHistoryElement) -> Self {
         Self {
             original_index: value.original_index,
-            original_state: value.original_state.into(),
+            original_state: value.original_state.clone().into(),
             new_index: value.new_index,
+            forced: value.forced,
         }
     }
 }
@@ -123,6 +489,7 @@ impl Into<MoveHistoryElement> for StoredMoveHistoryElement {
             original_index: self.original_index,
             original_state: self.original_state.into(),
             new_index: self.new_index,
+            forced: self.forced,
         }
     }
 }
@@ -153,6 +520,8 @@ impl Into<PrioHistoryElement> for StoredPrioHistoryElement {
 #[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
 pub struct StoredEditHistoryElement {
     pub original_description: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub original_body: Option<String>,
     pub index: usize ,
 }
 
@@ -160,6 +529,7 @@ impl From<&EditHistoryElement> for StoredEditHistoryElement {
     fn from(value: &EditHistoryElement) -> Self {
         Self {
             original_description: value.original_description.clone(),
+            original_body: value.original_body.clone(),
             index: value.index,
         }
     }
@@ -169,6 +539,7 @@ impl Into<EditHistoryElement> for StoredEditHistoryElement {
     fn into(self) -> EditHistoryElement {
         EditHistoryElement {
             original_description: self.original_description.clone(),
+            original_body: self.original_body,
             index: self.index,
         }
     }
@@ -238,6 +609,73 @@ impl Into<DeleteHistoryElement> for StoredDeleteHistoryElement {
     }
 }
 
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct StoredArchiveHistoryElements {
+    pub archivals: Vec<StoredArchiveHistoryElement>,
+}
+
+impl From<&ArchiveHistoryElements> for StoredArchiveHistoryElements {
+    fn from(value: &ArchiveHistoryElements) -> Self {
+        Self {
+            archivals: value.archivals.iter().map(|x| x.into()).collect(),
+        }
+    }
+}
+
+impl From<StoredArchiveHistoryElements> for ArchiveHistoryElements {
+    fn from(value: StoredArchiveHistoryElements) -> Self {
+        ArchiveHistoryElements {
+            archivals: value.archivals.into_iter().map(|x| x.into()).collect(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct StoredArchiveHistoryElement {
+    /// The position in which the issue had been located just before it was archived.
+    pub(crate) original_position_in_issues: usize,
+}
+
+impl From<&ArchiveHistoryElement> for StoredArchiveHistoryElement {
+    fn from(value: &ArchiveHistoryElement) -> Self {
+        Self {
+            original_position_in_issues: value.original_position_in_issues,
+        }
+    }
+}
+
+impl From<StoredArchiveHistoryElement> for ArchiveHistoryElement {
+    fn from(value: StoredArchiveHistoryElement) -> Self {
+        ArchiveHistoryElement {
+            original_position_in_issues: value.original_position_in_issues,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct StoredUnarchiveHistoryElement {
+    pub(crate) index: usize,
+    pub(crate) original_position_in_archive: usize,
+}
+
+impl From<&UnarchiveHistoryElement> for StoredUnarchiveHistoryElement {
+    fn from(value: &UnarchiveHistoryElement) -> Self {
+        Self {
+            index: value.index,
+            original_position_in_archive: value.original_position_in_archive,
+        }
+    }
+}
+
+impl From<StoredUnarchiveHistoryElement> for UnarchiveHistoryElement {
+    fn from(value: StoredUnarchiveHistoryElement) -> Self {
+        UnarchiveHistoryElement {
+            index: value.index,
+            original_position_in_archive: value.original_position_in_archive,
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
 pub struct StoredDueHistoryElement {
     pub(crate) index: usize,
@@ -262,6 +700,361 @@ impl Into<DueHistoryElement> for StoredDueHistoryElement {
     }
 }
 
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct StoredTagHistoryElement {
+    pub(crate) index: usize,
+    pub(crate) original_tags: Vec<String>,
+}
+
+impl From<&TagHistoryElement> for StoredTagHistoryElement {
+    fn from(value: &TagHistoryElement) -> Self {
+        Self {
+            index: value.index,
+            original_tags: value.original_tags.iter().map(|t| t.to_string()).collect(),
+        }
+    }
+}
+
+impl From<StoredTagHistoryElement> for TagHistoryElement {
+    fn from(value: StoredTagHistoryElement) -> Self {
+        TagHistoryElement {
+            index: value.index,
+            original_tags: value.original_tags.iter().map(|t| Tag::from(t.as_str())).collect(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct StoredChecklistHistoryElement {
+    pub(crate) index: usize,
+    pub(crate) original_checklist: Vec<StoredChecklistItem>,
+}
+
+impl From<&ChecklistHistoryElement> for StoredChecklistHistoryElement {
+    fn from(value: &ChecklistHistoryElement) -> Self {
+        Self {
+            index: value.index,
+            original_checklist: value.original_checklist.iter().map(StoredChecklistItem::from).collect(),
+        }
+    }
+}
+
+impl From<StoredChecklistHistoryElement> for ChecklistHistoryElement {
+    fn from(value: StoredChecklistHistoryElement) -> Self {
+        ChecklistHistoryElement {
+            index: value.index,
+            original_checklist: value.original_checklist.into_iter().map(|x| x.into()).collect(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct StoredDependencyHistoryElement {
+    pub(crate) index: usize,
+    pub(crate) original_blocked_by: Vec<u32>,
+}
+
+impl From<&DependencyHistoryElement> for StoredDependencyHistoryElement {
+    fn from(value: &DependencyHistoryElement) -> Self {
+        Self {
+            index: value.index,
+            original_blocked_by: value.original_blocked_by.iter().map(|&ShortId(id)| id).collect(),
+        }
+    }
+}
+
+impl From<StoredDependencyHistoryElement> for DependencyHistoryElement {
+    fn from(value: StoredDependencyHistoryElement) -> Self {
+        DependencyHistoryElement {
+            index: value.index,
+            original_blocked_by: value.original_blocked_by.into_iter().map(ShortId).collect(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct StoredRecurHistoryElement {
+    pub(crate) index: usize,
+    #[serde(default, with = "stored_recurrence", skip_serializing_if = "Option::is_none")]
+    pub(crate) original_recurrence: Option<Recurrence>,
+}
+
+impl From<&RecurHistoryElement> for StoredRecurHistoryElement {
+    fn from(value: &RecurHistoryElement) -> Self {
+        Self {
+            index: value.index,
+            original_recurrence: value.original_recurrence,
+        }
+    }
+}
+
+impl From<StoredRecurHistoryElement> for RecurHistoryElement {
+    fn from(value: StoredRecurHistoryElement) -> Self {
+        RecurHistoryElement {
+            index: value.index,
+            original_recurrence: value.original_recurrence,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct StoredEstimateHistoryElement {
+    pub(crate) index: usize,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) original_estimate: Option<u32>,
+}
+
+impl From<&EstimateHistoryElement> for StoredEstimateHistoryElement {
+    fn from(value: &EstimateHistoryElement) -> Self {
+        Self {
+            index: value.index,
+            original_estimate: value.original_estimate,
+        }
+    }
+}
+
+impl From<StoredEstimateHistoryElement> for EstimateHistoryElement {
+    fn from(value: StoredEstimateHistoryElement) -> Self {
+        EstimateHistoryElement {
+            index: value.index,
+            original_estimate: value.original_estimate,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct StoredAssignHistoryElement {
+    pub(crate) index: usize,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) original_assignee: Option<String>,
+}
+
+impl From<&AssignHistoryElement> for StoredAssignHistoryElement {
+    fn from(value: &AssignHistoryElement) -> Self {
+        Self {
+            index: value.index,
+            original_assignee: value.original_assignee.as_ref().map(Assignee::to_string),
+        }
+    }
+}
+
+impl From<StoredAssignHistoryElement> for AssignHistoryElement {
+    fn from(value: StoredAssignHistoryElement) -> Self {
+        AssignHistoryElement {
+            index: value.index,
+            original_assignee: value.original_assignee.as_deref().map(Assignee::from),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct StoredPriorityLevelHistoryElement {
+    pub(crate) index: usize,
+    #[serde(default)]
+    pub(crate) original_priority: StoredPriority,
+}
+
+impl From<&PriorityLevelHistoryElement> for StoredPriorityLevelHistoryElement {
+    fn from(value: &PriorityLevelHistoryElement) -> Self {
+        Self {
+            index: value.index,
+            original_priority: value.original_priority.into(),
+        }
+    }
+}
+
+impl From<StoredPriorityLevelHistoryElement> for PriorityLevelHistoryElement {
+    fn from(value: StoredPriorityLevelHistoryElement) -> Self {
+        PriorityLevelHistoryElement {
+            index: value.index,
+            original_priority: value.original_priority.into(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct StoredTransferHistoryElement {
+    pub(crate) index: usize,
+    pub(crate) board: String,
+    pub(crate) short_id: u32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) original_short_id: Option<u32>,
+    pub(crate) original_state: StoredState,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub(crate) original_blocked_by: Vec<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) original_parent: Option<u32>,
+}
+
+impl From<&TransferHistoryElement> for StoredTransferHistoryElement {
+    fn from(value: &TransferHistoryElement) -> Self {
+        Self {
+            index: value.index,
+            board: value.board.to_string(),
+            short_id: value.short_id.0,
+            original_short_id: value.original_short_id.map(|ShortId(id)| id),
+            original_state: value.original_state.clone().into(),
+            original_blocked_by: value.original_blocked_by.iter().map(|&ShortId(id)| id).collect(),
+            original_parent: value.original_parent.map(|ShortId(id)| id),
+        }
+    }
+}
+
+impl From<StoredTransferHistoryElement> for TransferHistoryElement {
+    fn from(value: StoredTransferHistoryElement) -> Self {
+        TransferHistoryElement {
+            index: value.index,
+            board: BoardName::try_from(value.board.as_str()).expect("invalid board name in board file"),
+            short_id: ShortId(value.short_id),
+            original_short_id: value.original_short_id.map(ShortId),
+            original_state: value.original_state.into(),
+            original_blocked_by: value.original_blocked_by.into_iter().map(ShortId).collect(),
+            original_parent: value.original_parent.map(ShortId),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct StoredCommentHistoryElement {
+    pub(crate) index: usize,
+}
+
+impl From<&CommentHistoryElement> for StoredCommentHistoryElement {
+    fn from(value: &CommentHistoryElement) -> Self {
+        Self {
+            index: value.index,
+        }
+    }
+}
+
+impl From<StoredCommentHistoryElement> for CommentHistoryElement {
+    fn from(value: StoredCommentHistoryElement) -> Self {
+        CommentHistoryElement {
+            index: value.index,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct StoredLinkHistoryElement {
+    pub(crate) index: usize,
+    pub(crate) original_links: Vec<StoredLink>,
+}
+
+impl From<&LinkHistoryElement> for StoredLinkHistoryElement {
+    fn from(value: &LinkHistoryElement) -> Self {
+        Self {
+            index: value.index,
+            original_links: value.original_links.iter().map(StoredLink::from).collect(),
+        }
+    }
+}
+
+impl From<StoredLinkHistoryElement> for LinkHistoryElement {
+    fn from(value: StoredLinkHistoryElement) -> Self {
+        LinkHistoryElement {
+            index: value.index,
+            original_links: value.original_links.into_iter().map(|x| x.into()).collect(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct StoredParentHistoryElement {
+    pub(crate) index: usize,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) original_parent: Option<u32>,
+}
+
+impl From<&ParentHistoryElement> for StoredParentHistoryElement {
+    fn from(value: &ParentHistoryElement) -> Self {
+        Self {
+            index: value.index,
+            original_parent: value.original_parent.map(|ShortId(id)| id),
+        }
+    }
+}
+
+impl From<StoredParentHistoryElement> for ParentHistoryElement {
+    fn from(value: StoredParentHistoryElement) -> Self {
+        ParentHistoryElement {
+            index: value.index,
+            original_parent: value.original_parent.map(ShortId),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct StoredFieldsHistoryElement {
+    pub(crate) index: usize,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub(crate) original_fields: BTreeMap<String, String>,
+}
+
+impl From<&FieldsHistoryElement> for StoredFieldsHistoryElement {
+    fn from(value: &FieldsHistoryElement) -> Self {
+        Self {
+            index: value.index,
+            original_fields: value.original_fields.clone(),
+        }
+    }
+}
+
+impl From<StoredFieldsHistoryElement> for FieldsHistoryElement {
+    fn from(value: StoredFieldsHistoryElement) -> Self {
+        FieldsHistoryElement {
+            index: value.index,
+            original_fields: value.original_fields,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct StoredAddTemplateHistoryElement {
+    pub(crate) count: usize,
+}
+
+impl From<&AddTemplateHistoryElement> for StoredAddTemplateHistoryElement {
+    fn from(value: &AddTemplateHistoryElement) -> Self {
+        Self {
+            count: value.count,
+        }
+    }
+}
+
+impl From<StoredAddTemplateHistoryElement> for AddTemplateHistoryElement {
+    fn from(value: StoredAddTemplateHistoryElement) -> Self {
+        AddTemplateHistoryElement {
+            count: value.count,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct StoredBlockHistoryElement {
+    pub(crate) index: usize,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) original_reason: Option<String>,
+}
+
+impl From<&BlockHistoryElement> for StoredBlockHistoryElement {
+    fn from(value: &BlockHistoryElement) -> Self {
+        Self {
+            index: value.index,
+            original_reason: value.original_reason.clone(),
+        }
+    }
+}
+
+impl From<StoredBlockHistoryElement> for BlockHistoryElement {
+    fn from(value: StoredBlockHistoryElement) -> Self {
+        BlockHistoryElement {
+            index: value.index,
+            original_reason: value.original_reason,
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
 pub enum StoredUndoableHistoryElement {
     Add,
@@ -271,6 +1064,22 @@ pub enum StoredUndoableHistoryElement {
     Edit(StoredEditHistoryElement),
     Flush(StoredFlushHistoryElement),
     Due(StoredDueHistoryElement),
+    Tag(StoredTagHistoryElement),
+    Checklist(StoredChecklistHistoryElement),
+    Dependency(StoredDependencyHistoryElement),
+    Comment(StoredCommentHistoryElement),
+    Recur(StoredRecurHistoryElement),
+    Estimate(StoredEstimateHistoryElement),
+    Transfer(StoredTransferHistoryElement),
+    Assign(StoredAssignHistoryElement),
+    PriorityLevel(StoredPriorityLevelHistoryElement),
+    Archive(StoredArchiveHistoryElements),
+    Unarchive(StoredUnarchiveHistoryElement),
+    Link(StoredLinkHistoryElement),
+    Block(StoredBlockHistoryElement),
+    AddTemplate(StoredAddTemplateHistoryElement),
+    Parent(StoredParentHistoryElement),
+    Fields(StoredFieldsHistoryElement),
 }
 
 impl From<&UndoableHistoryElement> for StoredUndoableHistoryElement {
@@ -283,6 +1092,22 @@ impl From<&UndoableHistoryElement> for StoredUndoableHistoryElement {
             UndoableHistoryElement::Edit(e) => StoredUndoableHistoryElement::Edit(e.into()),
             UndoableHistoryElement::Flush(e) => StoredUndoableHistoryElement::Flush(e.into()),
             UndoableHistoryElement::Due(e) => StoredUndoableHistoryElement::Due(e.into()),
+            UndoableHistoryElement::Tag(e) => StoredUndoableHistoryElement::Tag(e.into()),
+            UndoableHistoryElement::Checklist(e) => StoredUndoableHistoryElement::Checklist(e.into()),
+            UndoableHistoryElement::Dependency(e) => StoredUndoableHistoryElement::Dependency(e.into()),
+            UndoableHistoryElement::Comment(e) => StoredUndoableHistoryElement::Comment(e.into()),
+            UndoableHistoryElement::Recur(e) => StoredUndoableHistoryElement::Recur(e.into()),
+            UndoableHistoryElement::Estimate(e) => StoredUndoableHistoryElement::Estimate(e.into()),
+            UndoableHistoryElement::Transfer(e) => StoredUndoableHistoryElement::Transfer(e.into()),
+            UndoableHistoryElement::Assign(e) => StoredUndoableHistoryElement::Assign(e.into()),
+            UndoableHistoryElement::PriorityLevel(e) => StoredUndoableHistoryElement::PriorityLevel(e.into()),
+            UndoableHistoryElement::Archive(e) => StoredUndoableHistoryElement::Archive(e.into()),
+            UndoableHistoryElement::Unarchive(e) => StoredUndoableHistoryElement::Unarchive(e.into()),
+            UndoableHistoryElement::Link(e) => StoredUndoableHistoryElement::Link(e.into()),
+            UndoableHistoryElement::Block(e) => StoredUndoableHistoryElement::Block(e.into()),
+            UndoableHistoryElement::AddTemplate(e) => StoredUndoableHistoryElement::AddTemplate(e.into()),
+            UndoableHistoryElement::Parent(e) => StoredUndoableHistoryElement::Parent(e.into()),
+            UndoableHistoryElement::Fields(e) => StoredUndoableHistoryElement::Fields(e.into()),
         }
     }
 }
@@ -297,35 +1122,255 @@ impl Into<UndoableHistoryElement> for StoredUndoableHistoryElement {
             StoredUndoableHistoryElement::Edit(e) => UndoableHistoryElement::Edit(e.into()),
             StoredUndoableHistoryElement::Flush(e) => UndoableHistoryElement::Flush(e.into()),
             StoredUndoableHistoryElement::Due(e) => UndoableHistoryElement::Due(e.into()),
+            StoredUndoableHistoryElement::Tag(e) => UndoableHistoryElement::Tag(e.into()),
+            StoredUndoableHistoryElement::Checklist(e) => UndoableHistoryElement::Checklist(e.into()),
+            StoredUndoableHistoryElement::Dependency(e) => UndoableHistoryElement::Dependency(e.into()),
+            StoredUndoableHistoryElement::Comment(e) => UndoableHistoryElement::Comment(e.into()),
+            StoredUndoableHistoryElement::Recur(e) => UndoableHistoryElement::Recur(e.into()),
+            StoredUndoableHistoryElement::Estimate(e) => UndoableHistoryElement::Estimate(e.into()),
+            StoredUndoableHistoryElement::Transfer(e) => UndoableHistoryElement::Transfer(e.into()),
+            StoredUndoableHistoryElement::Assign(e) => UndoableHistoryElement::Assign(e.into()),
+            StoredUndoableHistoryElement::PriorityLevel(e) => UndoableHistoryElement::PriorityLevel(e.into()),
+            StoredUndoableHistoryElement::Archive(e) => UndoableHistoryElement::Archive(e.into()),
+            StoredUndoableHistoryElement::Unarchive(e) => UndoableHistoryElement::Unarchive(e.into()),
+            StoredUndoableHistoryElement::Link(e) => UndoableHistoryElement::Link(e.into()),
+            StoredUndoableHistoryElement::Block(e) => UndoableHistoryElement::Block(e.into()),
+            StoredUndoableHistoryElement::AddTemplate(e) => UndoableHistoryElement::AddTemplate(e.into()),
+            StoredUndoableHistoryElement::Parent(e) => UndoableHistoryElement::Parent(e.into()),
+            StoredUndoableHistoryElement::Fields(e) => UndoableHistoryElement::Fields(e.into()),
         }
     }
 }
 
-#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Hash, Copy, Clone)]
+/// Settings shared by all the boards of the installation
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct StoredSettings {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) default_board: Option<String>,
+}
+
+/// The archive of a board, which is stored apart from the board so that the board stays small
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
 #[serde(rename_all = "camelCase")]
-pub enum StoredState {
-    Open,
-    Review,
-    Done,
+pub struct StoredArchive {
+    /// Completed work moved out of the active board, the most recently archived first
+    #[serde(default)]
+    issues: Vec<StoredIssue>,
+}
+
+impl From<&HistorizedBoard<Issue>> for StoredArchive {
+    fn from(b: &HistorizedBoard<Issue>) -> Self {
+        Self {
+            issues: b.get_archived_entities().iter().map(|e| StoredIssue::from(e.deref())).collect(),
+        }
+    }
+}
+
+impl StoredArchive {
+    /// The archived issues, the most recently archived first
+    pub fn into_issues(self) -> Vec<Issue> {
+        self.issues.into_iter().map(|x| x.into()).collect()
+    }
 }
 
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Hash, Clone)]
+#[serde(transparent)]
+pub struct StoredState(String);
+
 
 impl Into<State> for StoredState {
     fn into(self) -> State {
-        match self {
-            StoredState::Open => State::Open,
-            StoredState::Review => State::Review,
-            StoredState::Done => State::Done,
-        }
+        State::from(self.0.as_str())
     }
 }
 
 impl From<State> for StoredState {
     fn from(s: State) -> Self {
-        match s {
-            State::Open => StoredState::Open,
-            State::Review => StoredState::Review,
-            State::Done => StoredState::Done,
+        Self(s.to_string())
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct StoredColumn {
+    name: String,
+
+    /// Maximum number of issues in the column
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    wip_limit: Option<usize>,
+
+    /// Whether issues moved to the column are placed by their priority level
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    sort_by_priority: bool,
+
+    /// Names of the columns the issues of the column may be moved to
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    allowed_transitions: Option<Vec<String>>,
+
+    /// Issues in the column for longer than this many days are overdue. `null` turns it off.
+    #[serde(default = "default_stale_after_days", skip_serializing_if = "is_default_stale_after_days")]
+    stale_after_days: Option<u32>,
+}
+
+impl From<StoredColumn> for Column {
+    fn from(value: StoredColumn) -> Self {
+        Column::new(value.name.as_str())
+            .with_wip_limit(value.wip_limit)
+            .with_sort_by_priority(value.sort_by_priority)
+            .with_allowed_transitions(value.allowed_transitions
+                .map(|targets| targets.iter().map(|target| State::from(target.as_str())).collect()))
+            .with_stale_after_days(value.stale_after_days)
+    }
+}
+
+impl From<&Column> for StoredColumn {
+    fn from(column: &Column) -> Self {
+        Self {
+            name: column.name().to_string(),
+            wip_limit: column.wip_limit(),
+            sort_by_priority: column.sorts_by_priority(),
+            allowed_transitions: column.allowed_transitions()
+                .map(|targets| targets.iter().map(State::to_string).collect()),
+            stale_after_days: column.stale_after_days(),
+        }
+    }
+}
+
+fn default_stale_after_days() -> Option<u32> {
+    Some(DEFAULT_STALE_AFTER_DAYS)
+}
+
+fn is_default_stale_after_days(days: &Option<u32>) -> bool {
+    *days == default_stale_after_days()
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct StoredTemplate {
+    name: String,
+    issues: Vec<StoredTemplateIssue>,
+}
+
+impl From<StoredTemplate> for Template {
+    fn from(value: StoredTemplate) -> Self {
+        Template {
+            name: value.name,
+            issues: value.issues.into_iter().map(|x| x.into()).collect(),
+        }
+    }
+}
+
+impl From<&Template> for StoredTemplate {
+    fn from(template: &Template) -> Self {
+        Self {
+            name: template.name.clone(),
+            issues: template.issues.iter().map(StoredTemplateIssue::from).collect(),
         }
     }
-}
\ No newline at end of file
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct StoredTemplateIssue {
+    /// Description of the issue, `{}` stands for the text given when the template is used
+    description: String,
+
+    /// Column of the issue, the first column by default
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    state: Option<String>,
+
+    /// Due date relative to the day the template is used, e.g. `in 7 days`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    due: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    tags: Vec<String>,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    checklist: Vec<String>,
+}
+
+impl From<StoredTemplateIssue> for TemplateIssue {
+    fn from(value: StoredTemplateIssue) -> Self {
+        TemplateIssue {
+            description: value.description,
+            state: value.state.as_deref().map(State::from),
+            due: value.due,
+            tags: value.tags.iter().map(|t| Tag::from(t.as_str())).collect(),
+            checklist: value.checklist,
+        }
+    }
+}
+
+impl From<&TemplateIssue> for StoredTemplateIssue {
+    fn from(issue: &TemplateIssue) -> Self {
+        Self {
+            description: issue.description.clone(),
+            state: issue.state.as_ref().map(State::to_string),
+            due: issue.due.clone(),
+            tags: issue.tags.iter().map(|t| t.to_string()).collect(),
+            checklist: issue.checklist.clone(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct StoredCustomField {
+    name: String,
+
+    #[serde(rename = "type")]
+    field_type: StoredFieldType,
+
+    /// Allowed values of an `enum` field
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    values: Vec<String>,
+
+    /// Whether the values of the field are shown on the board
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    show: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, Copy)]
+#[serde(rename_all = "lowercase")]
+pub enum StoredFieldType {
+    String,
+    Number,
+    Date,
+    Enum,
+}
+
+impl From<StoredCustomField> for CustomField {
+    fn from(value: StoredCustomField) -> Self {
+        CustomField {
+            name: value.name,
+            field_type: match value.field_type {
+                StoredFieldType::String => FieldType::Text,
+                StoredFieldType::Number => FieldType::Number,
+                StoredFieldType::Date => FieldType::Date,
+                StoredFieldType::Enum => FieldType::Enum(value.values),
+            },
+            show: value.show,
+        }
+    }
+}
+
+impl From<&CustomField> for StoredCustomField {
+    fn from(field: &CustomField) -> Self {
+        let (field_type, values) = match &field.field_type {
+            FieldType::Text => (StoredFieldType::String, Vec::new()),
+            FieldType::Number => (StoredFieldType::Number, Vec::new()),
+            FieldType::Date => (StoredFieldType::Date, Vec::new()),
+            FieldType::Enum(values) => (StoredFieldType::Enum, values.clone()),
+        };
+
+        Self {
+            name: field.name.clone(),
+            field_type,
+            values,
+            show: field.show,
+        }
+    }
+}
+
+fn default_columns() -> Vec<StoredColumn> {
+    Columns::default().iter().map(StoredColumn::from).collect()
+}
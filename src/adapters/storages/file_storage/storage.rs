@@ -1,9 +1,8 @@
 use std::fs;
-use std::io::Write;
-use std::path::PathBuf;
-use home::home_dir;
-use crate::adapters::storages::file_storage::serde_resources::StoredBoard;
-use crate::adapters::storages::IssueStorage;
+use std::io::{ErrorKind, Write};
+use std::path::{Path, PathBuf};
+use crate::adapters::storages::file_storage::serde_resources::{StoredArchive, StoredBoard};
+use crate::adapters::storages::{FileBoardRegistry, IssueStorage};
 use crate::application::Issue;
 use crate::application::domain::historized_board::HistorizedBoard;
 
@@ -11,12 +10,10 @@ pub struct FileStorage {
     pub source: PathBuf,
 }
 
+/// The board used when no board is selected, see [FileBoardRegistry::active_storage]
 impl Default for FileStorage {
     fn default() -> Self {
-        FileStorage {
-            source: home_dir().expect("Failed to get home directory")
-                .join(".kanban").into(),
-        }
+        FileBoardRegistry::default().active_storage()
     }
 }
 
@@ -25,14 +22,24 @@ impl IssueStorage for FileStorage {
         let file_contents = fs::read_to_string(&self.source)
             .unwrap_or_default();
 
-        if file_contents.is_empty() {
-            return HistorizedBoard::default();
-        }
+        let mut board = if file_contents.is_empty() {
+            HistorizedBoard::default()
+        } else {
+            serde_yaml::from_str::<StoredBoard>(&file_contents)
+                .expect("unexpected file format")
+                .into()
+        };
 
-        let stored_board = serde_yaml::from_str::<StoredBoard>(&file_contents)
-            .expect("unexpected file format");
+        let archive_contents = fs::read_to_string(self.archive_path())
+            .unwrap_or_default();
 
-        stored_board.into()
+        if !archive_contents.is_empty() {
+            let stored_archive = serde_yaml::from_str::<StoredArchive>(&archive_contents)
+                .expect("unexpected archive file format");
+            board.set_archived_entities(stored_archive.into_issues());
+        }
+
+        board
     }
 
     fn save(&self, board: &HistorizedBoard<Issue>) {
@@ -40,6 +47,14 @@ impl IssueStorage for FileStorage {
 
         let mut file = fs::File::create(&self.source).expect("cannot open file to write board");
         file.write_all(content.as_bytes()).expect("cannot write to file");
+
+        if board.get_archived_entities().is_empty() {
+            Self::remove_if_exists(&self.archive_path());
+        } else {
+            let archive_content = serde_yaml::to_string(&StoredArchive::from(board))
+                .expect("Internal error: cannot serialize archive");
+            fs::write(self.archive_path(), archive_content).expect("cannot write archive");
+        }
     }
 }
 
@@ -50,21 +65,59 @@ impl FileStorage {
             .expect("Internal error: cannot serialize board");
         content
     }
+
+    /// The archive is stored next to the board, e.g. in `~/.kanban.archive` for `~/.kanban`
+    fn archive_path(&self) -> PathBuf {
+        let mut path = self.source.clone().into_os_string();
+        path.push(".archive");
+        path.into()
+    }
+
+    /// Moves the board and its archive to the files of `to`
+    pub(crate) fn rename(&self, to: &FileStorage) {
+        fs::rename(&self.source, &to.source).expect("cannot rename board file");
+
+        match fs::rename(self.archive_path(), to.archive_path()) {
+            Err(e) if e.kind() != ErrorKind::NotFound => panic!("cannot rename archive file: {}", e),
+            _ => {},
+        }
+    }
+
+    /// Deletes the board together with its archive
+    pub(crate) fn delete(&self) {
+        Self::remove_if_exists(&self.source);
+        Self::remove_if_exists(&self.archive_path());
+    }
+
+    fn remove_if_exists(path: &Path) {
+        match fs::remove_file(path) {
+            Err(e) if e.kind() != ErrorKind::NotFound => panic!("cannot delete {}: {}", path.display(), e),
+            _ => {},
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use std::env::current_dir;
+    use std::fs;
+    use std::collections::BTreeMap;
     use std::ops::Deref;
-    use assert2::check;
-    use time::macros::date;
+    use assert2::{check, let_assert};
+    use time::macros::{date, datetime};
+    use crate::application::domain::activity::{ActivityEvent, ActivityKind};
+    use crate::application::domain::date_parse::Recurrence;
+    use crate::application::domain::time_tracking::WorkInterval;
     use crate::application::{Issue, State};
     use crate::adapters::storages::file_storage::FileStorage;
     use crate::adapters::storages::IssueStorage;
     use crate::application::board::test_utils::check_boards_are_equal;
+    use crate::application::domain::column::Columns;
     use crate::application::domain::historized_board::HistorizedBoard;
-    use crate::application::domain::history::{DeleteHistoryElement, DeleteHistoryElements, EditHistoryElement, MoveHistoryElement, MoveHistoryElements, PrioHistoryElement, UndoableHistoryElement};
-    use crate::application::issue::Description;
+    use crate::application::domain::history::{AddTemplateHistoryElement, ArchiveHistoryElement, ArchiveHistoryElements, AssignHistoryElement, BlockHistoryElement, ChecklistHistoryElement, CommentHistoryElement, DeleteHistoryElement, DependencyHistoryElement, DeleteHistoryElements, EditHistoryElement, EstimateHistoryElement, LinkHistoryElement, MoveHistoryElement, MoveHistoryElements, FieldsHistoryElement, ParentHistoryElement, PrioHistoryElement, PriorityLevelHistoryElement, RecurHistoryElement, TransferHistoryElement, UndoableHistoryElement};
+    use crate::application::domain::board_name::BoardName;
+    use crate::application::issue::{Assignee, ChecklistItem, Description, Link, Priority, ShortId, Tag};
+    use crate::adapters::storages::file_storage::serde_resources::StoredBoard;
 
     #[test]
     fn test_file_storage_load_non_existent_file_failed_no_permission() {
@@ -77,18 +130,24 @@ mod tests {
         check!(board.entity_count() == 2, "Expected board to have two issues");
         check!(board.get_deleted_entities().len() == 2, "Expected board to have 2 deleted issues");
         check!(board.history.stack.len() == 7, "Expected board to have a specific number of history elements");
+        check!(board.columns() == &Columns::default(), "Expected board without columns to have the default columns");
+        check!(board.short_id_counter() == 5, "Expected issues of a board without short ids to get short ids");
         [
             Issue {
                 description: Description::from("Get a coffee"),
-                state: State::Open,
+                state: State::from("Open"),
                 time_created: date!(2024-01-31),
                 due_date: None,
+                short_id: Some(ShortId(1)),
+                ..Default::default()
             },
             Issue {
                 description: Description::from("Take a break"),
-                state: State::Done,
+                state: State::from("Done"),
                 time_created: date!(2023-12-11),
                 due_date: None,
+                short_id: Some(ShortId(2)),
+                ..Default::default()
             },
         ].into_iter().zip(board.entities().iter()).for_each(|(expected_issue, actual_issue)| {
             assert_eq!(actual_issue.deref(), &expected_issue, "Expected specific loaded issues")
@@ -98,6 +157,7 @@ mod tests {
             UndoableHistoryElement::Add,
             UndoableHistoryElement::Edit(EditHistoryElement{
                 original_description: String::from("Don't get a coffee"),
+                original_body: None,
                 index: 0,
             }),
             UndoableHistoryElement::Delete(DeleteHistoryElements{
@@ -115,15 +175,41 @@ mod tests {
             UndoableHistoryElement::Move(MoveHistoryElements{
                 moves: vec![MoveHistoryElement{
                     original_index: 1,
-                    original_state: State::Open,
+                    original_state: State::from("Open"),
                     new_index: 1,
+                    forced: false,
                 }],
+                created_occurrences: 0,
             }),
         ];
 
         check!(board.history.stack == expected_history.as_slice(), "Expected specific history");
     }
 
+    #[test]
+    fn test_file_storage_load_board_with_custom_columns() {
+        let storage = FileStorage {
+            source: current_dir().unwrap().join("resources/test/custom_columns_board.yaml")
+        };
+
+        let board = storage.load();
+
+        let column_names = board.columns().iter().map(|c| c.name()).collect::<Vec<_>>();
+        check!(column_names == ["Backlog", "In Progress", "Review", "QA", "Done"]);
+        let wip_limits = board.columns().iter().map(|c| c.wip_limit()).collect::<Vec<_>>();
+        check!(wip_limits == [None, Some(3), None, None, None]);
+        let sorted_by_priority = board.columns().iter().map(|c| c.sorts_by_priority()).collect::<Vec<_>>();
+        check!(sorted_by_priority == [false, false, false, true, false]);
+        let_assert!(Some(qa) = board.columns().find(&State::from("QA")));
+        check!(qa.allowed_transitions() == Some([State::from("Review"), State::from("Done")].as_slice()));
+        check!(!qa.allows_transition_to(&State::from("Backlog")));
+        let stale_after_days = board.columns().iter().map(|c| c.stale_after_days()).collect::<Vec<_>>();
+        check!(stale_after_days == [None, Some(5), Some(13), Some(13), Some(13)]);
+        check!(board.due_soon_days() == Some(3));
+        check!(board.get_with_index(0).state.to_string() == "In Progress");
+        check!(board.get_with_index(1).state.to_string() == "QA");
+    }
+
     #[test]
     fn test_file_storage_load_non_existent_file_successful() {
         // Given
@@ -138,27 +224,471 @@ mod tests {
         check_boards_are_equal(&board, &HistorizedBoard::default())
     }
 
+    #[test]
+    fn test_tags_are_stored_and_loaded() {
+        let board = HistorizedBoard::default().with_issue(Issue {
+            description: Description::from("Fix login"),
+            state: State::from("Open"),
+            time_created: date!(2025-02-10),
+            due_date: None,
+            tags: vec![Tag::from("backend"), Tag::from("urgent")],
+            short_id: Some(ShortId(1)),
+            ..Default::default()
+        });
+
+        let yaml = FileStorage::board_to_yaml(&board);
+        check!(yaml.contains("    tags:\n      - backend\n      - urgent\n"));
+
+        let loaded_board: HistorizedBoard<Issue> = serde_yaml::from_str::<StoredBoard>(&yaml).unwrap().into();
+        check_boards_are_equal(&loaded_board, &board);
+    }
+
+    #[test]
+    fn test_checklist_is_stored_and_loaded() {
+        let mut board = HistorizedBoard::default().with_issue(Issue {
+            description: Description::from("Fix login"),
+            state: State::from("Open"),
+            time_created: date!(2025-02-10),
+            short_id: Some(ShortId(1)),
+            checklist: vec![
+                ChecklistItem { text: String::from("reproduce"), done: true },
+                ChecklistItem::new("write a test"),
+            ],
+            ..Default::default()
+        });
+        board.history.add(UndoableHistoryElement::Checklist(ChecklistHistoryElement {
+            index: 0,
+            original_checklist: vec![ChecklistItem::new("reproduce")],
+        }));
+
+        let yaml = FileStorage::board_to_yaml(&board);
+        check!(yaml.contains("    checklist:\n      - text: reproduce\n        done: true\n      - text: write a test\n        done: false\n"));
+
+        let loaded_board: HistorizedBoard<Issue> = serde_yaml::from_str::<StoredBoard>(&yaml).unwrap().into();
+        check_boards_are_equal(&loaded_board, &board);
+    }
+
+    #[test]
+    fn test_dependencies_are_stored_and_loaded() {
+        let mut board = HistorizedBoard::default().with_issue(Issue {
+            description: Description::from("Deploy"),
+            state: State::from("Open"),
+            time_created: date!(2025-02-10),
+            short_id: Some(ShortId(3)),
+            blocked_by: vec![ShortId(1), ShortId(2)],
+            ..Default::default()
+        });
+        board.history.add(UndoableHistoryElement::Dependency(DependencyHistoryElement {
+            index: 0,
+            original_blocked_by: vec![ShortId(1)],
+        }));
+
+        let yaml = FileStorage::board_to_yaml(&board);
+        check!(yaml.contains("    blockedBy:\n      - 1\n      - 2\n"));
+
+        let loaded_board: HistorizedBoard<Issue> = serde_yaml::from_str::<StoredBoard>(&yaml).unwrap().into();
+        check_boards_are_equal(&loaded_board, &board);
+    }
+
+    #[test]
+    fn test_body_is_stored_and_loaded() {
+        let mut board = HistorizedBoard::default().with_issue(Issue {
+            description: Description::from("Fix login"),
+            state: State::from("Open"),
+            time_created: date!(2025-02-10),
+            short_id: Some(ShortId(1)),
+            body: Some(String::from("Steps:\n  1. open the page\n  2. log in")),
+            ..Default::default()
+        });
+        board.history.add(UndoableHistoryElement::Edit(EditHistoryElement {
+            original_description: String::from("Fix the login"),
+            original_body: Some(String::from("Steps:")),
+            index: 0,
+        }));
+
+        let yaml = FileStorage::board_to_yaml(&board);
+
+        let loaded_board: HistorizedBoard<Issue> = serde_yaml::from_str::<StoredBoard>(&yaml).unwrap().into();
+        check_boards_are_equal(&loaded_board, &board);
+    }
+
+    #[test]
+    fn test_activity_is_stored_and_loaded() {
+        let mut board = HistorizedBoard::default().with_issue(Issue {
+            description: Description::from("Fix login"),
+            state: State::from("Review"),
+            time_created: date!(2025-02-10),
+            short_id: Some(ShortId(1)),
+            activity: vec![
+                ActivityEvent::new(datetime!(2025-02-10 09:15 UTC), ActivityKind::Created),
+                ActivityEvent::new(datetime!(2025-02-11 10:00 UTC), ActivityKind::Moved { from: State::from("Open"), to: State::from("Review") }),
+                ActivityEvent::new(datetime!(2025-02-11 10:05 UTC), ActivityKind::DueChanged { from: None, to: Some(date!(2025-02-20)) }),
+                ActivityEvent::new(datetime!(2025-02-12 08:00 UTC), ActivityKind::Edited),
+                ActivityEvent::new(datetime!(2025-02-12 16:30 UTC), ActivityKind::Comment(String::from("waiting on infra"))),
+            ],
+            ..Default::default()
+        });
+        board.history.add(UndoableHistoryElement::Comment(CommentHistoryElement {
+            index: 0,
+        }));
+
+        let yaml = FileStorage::board_to_yaml(&board);
+        check!(yaml.contains("      - time: \"2025-02-10T09:15:00Z\"\n        event: Created\n"));
+
+        let loaded_board: HistorizedBoard<Issue> = serde_yaml::from_str::<StoredBoard>(&yaml).unwrap().into();
+        check_boards_are_equal(&loaded_board, &board);
+    }
+
+    #[test]
+    fn test_recurrence_is_stored_and_loaded() {
+        let mut board = HistorizedBoard::default().with_issue(Issue {
+            description: Description::from("Weekly report"),
+            state: State::from("Open"),
+            time_created: date!(2025-02-10),
+            short_id: Some(ShortId(1)),
+            recurrence: Some(Recurrence::Weeks(2)),
+            ..Default::default()
+        });
+        board.history.add(UndoableHistoryElement::Recur(RecurHistoryElement {
+            index: 0,
+            original_recurrence: Some(Recurrence::MonthDay(1)),
+        }));
+        board.history.add(UndoableHistoryElement::Move(MoveHistoryElements {
+            moves: vec![MoveHistoryElement {
+                original_index: 1,
+                original_state: State::from("Review"),
+                new_index: 1,
+                forced: true,
+            }],
+            created_occurrences: 1,
+        }));
+
+        let yaml = FileStorage::board_to_yaml(&board);
+        check!(yaml.contains("    recurrence: every 2 weeks\n"));
+        check!(yaml.contains("forced: true\n"));
+        check!(yaml.contains("      original_recurrence: monthly on 1\n"));
+
+        let loaded_board: HistorizedBoard<Issue> = serde_yaml::from_str::<StoredBoard>(&yaml).unwrap().into();
+        check_boards_are_equal(&loaded_board, &board);
+    }
+
+    #[test]
+    fn test_estimate_is_stored_and_loaded() {
+        let mut board = HistorizedBoard::default().with_issue(Issue {
+            description: Description::from("Fix login"),
+            state: State::from("Open"),
+            time_created: date!(2025-02-10),
+            short_id: Some(ShortId(1)),
+            estimate: Some(5),
+            ..Default::default()
+        });
+        board.history.add(UndoableHistoryElement::Estimate(EstimateHistoryElement {
+            index: 0,
+            original_estimate: Some(3),
+        }));
+
+        let yaml = FileStorage::board_to_yaml(&board);
+        check!(yaml.contains("    estimate: 5\n"));
+
+        let loaded_board: HistorizedBoard<Issue> = serde_yaml::from_str::<StoredBoard>(&yaml).unwrap().into();
+        check_boards_are_equal(&loaded_board, &board);
+    }
+
+    #[test]
+    fn test_wait_date_is_stored_and_loaded() {
+        let board = HistorizedBoard::default().with_issue(Issue {
+            description: Description::from("Renew certificate"),
+            state: State::from("Open"),
+            time_created: date!(2025-02-10),
+            short_id: Some(ShortId(1)),
+            wait_until: Some(date!(2025-06-01)),
+            ..Default::default()
+        });
+
+        let yaml = FileStorage::board_to_yaml(&board);
+        check!(yaml.contains("    waitUntil: 2025-06-01\n"));
+
+        let loaded_board: HistorizedBoard<Issue> = serde_yaml::from_str::<StoredBoard>(&yaml).unwrap().into();
+        check_boards_are_equal(&loaded_board, &board);
+    }
+
+    #[test]
+    fn test_blocked_reason_is_stored_and_loaded() {
+        let mut board = HistorizedBoard::default().with_issue(Issue {
+            description: Description::from("Fix login"),
+            state: State::from("Open"),
+            time_created: date!(2025-02-10),
+            short_id: Some(ShortId(1)),
+            blocked_reason: Some(String::from("waiting for API keys")),
+            activity: vec![ActivityEvent::new(datetime!(2025-02-22 10:00 UTC), ActivityKind::Blocked(String::from("waiting for API keys")))],
+            ..Default::default()
+        });
+        board.history.add(UndoableHistoryElement::Block(BlockHistoryElement {
+            index: 0,
+            original_reason: None,
+        }));
+
+        let yaml = FileStorage::board_to_yaml(&board);
+        check!(yaml.contains("    blockedReason: waiting for API keys\n"));
+
+        let loaded_board: HistorizedBoard<Issue> = serde_yaml::from_str::<StoredBoard>(&yaml).unwrap().into();
+        check_boards_are_equal(&loaded_board, &board);
+    }
+
+    #[test]
+    fn test_parent_is_stored_and_loaded() {
+        let mut board = HistorizedBoard::default().with_issue(Issue {
+            description: Description::from("Fix login"),
+            state: State::from("Open"),
+            time_created: date!(2025-02-10),
+            short_id: Some(ShortId(13)),
+            parent: Some(ShortId(12)),
+            ..Default::default()
+        });
+        board.history.add(UndoableHistoryElement::Parent(ParentHistoryElement {
+            index: 0,
+            original_parent: None,
+        }));
+
+        let yaml = FileStorage::board_to_yaml(&board);
+        check!(yaml.contains("    parent: 12\n"));
+
+        let loaded_board: HistorizedBoard<Issue> = serde_yaml::from_str::<StoredBoard>(&yaml).unwrap().into();
+        check_boards_are_equal(&loaded_board, &board);
+    }
+
+    #[test]
+    fn test_custom_fields_are_stored_and_loaded() {
+        let mut board = HistorizedBoard::default().with_issue(Issue {
+            description: Description::from("Fix login"),
+            state: State::from("Open"),
+            time_created: date!(2025-02-10),
+            short_id: Some(ShortId(1)),
+            fields: BTreeMap::from([
+                (String::from("customer"), String::from("acme")),
+                (String::from("environment"), String::from("prod")),
+            ]),
+            ..Default::default()
+        }).with_custom_fields();
+        board.history.add(UndoableHistoryElement::Fields(FieldsHistoryElement {
+            index: 0,
+            original_fields: BTreeMap::from([(String::from("customer"), String::from("globex"))]),
+        }));
+
+        let yaml = FileStorage::board_to_yaml(&board);
+        check!(yaml.contains("fields:\n  - name: customer\n    type: string\n    show: true\n  - name: ticket\n    type: number\n"));
+        check!(yaml.contains("    type: enum\n    values:\n      - staging\n      - prod\n"));
+        check!(yaml.contains("    fields:\n      customer: acme\n      environment: prod\n"));
+
+        let loaded_board: HistorizedBoard<Issue> = serde_yaml::from_str::<StoredBoard>(&yaml).unwrap().into();
+        check_boards_are_equal(&loaded_board, &board);
+    }
+
+    #[test]
+    fn test_templates_are_stored_and_loaded() {
+        let mut board = HistorizedBoard::default().with_release_template();
+        board.history.add(UndoableHistoryElement::AddTemplate(AddTemplateHistoryElement {
+            count: 2,
+        }));
+
+        let yaml = FileStorage::board_to_yaml(&board);
+        check!(yaml.contains("templates:\n  - name: release\n    issues:\n      - description: \"Release {}\"\n        due: in 7 days\n"));
+
+        let loaded_board: HistorizedBoard<Issue> = serde_yaml::from_str::<StoredBoard>(&yaml).unwrap().into();
+        check_boards_are_equal(&loaded_board, &board);
+    }
+
+    #[test]
+    fn test_work_log_is_stored_and_loaded() {
+        let board = HistorizedBoard::default().with_issue(Issue {
+            description: Description::from("Fix login"),
+            state: State::from("Review"),
+            time_created: date!(2025-02-10),
+            short_id: Some(ShortId(1)),
+            work_log: vec![
+                WorkInterval { start: datetime!(2025-02-10 09:15 UTC), end: Some(datetime!(2025-02-10 10:40 UTC)) },
+                WorkInterval::started_at(datetime!(2025-02-11 08:00 UTC)),
+            ],
+            ..Default::default()
+        });
+
+        let yaml = FileStorage::board_to_yaml(&board);
+        check!(yaml.contains("    workLog:\n"));
+        check!(yaml.matches("end:").count() == 1, "Expected the running interval not to have an end");
+
+        let loaded_board: HistorizedBoard<Issue> = serde_yaml::from_str::<StoredBoard>(&yaml).unwrap().into();
+        check_boards_are_equal(&loaded_board, &board);
+        check!(loaded_board.get_with_index(0).is_tracked());
+    }
+
+    #[test]
+    fn test_assignee_is_stored_and_loaded() {
+        let mut board = HistorizedBoard::default().with_issue(Issue {
+            description: Description::from("Fix login"),
+            state: State::from("Open"),
+            time_created: date!(2025-02-10),
+            short_id: Some(ShortId(1)),
+            assignee: Some(Assignee::from("alice")),
+            ..Default::default()
+        });
+        board.history.add(UndoableHistoryElement::Assign(AssignHistoryElement {
+            index: 0,
+            original_assignee: None,
+        }));
+
+        let yaml = FileStorage::board_to_yaml(&board);
+        check!(yaml.contains("    assignee: alice\n"));
+
+        let loaded_board: HistorizedBoard<Issue> = serde_yaml::from_str::<StoredBoard>(&yaml).unwrap().into();
+        check_boards_are_equal(&loaded_board, &board);
+    }
+
+    #[test]
+    fn test_priority_is_stored_and_loaded() {
+        let mut board = HistorizedBoard::default()
+            .with_issue(Issue {
+                description: Description::from("Fix login"),
+                state: State::from("Open"),
+                time_created: date!(2025-02-10),
+                short_id: Some(ShortId(1)),
+                priority: Priority::Critical,
+                ..Default::default()
+            })
+            .with_issue(Issue {
+                description: Description::from("Restyle the login page"),
+                state: State::from("Open"),
+                time_created: date!(2025-02-10),
+                short_id: Some(ShortId(2)),
+                ..Default::default()
+            });
+        board.history.add(UndoableHistoryElement::PriorityLevel(PriorityLevelHistoryElement {
+            index: 0,
+            original_priority: Priority::Low,
+        }));
+
+        let yaml = FileStorage::board_to_yaml(&board);
+        check!(yaml.contains("    priority: critical\n"));
+        check!(yaml.matches(" priority:").count() == 1, "Expected the normal priority not to be stored");
+
+        let loaded_board: HistorizedBoard<Issue> = serde_yaml::from_str::<StoredBoard>(&yaml).unwrap().into();
+        check_boards_are_equal(&loaded_board, &board);
+    }
+
+    #[test]
+    fn test_links_are_stored_and_loaded() {
+        let mut board = HistorizedBoard::default()
+            .with_issue(Issue {
+                description: Description::from("Fix login"),
+                state: State::from("Open"),
+                time_created: date!(2025-02-10),
+                short_id: Some(ShortId(1)),
+                links: vec![
+                    Link::new("https://example.com/pr/42", Some("PR")),
+                    Link::new("~/designs/login.pdf", None),
+                ],
+                ..Default::default()
+            });
+        board.history.add(UndoableHistoryElement::Link(LinkHistoryElement {
+            index: 0,
+            original_links: vec![Link::new("https://example.com/pr/42", Some("PR"))],
+        }));
+
+        let yaml = FileStorage::board_to_yaml(&board);
+        check!(yaml.contains("    links:\n      - target: \"https://example.com/pr/42\"\n        label: PR\n      - target: ~/designs/login.pdf\n"));
+
+        let loaded_board: HistorizedBoard<Issue> = serde_yaml::from_str::<StoredBoard>(&yaml).unwrap().into();
+        check_boards_are_equal(&loaded_board, &board);
+    }
+
+    #[test]
+    fn test_archive_is_stored_and_loaded() {
+        let mut board = HistorizedBoard::default().with_4_typical_issues();
+        board.set_archived_entities(vec![
+            Issue { description: Description::from("Archived issue"), state: State::from("Done"), time_created: date!(2025-01-10), short_id: Some(ShortId(9)), ..Default::default() },
+        ]);
+        board.set_archive_after_days(Some(30));
+        board.assign_missing_short_ids();
+        board.history.add(UndoableHistoryElement::Archive(ArchiveHistoryElements {
+            archivals: vec![ArchiveHistoryElement { original_position_in_issues: 2 }],
+        }));
+
+        let directory = tempfile::tempdir().unwrap();
+        let storage = FileStorage {
+            source: directory.path().join(".kanban"),
+        };
+        storage.save(&board);
+
+        let yaml = fs::read_to_string(directory.path().join(".kanban")).unwrap();
+        check!(!yaml.contains("Archived issue"), "Expected the archive to be stored apart from the board");
+        check!(yaml.contains("archiveAfterDays: 30\n"));
+        let archive_yaml = fs::read_to_string(directory.path().join(".kanban.archive")).unwrap();
+        check!(archive_yaml.contains("description: Archived issue\n"));
+
+        let loaded_board = storage.load();
+        check_boards_are_equal(&loaded_board, &board);
+        check!(loaded_board.get_archived_entities().len() == 1);
+        check!(loaded_board.get_with_index(0).short_id == Some(ShortId(10)), "Expected short ids of archived issues not to be reused");
+
+        board.set_archived_entities(vec![]);
+        storage.save(&board);
+        check!(!directory.path().join(".kanban.archive").exists());
+    }
+
+    #[test]
+    fn test_transfer_is_stored_and_loaded() {
+        let mut board = HistorizedBoard::default().with_4_typical_issues();
+        board.assign_missing_short_ids();
+        board.history.add(UndoableHistoryElement::Transfer(TransferHistoryElement {
+            index: 2,
+            board: BoardName::try_from("personal").unwrap(),
+            short_id: ShortId(7),
+            original_short_id: Some(ShortId(3)),
+            original_state: State::from("Review"),
+            original_blocked_by: vec![ShortId(1)],
+            original_parent: Some(ShortId(4)),
+        }));
+
+        let yaml = FileStorage::board_to_yaml(&board);
+        check!(yaml.contains("board: personal\n"));
+
+        let loaded_board: HistorizedBoard<Issue> = serde_yaml::from_str::<StoredBoard>(&yaml).unwrap().into();
+        check_boards_are_equal(&loaded_board, &board);
+    }
+
+    #[test]
+    fn test_invalid_recurrence_is_not_loaded() {
+        let yaml = "issues:\n  - description: Weekly report\n    state: Open\n    timeCreated: 2025-02-10\n    dueDate: ~\n    recurrence: now and then\n";
+
+        check!(serde_yaml::from_str::<StoredBoard>(yaml).is_err());
+        check!(serde_yaml::from_str::<StoredBoard>(&yaml.replace("now and then", "every day")).is_ok());
+    }
+
     #[test]
     fn test_typical_board_to_storage_yaml() {
         let board = HistorizedBoard::default().with_4_typical_issues();
         let formatted_output  = FileStorage::board_to_yaml(&board);
 
         assert_eq!(formatted_output,r#"---
+columns:
+  - name: Open
+  - name: Review
+  - name: Done
+nextShortId: 1
 issues:
   - description: Task inserted fourth
-    state: open
+    state: Open
     timeCreated: 2025-02-10
     dueDate: ~
   - description: Task inserted third
-    state: done
+    state: Done
     timeCreated: 2025-02-03
     dueDate: ~
   - description: Task inserted second
-    state: review
+    state: Review
     timeCreated: 2025-02-12
     dueDate: 2025-02-16
   - description: Task inserted first
-    state: open
+    state: Open
     timeCreated: 2025-02-13
     dueDate: ~
 deletedIssues: []
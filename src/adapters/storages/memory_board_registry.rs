@@ -0,0 +1,72 @@
+#[cfg(test)]
+pub mod test {
+    use std::cell::RefCell;
+    use std::collections::BTreeMap;
+    use crate::application::Issue;
+    use crate::application::domain::board_name::BoardName;
+    use crate::application::domain::historized_board::HistorizedBoard;
+    use crate::application::ports::board_registry::BoardRegistry;
+
+    /// Boards kept in memory. The main board always exists, like with the file registry.
+    #[derive(Default)]
+    pub(crate) struct MemoryBoardRegistry {
+        pub(crate) boards: RefCell<BTreeMap<BoardName, HistorizedBoard<Issue>>>,
+        pub(crate) default_board: RefCell<BoardName>,
+        pub(crate) selected_board: Option<BoardName>,
+    }
+
+    impl MemoryBoardRegistry {
+        pub(crate) fn with_board(self, name: &str, board: HistorizedBoard<Issue>) -> Self {
+            self.boards.borrow_mut().insert(BoardName::try_from(name).unwrap(), board);
+            self
+        }
+    }
+
+    impl BoardRegistry for MemoryBoardRegistry {
+        fn boards(&self) -> Vec<BoardName> {
+            let mut boards = self.boards.borrow().keys().cloned().collect::<Vec<_>>();
+            if !boards.contains(&BoardName::main()) {
+                boards.push(BoardName::main());
+            }
+            boards.sort();
+            boards
+        }
+
+        fn default_board(&self) -> BoardName {
+            self.default_board.borrow().clone()
+        }
+
+        fn set_default_board(&self, name: &BoardName) {
+            self.default_board.swap(&RefCell::new(name.clone()));
+        }
+
+        fn active_board(&self) -> BoardName {
+            self.selected_board.clone().unwrap_or_else(|| self.default_board())
+        }
+
+        fn load(&self, name: &BoardName) -> HistorizedBoard<Issue> {
+            self.boards.borrow().get(name).cloned().unwrap_or_default()
+        }
+
+        fn save(&self, name: &BoardName, board: &HistorizedBoard<Issue>) {
+            self.boards.borrow_mut().insert(name.clone(), board.clone());
+        }
+
+        fn rename(&self, from: &BoardName, to: &BoardName) {
+            let board = self.boards.borrow_mut().remove(from).unwrap_or_default();
+            self.boards.borrow_mut().insert(to.clone(), board);
+
+            if self.default_board() == *from {
+                self.set_default_board(to);
+            }
+        }
+
+        fn delete(&self, name: &BoardName) {
+            self.boards.borrow_mut().remove(name);
+
+            if self.default_board() == *name {
+                self.set_default_board(&BoardName::main());
+            }
+        }
+    }
+}
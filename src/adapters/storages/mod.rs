@@ -1,5 +1,6 @@
 pub mod memory_issue_storage;
+pub mod memory_board_registry;
 mod file_storage;
 
-pub use file_storage::{FileStorage};
+pub use file_storage::{FileStorage, FileBoardRegistry};
 pub use crate::application::ports::issue_storage::IssueStorage;
\ No newline at end of file
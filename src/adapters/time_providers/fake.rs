@@ -1,5 +1,5 @@
-use time::Date;
-use time::macros::date;
+use time::{Date, OffsetDateTime};
+use time::macros::{date, time};
 use crate::application::ports::time::{TodayProvider};
 
 pub(crate) const DEFAULT_FAKE_TODAY: Date = date!(2025-02-22); // Saturday
@@ -20,5 +20,10 @@ impl TodayProvider for FakeTodayProvider {
     fn today(&self) -> Date {
         self.fake_today_answer
     }
+
+    /// The fake clock always shows 10:00 UTC of the fake today
+    fn now(&self) -> OffsetDateTime {
+        self.fake_today_answer.with_time(time!(10:00)).assume_utc()
+    }
 }
 
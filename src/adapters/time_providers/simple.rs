@@ -1,5 +1,5 @@
 use std::cell::LazyCell;
-use time::Date;
+use time::{Date, OffsetDateTime};
 use crate::application::ports::time::{TodayProvider};
 
 pub(crate) struct SimpleTimeProvider {
@@ -20,4 +20,8 @@ impl TodayProvider for SimpleTimeProvider {
     fn today(&self) -> Date {
         *self.today
     }
+
+    fn now(&self) -> OffsetDateTime {
+        OffsetDateTime::now_utc()
+    }
 }
\ No newline at end of file
@@ -0,0 +1,106 @@
+use std::fmt::{Display, Formatter};
+use time::{Duration, OffsetDateTime};
+use crate::application::State;
+
+/// Something that happened to an issue
+#[derive(Debug, PartialEq, Clone, Hash)]
+pub enum ActivityKind {
+    Created,
+    Moved {
+        from: State,
+        to: State,
+    },
+    /// A move that the workflow rules of the board do not allow was forced
+    TransitionForced {
+        from: State,
+        to: State,
+    },
+    DueChanged {
+        from: Option<time::Date>,
+        to: Option<time::Date>,
+    },
+    Edited,
+    Comment(String),
+    Blocked(String),
+    Unblocked,
+}
+
+impl Display for ActivityKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ActivityKind::Created => write!(f, "created"),
+            ActivityKind::Moved { from, to } => write!(f, "moved from {} to {}", from, to),
+            ActivityKind::TransitionForced { from, to } => write!(f, "forced the move from {} to {} against the workflow rules", from, to),
+            ActivityKind::DueChanged { from: _, to: Some(to) } => write!(f, "due date set to {}", to),
+            ActivityKind::DueChanged { from: _, to: None } => write!(f, "due date removed"),
+            ActivityKind::Edited => write!(f, "edited"),
+            ActivityKind::Comment(text) => write!(f, "comment: {}", text),
+            ActivityKind::Blocked(reason) => write!(f, "blocked: {}", reason),
+            ActivityKind::Unblocked => write!(f, "unblocked"),
+        }
+    }
+}
+
+/// An entry of the activity log of an issue
+#[derive(Debug, PartialEq, Clone, Hash)]
+pub struct ActivityEvent {
+    pub(crate) time: OffsetDateTime,
+    pub(crate) kind: ActivityKind,
+}
+
+impl ActivityEvent {
+    pub fn new(time: OffsetDateTime, kind: ActivityKind) -> Self {
+        Self {
+            time,
+            kind,
+        }
+    }
+}
+
+impl Display for ActivityEvent {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:04}-{:02}-{:02} {:02}:{:02} {}",
+               self.time.year(), u8::from(self.time.month()), self.time.day(),
+               self.time.hour(), self.time.minute(),
+               self.kind)
+    }
+}
+
+/// A period an issue spent in a state. The period of the current state of the issue has no end.
+#[derive(Debug, PartialEq, Clone)]
+pub struct StatePeriod {
+    pub(crate) state: State,
+    pub(crate) entered: OffsetDateTime,
+    pub(crate) left: Option<OffsetDateTime>,
+}
+
+impl StatePeriod {
+    /// How long the issue was in the state. The current state lasts until `now`.
+    pub fn duration(&self, now: OffsetDateTime) -> Duration {
+        (self.left.unwrap_or(now) - self.entered).max(Duration::ZERO)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert2::check;
+    use time::macros::{date, datetime};
+    use crate::application::domain::activity::{ActivityEvent, ActivityKind};
+    use crate::application::State;
+
+    #[test]
+    fn test_display_events() {
+        let time = datetime!(2025-02-22 09:05 UTC);
+
+        for (kind, expected) in [
+            (ActivityKind::Created, "2025-02-22 09:05 created"),
+            (ActivityKind::Moved { from: State::from("Open"), to: State::from("Review") }, "2025-02-22 09:05 moved from Open to Review"),
+            (ActivityKind::TransitionForced { from: State::from("Open"), to: State::from("Done") }, "2025-02-22 09:05 forced the move from Open to Done against the workflow rules"),
+            (ActivityKind::DueChanged { from: None, to: Some(date!(2025-03-01)) }, "2025-02-22 09:05 due date set to 2025-03-01"),
+            (ActivityKind::DueChanged { from: Some(date!(2025-03-01)), to: None }, "2025-02-22 09:05 due date removed"),
+            (ActivityKind::Comment(String::from("waiting on infra")), "2025-02-22 09:05 comment: waiting on infra"),
+        ] {
+            check!(ActivityEvent::new(time, kind).to_string() == expected);
+        }
+    }
+}
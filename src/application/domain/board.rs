@@ -1,15 +1,41 @@
 use std::fmt::Debug;
 use nonempty_collections::NEVec;
+use time::Date;
 use uuid::Uuid;
-use crate::application::issue::{Entity, IdGenerator, Issue, UUidGenerator};
+use crate::application::issue::{DuePolicy, Entity, IdGenerator, Issue, IssueCategory, IssueRef, ShortId, State, UUidGenerator, DEFAULT_STALE_AFTER_DAYS};
+use crate::application::domain::column::{Column, Columns};
 use crate::application::domain::error::{DomainError, DomainResult, DomainResultMultiError};
-use crate::application::State;
+use crate::application::domain::template::Template;
+use crate::application::domain::custom_field::{CustomField, FieldAssignment};
+use crate::application::domain::date_parse::DateParser;
+use crate::application::ports::time::TodayProvider;
 
 #[derive(Debug, Clone)]
 pub struct Board<T, IdGen: IdGenerator = UUidGenerator> {
     entities: Vec<Entity<T>>,
     deleted_entities: Vec<Entity<T>>,
 
+    /// Completed work moved out of the active board. The first is the one most recently archived.
+    archived_entities: Vec<Entity<T>>,
+
+    /// Columns of the board, in the order they are displayed
+    columns: Columns,
+
+    /// Done issues are archived when they have been done for this many days
+    archive_after_days: Option<u32>,
+
+    /// Issues due within this many days are due soon
+    due_soon_days: Option<u32>,
+
+    /// Recipes for issues that are added over and over
+    templates: Vec<Template>,
+
+    /// Metadata that the issues of the board can have, in the order they are displayed
+    fields: Vec<CustomField>,
+
+    /// The short id that the next added issue gets
+    short_id_counter: u32,
+
     id_generator: IdGen
 }
 
@@ -18,6 +44,13 @@ impl<T, IdGen: IdGenerator> Default for Board<T, IdGen> {
         Self {
             entities: Default::default(),
             deleted_entities: Default::default(),
+            archived_entities: Default::default(),
+            columns: Default::default(),
+            archive_after_days: None,
+            due_soon_days: None,
+            templates: Vec::default(),
+            fields: Vec::default(),
+            short_id_counter: 1,
             id_generator: Default::default(),
         }
     }
@@ -39,6 +72,104 @@ impl<T, IdGen: IdGenerator> Board<T, IdGen> {
         &self.entities
     }
 
+    pub fn columns(&self) -> &Columns {
+        &self.columns
+    }
+
+    pub fn set_columns(&mut self, columns: Columns) {
+        self.columns = columns;
+    }
+
+    pub fn archive_after_days(&self) -> Option<u32> {
+        self.archive_after_days
+    }
+
+    pub fn set_archive_after_days(&mut self, days: Option<u32>) {
+        self.archive_after_days = days;
+    }
+
+    pub fn due_soon_days(&self) -> Option<u32> {
+        self.due_soon_days
+    }
+
+    pub fn set_due_soon_days(&mut self, days: Option<u32>) {
+        self.due_soon_days = days;
+    }
+
+    pub fn templates(&self) -> &[Template] {
+        &self.templates
+    }
+
+    pub fn set_templates(&mut self, templates: Vec<Template>) {
+        self.templates = templates;
+    }
+
+    /// The template called `name`, ignoring the case
+    pub fn find_template(&self, name: &str) -> DomainResult<&Template> {
+        self.templates.iter()
+            .find(|template| template.is_named(name))
+            .ok_or_else(|| DomainError::TemplateNotFound {
+                name: name.to_string(),
+                available: match self.templates.as_slice() {
+                    [] => String::from("none"),
+                    templates => templates.iter().map(Template::name).collect::<Vec<_>>().join(", "),
+                },
+            })
+    }
+
+    pub fn fields(&self) -> &[CustomField] {
+        &self.fields
+    }
+
+    pub fn set_fields(&mut self, fields: Vec<CustomField>) {
+        self.fields = fields;
+    }
+
+    /// The custom field called `name`, ignoring the case
+    pub fn find_field(&self, name: &str) -> DomainResult<&CustomField> {
+        self.fields.iter()
+            .find(|field| field.is_named(name))
+            .ok_or_else(|| DomainError::UnknownField {
+                name: name.to_string(),
+                available: match self.fields.as_slice() {
+                    [] => String::from("none"),
+                    fields => fields.iter().map(CustomField::name).collect::<Vec<_>>().join(", "),
+                },
+            })
+    }
+
+    /// The assignment with the declared name of its field and its value in canonical form, e.g.
+    /// `Customer=acme` becomes `customer=acme` for a field declared as `customer`
+    pub fn resolve_field_assignment<P: TodayProvider>(&self, assignment: &FieldAssignment, date_parser: &DateParser<P>) -> DomainResult<FieldAssignment> {
+        let field = self.find_field(&assignment.name)?;
+
+        Ok(FieldAssignment {
+            name: field.name.clone(),
+            value: assignment.value.as_deref()
+                .map(|value| field.parse_value(value, date_parser))
+                .transpose()?,
+        })
+    }
+
+    /// The due policy of the issues in `state`
+    pub fn due_policy(&self, state: &State) -> DuePolicy {
+        DuePolicy {
+            stale_after_days: self.columns.find(state)
+                .map_or(Some(DEFAULT_STALE_AFTER_DAYS), Column::stale_after_days),
+            due_soon_days: self.due_soon_days,
+        }
+    }
+
+    /// The category of `issue` by the due policy of its column. Done issues are never overdue or
+    /// due soon.
+    pub fn category_of(&self, issue: &Issue, today: Date) -> IssueCategory {
+        if self.columns.is_done(&issue.state) {
+            return IssueCategory::Normal;
+        }
+
+        issue.category(today, &self.due_policy(&issue.state))
+    }
+
     #[cfg(test)]
     pub(crate) fn entity_count(&self) -> usize {
         self.entities.len()
@@ -75,19 +206,6 @@ impl<T, IdGen: IdGenerator> Board<T, IdGen> {
             .ok_or(DomainError::IndexOutOfRange(index))
     }
 
-    pub fn find_entities_by_indices(&self, indices: &[usize]) -> DomainResultMultiError<Vec<Uuid>> {
-        let mut errors = indices
-            .iter()
-            .filter(|&&i| self.entities.len() <= i)
-            .map(|&i|DomainError::IndexOutOfRange(i));
-
-        if let Some(head) = errors.next() {
-            return Err(NEVec::from((head, errors.collect())));
-        }
-
-        Ok(indices.iter().map(|&order| self.entities[order].id).collect())
-    }
-
     /// Adds a new issue to the board to first priority
     pub fn append_entity(&mut self, issue: T) {
         self.entities.insert(0, Entity::build(issue, &mut self.id_generator) );
@@ -118,14 +236,320 @@ impl<T, IdGen: IdGenerator> Board<T, IdGen> {
     pub fn get_deleted_entities_mut(&mut self) -> &mut Vec<Entity<T>> {
         &mut self.deleted_entities
     }
+
+    pub(crate) fn set_archived_entities(&mut self, archived_entities: Vec<T>) {
+        self.archived_entities = archived_entities.into_iter()
+            .map(|x| Entity::build(x, &mut self.id_generator))
+            .collect();
+    }
+
+    /// Moves the entity with id to the archive. Panics if used with a non-existing id.
+    pub(crate) fn archive(&mut self, id: Uuid) {
+        let entity = self.remove(id);
+        self.archived_entities.insert(0, entity);
+    }
+
+    /// Returns a list of the archived issues. The first element of the list is the one most recently archived.
+    pub fn get_archived_entities(&self) -> &[Entity<T>] {
+        &self.archived_entities
+    }
+
+    pub fn get_archived_entities_mut(&mut self) -> &mut Vec<Entity<T>> {
+        &mut self.archived_entities
+    }
 }
 
 impl<IdGen: IdGenerator> Board<Issue, IdGen> {
+    /// Returns a short id that has never been used on this board.
+    pub fn next_short_id(&mut self) -> ShortId {
+        let short_id = ShortId(self.short_id_counter);
+        self.short_id_counter += 1;
+        short_id
+    }
+
+    pub fn short_id_counter(&self) -> u32 {
+        self.short_id_counter
+    }
+
+    /// Sets the short id the next issue gets. The counter never goes below the short ids already in use.
+    pub fn set_short_id_counter(&mut self, counter: u32) {
+        let highest_in_use = self.entities.iter()
+            .chain(self.deleted_entities.iter())
+            .chain(self.archived_entities.iter())
+            .filter_map(|e| e.short_id)
+            .map(|ShortId(id)| id)
+            .max()
+            .unwrap_or(0);
+
+        self.short_id_counter = counter.max(highest_in_use + 1);
+    }
+
+    /// Gives a short id to every issue which does not have one yet (e.g. issues of boards that were
+    /// created before short ids existed).
+    pub fn assign_missing_short_ids(&mut self) {
+        self.set_short_id_counter(self.short_id_counter);
+
+        for i in 0..self.entities.len() {
+            if self.entities[i].short_id.is_none() {
+                self.entities[i].short_id = Some(self.next_short_id());
+            }
+        }
+
+        for i in 0..self.deleted_entities.len() {
+            if self.deleted_entities[i].short_id.is_none() {
+                self.deleted_entities[i].short_id = Some(self.next_short_id());
+            }
+        }
+
+        for i in 0..self.archived_entities.len() {
+            if self.archived_entities[i].short_id.is_none() {
+                self.archived_entities[i].short_id = Some(self.next_short_id());
+            }
+        }
+    }
+
+    /// Position of an archived issue in the archive. Indexes refer to the order of the archive.
+    pub fn find_archived_position_by_ref(&self, issue_ref: IssueRef) -> DomainResult<usize> {
+        match issue_ref {
+            IssueRef::Index(index) if index < self.archived_entities.len() => Ok(index),
+            IssueRef::Index(index) => Err(DomainError::IndexOutOfRange(index)),
+            IssueRef::ShortId(short_id) => self.archived_entities.iter()
+                .position(|e| e.short_id == Some(short_id))
+                .ok_or(DomainError::ShortIdNotFound(short_id)),
+        }
+    }
+
+    /// Moves the archived issue back to the top of its column. Returns its id.
+    pub fn unarchive(&mut self, position: usize) -> Uuid {
+        let entity = self.archived_entities.remove(position);
+        let id = entity.id;
+
+        self.entities.push(entity);
+        self.prio_top_in_category(id);
+        id
+    }
+
+    /// Done issues that have been done for at least `days` days
+    pub fn done_for_days(&self, days: u32, today: Date) -> Vec<Uuid> {
+        let done_state = self.columns.done_state();
+
+        self.entities.iter()
+            .filter(|e| e.state == done_state)
+            .filter(|e| (today - e.entered_state_on(&done_state)).whole_days() >= i64::from(days))
+            .map(|e| e.id)
+            .collect()
+    }
+
+    pub fn find_entity_id_by_short_id(&self, short_id: ShortId) -> DomainResult<Uuid> {
+        self.entities.iter()
+            .find(|e| e.short_id == Some(short_id))
+            .map(|e| e.id)
+            .ok_or(DomainError::ShortIdNotFound(short_id))
+    }
+
+    /// The issue whose timer is running. Only one issue is tracked at a time.
+    pub fn find_tracked_issue(&self) -> Option<Uuid> {
+        self.entities.iter()
+            .find(|e| e.is_tracked())
+            .map(|e| e.id)
+    }
+
+    pub fn issue_count_in(&self, state: &State) -> usize {
+        self.entities.iter().filter(|e| &e.state == state).count()
+    }
+
+    /// Sum of the estimates of the issues in the column of `state`. If none of them is estimated,
+    /// there is no total.
+    pub fn estimate_total_in(&self, state: &State) -> Option<u64> {
+        self.entities.iter()
+            .filter(|e| &e.state == state)
+            .filter_map(|e| e.estimate)
+            .map(u64::from)
+            .reduce(|total, estimate| total + estimate)
+    }
+
+    /// Checks whether `incoming` more issues fit into the column of `state` without exceeding its
+    /// WIP limit.
+    pub fn check_wip_limit(&self, state: &State, incoming: usize) -> DomainResult<()> {
+        let Some(column) = self.columns.find(state) else {
+            return Ok(());
+        };
+
+        match column.wip_limit() {
+            Some(limit) if incoming > 0 && self.issue_count_in(state) + incoming > limit => Err(DomainError::WipLimitExceeded {
+                column: column.name().to_string(),
+                limit,
+            }),
+            _ => Ok(()),
+        }
+    }
+
+    pub fn short_id_of(&self, id: Uuid) -> DomainResult<ShortId> {
+        self.get(id).short_id
+            .ok_or_else(|| DomainError::InvalidBoard(String::from("issue does not have a short id")))
+    }
+
+    /// Short ids of the issues that block the issue, and which are not done yet. Deleted issues do
+    /// not block any issues.
+    pub fn open_blockers(&self, id: Uuid) -> Vec<ShortId> {
+        self.get(id).blocked_by.iter()
+            .filter(|&&blocker| self.entities.iter()
+                .any(|e| e.short_id == Some(blocker) && !self.columns.is_done(&e.state)))
+            .copied()
+            .collect()
+    }
+
+    /// Records that the issue `blocked` cannot be done before `blocker`. Dependencies that would
+    /// make a cycle are rejected.
+    pub fn add_blocker(&mut self, blocked: Uuid, blocker: Uuid) -> DomainResult<()> {
+        let blocked_short_id = self.short_id_of(blocked)?;
+        let blocker_short_id = self.short_id_of(blocker)?;
+
+        if self.depends_on(blocker_short_id, blocked_short_id) {
+            return Err(DomainError::DependencyCycle {
+                blocked: blocked_short_id,
+                blocker: blocker_short_id,
+            });
+        }
+
+        let issue = self.get_mut(blocked);
+        if !issue.blocked_by.contains(&blocker_short_id) {
+            issue.blocked_by.push(blocker_short_id);
+        }
+
+        Ok(())
+    }
+
+    pub fn remove_blocker(&mut self, blocked: Uuid, blocker: Uuid) -> DomainResult<()> {
+        let blocker_short_id = self.short_id_of(blocker)?;
+        self.get_mut(blocked).blocked_by.retain(|&b| b != blocker_short_id);
+
+        Ok(())
+    }
+
+    /// Number of the done children of the issue and the number of all its children, if it has
+    /// any. Archived children are done, deleted children are not counted.
+    pub fn children_progress(&self, id: Uuid) -> Option<(usize, usize)> {
+        let short_id = self.get(id).short_id?;
+        let (done, all) = self.entities.iter()
+            .filter(|e| e.parent == Some(short_id))
+            .map(|e| self.columns.is_done(&e.state))
+            .chain(self.archived_entities.iter()
+                .filter(|e| e.parent == Some(short_id))
+                .map(|_| true))
+            .fold((0, 0), |(done, all), is_done| (done + usize::from(is_done), all + 1));
+
+        (all > 0).then_some((done, all))
+    }
+
+    /// Short ids of the children of the issue that are not done yet
+    pub fn open_children(&self, id: Uuid) -> Vec<ShortId> {
+        let Some(short_id) = self.get(id).short_id else {
+            return Vec::new();
+        };
+
+        self.entities.iter()
+            .filter(|e| e.parent == Some(short_id) && !self.columns.is_done(&e.state))
+            .filter_map(|e| e.short_id)
+            .collect()
+    }
+
+    /// Makes `parent` the parent of the issue `child`, or detaches `child` from its parent.
+    /// Parents that would make a cycle are rejected.
+    pub fn set_parent(&mut self, child: Uuid, parent: Option<Uuid>) -> DomainResult<()> {
+        let child_short_id = self.short_id_of(child)?;
+        let parent_short_id = parent.map(|parent| self.short_id_of(parent)).transpose()?;
+
+        if let Some(parent_short_id) = parent_short_id {
+            if self.is_descendant_of(parent_short_id, child_short_id) {
+                return Err(DomainError::ParentCycle {
+                    child: child_short_id,
+                    parent: parent_short_id,
+                });
+            }
+        }
+
+        self.get_mut(child).parent = parent_short_id;
+
+        Ok(())
+    }
+
+    /// Whether `issue` is `ancestor`, or a child of `ancestor` directly or through other issues.
+    /// Deleted and archived issues are taken into account too, as they can be restored.
+    fn is_descendant_of(&self, issue: ShortId, ancestor: ShortId) -> bool {
+        let mut current = Some(issue);
+        let mut visited = Vec::new();
+
+        while let Some(short_id) = current {
+            if short_id == ancestor {
+                return true;
+            }
+            if visited.contains(&short_id) {
+                return false;
+            }
+            visited.push(short_id);
+
+            current = self.entities.iter()
+                .chain(self.deleted_entities.iter())
+                .chain(self.archived_entities.iter())
+                .find(|e| e.short_id == Some(short_id))
+                .and_then(|e| e.parent);
+        }
+
+        false
+    }
+
+    /// Whether `issue` is `other`, or it is blocked by `other` directly or through other issues.
+    /// Deleted and archived issues are taken into account too, as they can be restored.
+    fn depends_on(&self, issue: ShortId, other: ShortId) -> bool {
+        let mut to_visit = vec![issue];
+        let mut visited = Vec::new();
+
+        while let Some(current) = to_visit.pop() {
+            if current == other {
+                return true;
+            }
+
+            if visited.contains(&current) {
+                continue;
+            }
+            visited.push(current);
+
+            if let Some(entity) = self.entities.iter()
+                .chain(self.deleted_entities.iter())
+                .chain(self.archived_entities.iter())
+                .find(|e| e.short_id == Some(current)) {
+                to_visit.extend(entity.blocked_by.iter().copied());
+            }
+        }
+
+        false
+    }
+
+    pub fn find_entity_id_by_ref(&self, issue_ref: IssueRef) -> DomainResult<Uuid> {
+        match issue_ref {
+            IssueRef::Index(index) => self.find_entity_id_by_index(index),
+            IssueRef::ShortId(short_id) => self.find_entity_id_by_short_id(short_id),
+        }
+    }
+
+    pub fn find_entities_by_refs(&self, issue_refs: &[IssueRef]) -> DomainResultMultiError<Vec<Uuid>> {
+        let mut errors = issue_refs
+            .iter()
+            .filter_map(|&issue_ref| self.find_entity_id_by_ref(issue_ref).err());
+
+        if let Some(head) = errors.next() {
+            return Err(NEVec::from((head, errors.collect())));
+        }
+
+        Ok(issue_refs.iter().map(|&issue_ref| self.find_entity_id_by_ref(issue_ref).unwrap()).collect())
+    }
+
     /// Changes the priority (order) of the issues, so that it becomes the most priority in
     /// its category (amongst issues with similar state).
     /// Returns the new position of the issue
     pub fn prio_top_in_category(&mut self, id: Uuid) {
-        let state = self.get(id).state;
+        let state = self.get(id).state.clone();
         let most_prio_position = self.entities
             .iter()
             .position(|i|i.state == state)
@@ -135,10 +559,26 @@ impl<IdGen: IdGenerator> Board<Issue, IdGen> {
         self.insert(most_prio_position, issue);
     }
 
+    /// Changes the order of the issues, so that the issue is placed above the first issue of its
+    /// category (amongst issues with similar state) that does not have a higher priority level.
+    pub fn prio_by_level_in_category(&mut self, id: Uuid) {
+        let state = self.get(id).state.clone();
+        let priority = self.get(id).priority;
+        let current_position = self.position(id);
+
+        let issue = self.remove(id);
+        let position = self.entities.iter()
+            .position(|i| i.state == state && i.priority <= priority)
+            .or_else(|| self.entities.iter().rposition(|i| i.state == state).map(|last| last + 1))
+            .unwrap_or(current_position);
+
+        self.insert(position, issue);
+    }
+
     /// Changes the priority (order) of the issues, so that it becomes the least priority in
     /// its category (amongst issues with similar state)
     pub fn prio_bottom_in_category(&mut self, id: Uuid) {
-        let state = self.get(id).state;
+        let state = self.get(id).state.clone();
         let least_prio_position = self.entities
             .iter()
             .rposition(|i|i.state == state)
@@ -151,7 +591,7 @@ impl<IdGen: IdGenerator> Board<Issue, IdGen> {
     /// Changes the priority (order) of the issues, so that it becomes one more priority in
     /// its category (amongst issues with similar state)
     pub fn prio_up_in_category(&mut self, id: Uuid) {
-        let state = self.get(id).state;
+        let state = self.get(id).state.clone();
 
         let entity_pos_reversed = self.entities
             .iter()
@@ -193,13 +633,13 @@ impl<IdGen: IdGenerator> Board<Issue, IdGen> {
         self.entities.insert(current_position + steps_down, issue);
     }
 
-    /// Flush will delete all issues which are not done, practically giving a clean slate for the
+    /// Flush will delete all issues which are not in the done column, practically giving a clean slate for the
     /// user. Flushed entities appear in the deleted issues, with reversed order (issue with index
     /// 0 ends up at position 'n', where n is number of not done issues).
     ///
     /// Returns the number of issues affected.
     pub fn flush(&mut self) -> usize {
-        let extracted_entities = self.entities.extract_if(|e| e.state != State::Done);
+        let extracted_entities = self.entities.extract_if(.., |e| !self.columns.is_done(&e.state));
         let mut count = 0;
         for entity in extracted_entities.into_iter() {
             count += 1;
@@ -226,9 +666,9 @@ mod tests {
     #[test]
     fn test_verify_indices_valid() {
         let board = given_board_with_2_tasks();
-        let indices = vec![0, 1];
+        let indices = vec![IssueRef::Index(0), IssueRef::Index(1)];
 
-        let result = board.find_entities_by_indices(&indices);
+        let result = board.find_entities_by_refs(&indices);
 
         let_assert!(Ok(ids) = result, "Expected validation to succeed");
         check!(ids == TEST_UUIDS[0..2]);
@@ -237,9 +677,9 @@ mod tests {
     #[test]
     fn test_verify_indices_invalid() {
         let board = given_board_with_2_tasks();
-        let indices = vec![0, 1, 2, 3];
+        let indices = vec![IssueRef::Index(0), IssueRef::Index(1), IssueRef::Index(2), IssueRef::Index(3)];
 
-        let result = board.find_entities_by_indices(&indices);
+        let result = board.find_entities_by_refs(&indices);
 
         let_assert!(Err(errors) = result, "expected errors when validating indices of board");
         assert_eq!(errors.len(), 2, "Expected 2 errors");
@@ -250,9 +690,9 @@ mod tests {
     #[test]
     fn test_verify_indices_empty_indices() {
         let board = given_board_with_2_tasks();
-        let indices = vec![];
+        let indices: Vec<IssueRef> = vec![];
 
-        let validated = board.find_entities_by_indices(&indices);
+        let validated = board.find_entities_by_refs(&indices);
 
         let_assert!(Ok(ids) = validated, "Expected validated indices to be good");
         check!(ids == [] as [Uuid; 0]);
@@ -261,13 +701,108 @@ mod tests {
     #[test]
     fn test_verify_indices_empty_board() {
         let board: HistorizedBoard<Issue> = HistorizedBoard::default();
-        let indices = vec![0, 1, 2, 3];
-        let validated = board.find_entities_by_indices(&indices);
+        let indices = vec![IssueRef::Index(0), IssueRef::Index(1), IssueRef::Index(2), IssueRef::Index(3)];
+        let validated = board.find_entities_by_refs(&indices);
 
         let_assert!(Err(errors) = validated);
         assert_eq!(errors.len(), 4, "Expected 4 errors for empty board");
     }
 
+    #[test]
+    fn test_find_entities_by_short_ids() {
+        let mut board = given_board_with_2_tasks();
+        board.assign_missing_short_ids();
+        let indices = vec![IssueRef::ShortId(ShortId(2)), IssueRef::Index(0), IssueRef::ShortId(ShortId(3))];
+
+        let result = board.find_entities_by_refs(&indices);
+
+        let_assert!(Err(errors) = result);
+        check!(errors.len() == 1);
+        check!(matches!(errors[0], DomainError::ShortIdNotFound(ShortId(3))));
+
+        let_assert!(Ok(ids) = board.find_entities_by_refs(&indices[0..2]));
+        check!(ids == [TEST_UUIDS[1], TEST_UUIDS[0]]);
+    }
+
+    #[test]
+    fn test_short_ids_are_not_reused() {
+        let mut board = given_board_with_2_tasks();
+        board.assign_missing_short_ids();
+        let id = board.find_entity_id_by_ref(IssueRef::ShortId(ShortId(2))).unwrap();
+        board.mark_as_deleted(id);
+
+        board.set_short_id_counter(1);
+
+        check!(board.next_short_id() == ShortId(3));
+    }
+
+    #[test]
+    fn test_children_progress() {
+        let mut board = HistorizedBoard::default().with_4_typical_issues(); // Open, Done, Review, Open
+        board.assign_missing_short_ids();
+        let ids = (0..4).map(|index| board.find_entity_id_by_index(index).unwrap()).collect::<Vec<_>>();
+        for &child in &ids[1..] {
+            board.set_parent(child, Some(ids[0])).unwrap();
+        }
+
+        check!(board.children_progress(ids[0]) == Some((1, 3)));
+        check!(board.open_children(ids[0]) == [ShortId(3), ShortId(4)]);
+        check!(board.children_progress(ids[1]) == None);
+
+        board.archive(ids[2]);
+        check!(board.children_progress(ids[0]) == Some((2, 3)), "Expected archived children to count as done");
+    }
+
+    #[test]
+    fn test_set_parent_refuses_cycles() {
+        let mut board = given_board_with_2_tasks();
+        board.assign_missing_short_ids();
+        board.set_parent(TEST_UUIDS[1], Some(TEST_UUIDS[0])).unwrap();
+
+        let_assert!(Err(DomainError::ParentCycle { child: ShortId(1), parent: ShortId(2) }) = board.set_parent(TEST_UUIDS[0], Some(TEST_UUIDS[1])));
+        check!(board.get(TEST_UUIDS[0]).parent == None);
+
+        let_assert!(Ok(()) = board.set_parent(TEST_UUIDS[1], None));
+        check!(board.get(TEST_UUIDS[1]).parent == None);
+    }
+
+    #[test]
+    fn test_dependency_cycles_through_archived_issues_are_refused() {
+        let mut board = HistorizedBoard::default().with_4_typical_issues();
+        board.assign_missing_short_ids();
+        let ids = (0..4).map(|index| board.find_entity_id_by_index(index).unwrap()).collect::<Vec<_>>();
+        board.add_blocker(ids[3], ids[1]).unwrap();
+        board.add_blocker(ids[1], ids[2]).unwrap();
+
+        board.archive(ids[1]);
+
+        let_assert!(Err(DomainError::DependencyCycle { blocked: ShortId(3), blocker: ShortId(4) }) = board.add_blocker(ids[2], ids[3]));
+    }
+
+    #[test]
+    fn test_parent_cycles_through_archived_issues_are_refused() {
+        let mut board = HistorizedBoard::default().with_4_typical_issues();
+        board.assign_missing_short_ids();
+        let ids = (0..4).map(|index| board.find_entity_id_by_index(index).unwrap()).collect::<Vec<_>>();
+        board.set_parent(ids[1], Some(ids[0])).unwrap();
+        board.set_parent(ids[2], Some(ids[1])).unwrap();
+
+        board.archive(ids[1]);
+
+        let_assert!(Err(DomainError::ParentCycle { child: ShortId(1), parent: ShortId(3) }) = board.set_parent(ids[0], Some(ids[2])));
+    }
+
+    #[test]
+    fn test_estimate_total_of_large_estimates() {
+        let board = HistorizedBoard::default()
+            .with_issue(Issue { description: Description::from("Huge"), state: State::from("Open"), estimate: Some(u32::MAX), ..Default::default() })
+            .with_issue(Issue { description: Description::from("Also huge"), state: State::from("Open"), estimate: Some(u32::MAX), ..Default::default() })
+            .with_issue(Issue { description: Description::from("Not estimated"), state: State::from("Review"), ..Default::default() });
+
+        check!(board.estimate_total_in(&State::from("Open")) == Some(2 * u64::from(u32::MAX)));
+        check!(board.estimate_total_in(&State::from("Review")) == None);
+    }
+
     #[test]
     fn test_prio_top_in_category_only_one_in_category() {
         let mut board = given_board_with_2_tasks(); // 0 in Open, 1 in Review
@@ -288,11 +823,11 @@ mod tests {
 
         // Then
         let expected = [
-            ("Third open task", State::Open),
-            ("First open task", State::Open),
-            ("First done task", State::Done),
-            ("First review task", State::Review),
-            ("Second open task", State::Open),
+            ("Third open task", State::from("Open")),
+            ("First open task", State::from("Open")),
+            ("First done task", State::from("Done")),
+            ("First review task", State::from("Review")),
+            ("Second open task", State::from("Open")),
         ];
 
         check_priorities(&expected, &board);
@@ -332,11 +867,11 @@ mod tests {
 
         // Then
         let expected = [
-            ("First done task", State::Done), // ^ from here
-            ("First review task", State::Review),
-            ("Second open task", State::Open),
-            ("Third open task", State::Open),
-            ("First open task", State::Open), // moved here
+            ("First done task", State::from("Done")), // ^ from here
+            ("First review task", State::from("Review")),
+            ("Second open task", State::from("Open")),
+            ("Third open task", State::from("Open")),
+            ("First open task", State::from("Open")), // moved here
         ];
 
         check_priorities(&expected, &board);
@@ -352,11 +887,11 @@ mod tests {
 
         // Then
         let expected = [
-            ("First open task", State::Open),
-            ("First done task", State::Done),
-            ("First review task", State::Review),
-            ("Third open task", State::Open),
-            ("Second open task", State::Open),
+            ("First open task", State::from("Open")),
+            ("First done task", State::from("Done")),
+            ("First review task", State::from("Review")),
+            ("Third open task", State::from("Open")),
+            ("Second open task", State::from("Open")),
         ];
 
         check_priorities(&expected, &board);
@@ -372,11 +907,11 @@ mod tests {
 
         // Then
         let expected = [
-            ("First open task", State::Open),
-            ("First done task", State::Done),
-            ("First review task", State::Review),
-            ("Third open task", State::Open), // ^ from here
-            ("Second open task", State::Open), // <-- moved here
+            ("First open task", State::from("Open")),
+            ("First done task", State::from("Done")),
+            ("First review task", State::from("Review")),
+            ("Third open task", State::from("Open")), // ^ from here
+            ("Second open task", State::from("Open")), // <-- moved here
         ];
 
         check_priorities(&expected, &board);
@@ -392,11 +927,11 @@ mod tests {
 
         // Then
         let expected = [
-            ("First done task", State::Done), // ^ from here
-            ("First review task", State::Review),
-            ("Second open task", State::Open),
-            ("First open task", State::Open), // moved here
-            ("Third open task", State::Open),
+            ("First done task", State::from("Done")), // ^ from here
+            ("First review task", State::from("Review")),
+            ("Second open task", State::from("Open")),
+            ("First open task", State::from("Open")), // moved here
+            ("Third open task", State::from("Open")),
         ];
 
         check_priorities(&expected, &board);
@@ -429,25 +964,27 @@ mod tests {
         HistorizedBoard::new(vec![
             Issue {
                 description: Description::from("First task"),
-                state: State::Open,
+                state: State::from("Open"),
                 time_created: DEFAULT_FAKE_TODAY,
                 due_date: None,
+                ..Default::default()
             },
             Issue {
                 description: Description::from("Second task"),
-                state: State::Review,
+                state: State::from("Review"),
                 time_created: DEFAULT_FAKE_TODAY,
                 due_date: None,
+                ..Default::default()
             },
         ], vec![], vec![])
     }
 
 
     fn check_priorities<IdGen: IdGenerator + Debug>(expected: &[(&str, State)], actual: &HistorizedBoard<Issue, IdGen>) {
-        expected.into_iter().enumerate().for_each(|(index, &(expected_description, expected_state))| {
+        expected.into_iter().enumerate().for_each(|(index, (expected_description, expected_state))| {
             let entity = &actual.entities()[index];
-            check!(entity.description == Description::from(expected_description), "Expected specific description for Issue at index '{}.\nBoard was: '{:?}'", index, actual);
-            check!(entity.state == expected_state, "Expected specific state for Issue at index '{}'.\nBoard was: '{:?}'", index, actual);
+            check!(entity.description == Description::from(*expected_description), "Expected specific description for Issue at index '{}.\nBoard was: '{:?}'", index, actual);
+            check!(&entity.state == expected_state, "Expected specific state for Issue at index '{}'.\nBoard was: '{:?}'", index, actual);
         })
     }
 
@@ -455,25 +992,30 @@ mod tests {
     fn board_for_testing_priorities() -> HistorizedBoard<Issue, FixedIdGenerator> {
         HistorizedBoard::new(
             vec![
-                Issue { description: Description::from("First open task"), state: State::Open,
+                Issue { description: Description::from("First open task"), state: State::from("Open"),
                     time_created: DEFAULT_FAKE_TODAY,
                     due_date: None,
+                    ..Default::default()
                 },
-                Issue { description: Description::from("First done task"), state: State::Done,
+                Issue { description: Description::from("First done task"), state: State::from("Done"),
                     time_created: DEFAULT_FAKE_TODAY,
                     due_date: None,
+                    ..Default::default()
                 },
-                Issue { description: Description::from("First review task"), state: State::Review,
+                Issue { description: Description::from("First review task"), state: State::from("Review"),
                     time_created: DEFAULT_FAKE_TODAY,
                     due_date: None,
+                    ..Default::default()
                 },
-                Issue { description: Description::from("Second open task"), state: State::Open,
+                Issue { description: Description::from("Second open task"), state: State::from("Open"),
                     time_created: DEFAULT_FAKE_TODAY,
                     due_date: None,
+                    ..Default::default()
                 },
-                Issue { description: Description::from("Third open task"), state: State::Open,
+                Issue { description: Description::from("Third open task"), state: State::from("Open"),
                     time_created: DEFAULT_FAKE_TODAY,
                     due_date: None,
+                    ..Default::default()
                 },
             ],
             vec![],
@@ -487,6 +1029,9 @@ pub(crate) mod test_utils {
     use assert2::{check, let_assert};
     use time::macros::date;
     use crate::application::{Issue, State};
+    use crate::application::domain::column::{Column, Columns};
+    use crate::application::domain::template::{Template, TemplateIssue};
+    use crate::application::domain::custom_field::{CustomField, FieldType};
     use crate::application::domain::historized_board::HistorizedBoard;
     use crate::application::issue::{Description, Entity};
 
@@ -531,19 +1076,95 @@ pub(crate) mod test_utils {
         }
 
         pub(crate) fn with_4_typical_issues(self) -> Self {
-            HistorizedBoard::new(
+            let columns = self.columns().clone();
+            let mut board = HistorizedBoard::new(
                 [self.board.entities.into_iter().map(|x| x.content).collect::<Vec<_>>(), typical_4_issues()].concat(),
                 self.board.deleted_entities.into_iter().map(|x1| x1.content).collect(),
                 self.history.stack
-            )
+            );
+            board.set_columns(columns);
+            board
         }
 
         pub(crate) fn with_issue(self, issue: Issue) -> Self {
-            HistorizedBoard::new(
+            let columns = self.columns().clone();
+            let mut board = HistorizedBoard::new(
                 [self.board.entities.into_iter().map(|x| x.content).collect::<Vec<_>>(), vec![issue]].concat(),
                 self.board.deleted_entities.into_iter().map(|x1| x1.content).collect(),
                 self.history.stack
-            )
+            );
+            board.set_columns(columns);
+            board
+        }
+
+        pub(crate) fn with_columns(mut self, names: &[&str]) -> Self {
+            let columns = Columns::new(names.iter().map(|&name| Column::new(name)).collect())
+                .expect("Expected valid columns");
+            self.set_columns(columns);
+            self
+        }
+
+        pub(crate) fn with_wip_limit(mut self, column: &str, limit: usize) -> Self {
+            let columns = Columns::new(self.columns().iter()
+                .cloned()
+                .map(|c| if c.state() == State::from(column) { c.with_wip_limit(Some(limit)) } else { c })
+                .collect())
+                .expect("Expected valid columns");
+            self.set_columns(columns);
+            self
+        }
+
+        pub(crate) fn with_sort_by_priority(mut self, column: &str) -> Self {
+            let columns = Columns::new(self.columns().iter()
+                .cloned()
+                .map(|c| if c.state() == State::from(column) { c.with_sort_by_priority(true) } else { c })
+                .collect())
+                .expect("Expected valid columns");
+            self.set_columns(columns);
+            self
+        }
+
+        pub(crate) fn with_allowed_transitions(mut self, column: &str, targets: &[&str]) -> Self {
+            let targets = targets.iter().map(|&target| State::from(target)).collect::<Vec<_>>();
+            let columns = Columns::new(self.columns().iter()
+                .cloned()
+                .map(|c| if c.state() == State::from(column) { c.with_allowed_transitions(Some(targets.clone())) } else { c })
+                .collect())
+                .expect("Expected valid columns");
+            self.set_columns(columns);
+            self
+        }
+
+        /// Adds the `release` template: a release issue due in a week and its announcement in Review
+        pub(crate) fn with_release_template(mut self) -> Self {
+            self.set_templates(vec![Template {
+                name: String::from("release"),
+                issues: vec![
+                    TemplateIssue {
+                        description: String::from("Release {}"),
+                        due: Some(String::from("in 7 days")),
+                        ..Default::default()
+                    },
+                    TemplateIssue {
+                        description: String::from("Announce {}"),
+                        state: Some(State::from("Review")),
+                        ..Default::default()
+                    },
+                ],
+            }]);
+            self
+        }
+
+        /// Declares the `customer` field, which is shown on the board, and the `ticket`,
+        /// `environment` and `deadline` fields
+        pub(crate) fn with_custom_fields(mut self) -> Self {
+            self.set_fields(vec![
+                CustomField { name: String::from("customer"), field_type: FieldType::Text, show: true },
+                CustomField { name: String::from("ticket"), field_type: FieldType::Number, show: false },
+                CustomField { name: String::from("environment"), field_type: FieldType::Enum(vec![String::from("staging"), String::from("prod")]), show: false },
+                CustomField { name: String::from("deadline"), field_type: FieldType::Date, show: false },
+            ]);
+            self
         }
     }
 
@@ -552,30 +1173,34 @@ pub(crate) mod test_utils {
             // index 0
             Issue {
                 description: Description::from("Task inserted fourth"),
-                state: State::Open,
+                state: State::from("Open"),
                 time_created: date!(2025-02-10),
-                due_date: None
+                due_date: None,
+                ..Default::default()
             },
             // index 1
             Issue {
                 description: Description::from("Task inserted third"),
-                state: State::Done,
+                state: State::from("Done"),
                 time_created: date!(2025-02-03),
-                due_date: None
+                due_date: None,
+                ..Default::default()
             },
             // index 2
             Issue {
                 description: Description::from("Task inserted second"),
-                state: State::Review,
+                state: State::from("Review"),
                 time_created: date!(2025-02-12),
                 due_date: Some(date!(2025-02-16)),
+                ..Default::default()
             },
             // index 3
             Issue {
                 description: Description::from("Task inserted first"),
-                state: State::Open,
+                state: State::from("Open"),
                 time_created: date!(2025-02-13),
-                due_date: None
+                due_date: None,
+                ..Default::default()
             },
         ]
     }
@@ -591,7 +1216,13 @@ pub(crate) mod test_utils {
     pub(crate) fn check_boards_are_equal(actual: &HistorizedBoard<Issue>, expected: &HistorizedBoard<Issue>) {
         check_compare_issues(actual.entities(), expected.entities());
         check_compare_issues(actual.get_deleted_entities(), expected.get_deleted_entities());
+        check_compare_issues(actual.get_archived_entities(), expected.get_archived_entities());
+        check!(actual.archive_after_days() == expected.archive_after_days());
+        check!(actual.due_soon_days() == expected.due_soon_days());
+        check!(actual.templates() == expected.templates(), "Expected board to have the same templates");
+        check!(actual.fields() == expected.fields(), "Expected board to have the same fields");
         check!(actual.history == expected.history, "Expected board to have the same history");
+        check!(actual.columns() == expected.columns(), "Expected board to have the same columns");
 
     }
 }
\ No newline at end of file
@@ -0,0 +1,66 @@
+use std::fmt::{Display, Formatter};
+use crate::application::domain::error::DomainError;
+
+/// Name of one of the boards of the installation. Board names are case-insensitive, they are
+/// stored in lowercase. They are used as file names, so they can only have letters, digits, `-`
+/// and `_`.
+#[derive(Debug, PartialEq, Eq, Clone, Hash, PartialOrd, Ord)]
+pub struct BoardName(String);
+
+impl BoardName {
+    /// The board that always exists, used until another board is made the default one
+    pub fn main() -> Self {
+        Self(String::from("main"))
+    }
+
+    pub fn is_main(&self) -> bool {
+        *self == Self::main()
+    }
+}
+
+impl Default for BoardName {
+    fn default() -> Self {
+        Self::main()
+    }
+}
+
+impl TryFrom<&str> for BoardName {
+    type Error = DomainError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        let s = s.trim();
+
+        // Board names are used as file names
+        if s.is_empty() || !s.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_') {
+            return Err(DomainError::InvalidBoardName(s.to_string()));
+        }
+
+        Ok(Self(s.to_lowercase()))
+    }
+}
+
+impl Display for BoardName {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert2::{check, let_assert};
+    use crate::application::domain::board_name::BoardName;
+    use crate::application::domain::error::DomainError;
+
+    #[test]
+    fn test_board_names_are_trimmed_and_lowercase() {
+        let_assert!(Ok(name) = BoardName::try_from(" Work_2-b "));
+        check!(name.to_string() == "work_2-b");
+    }
+
+    #[test]
+    fn test_board_names_cannot_be_paths() {
+        for name in ["", "  ", "../work", "work/home", "work.archive", ".hidden", "my board"] {
+            let_assert!(Err(DomainError::InvalidBoardName(_)) = BoardName::try_from(name), "{name:?} should be rejected");
+        }
+    }
+}
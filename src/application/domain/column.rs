@@ -0,0 +1,235 @@
+use crate::application::domain::error::{DomainError, DomainResult};
+use crate::application::domain::issue::DEFAULT_STALE_AFTER_DAYS;
+use crate::application::State;
+
+/// A column of the board. Every issue is in exactly one of the columns.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Column {
+    /// Name of the column, which is also the `State` of the issues in it
+    pub(crate) name: String,
+
+    /// Maximum number of issues in the column (work-in-progress limit)
+    pub(crate) wip_limit: Option<usize>,
+
+    /// Issues moved to the column are placed below the issues of higher priority, instead of on
+    /// the top of the column
+    pub(crate) sort_by_priority: bool,
+
+    /// States the issues of the column may be moved to. Without it, issues may be moved to any
+    /// column.
+    pub(crate) allowed_transitions: Option<Vec<State>>,
+
+    /// Issues in the column for longer than this many days are overdue. Without it, issues
+    /// become overdue only by their due date.
+    pub(crate) stale_after_days: Option<u32>,
+}
+
+impl Column {
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.trim().to_string(),
+            wip_limit: None,
+            sort_by_priority: false,
+            allowed_transitions: None,
+            stale_after_days: Some(DEFAULT_STALE_AFTER_DAYS),
+        }
+    }
+
+    pub fn with_wip_limit(mut self, wip_limit: Option<usize>) -> Self {
+        self.wip_limit = wip_limit;
+        self
+    }
+
+    pub fn wip_limit(&self) -> Option<usize> {
+        self.wip_limit
+    }
+
+    pub fn with_sort_by_priority(mut self, sort_by_priority: bool) -> Self {
+        self.sort_by_priority = sort_by_priority;
+        self
+    }
+
+    pub fn sorts_by_priority(&self) -> bool {
+        self.sort_by_priority
+    }
+
+    pub fn with_allowed_transitions(mut self, allowed_transitions: Option<Vec<State>>) -> Self {
+        self.allowed_transitions = allowed_transitions;
+        self
+    }
+
+    pub fn allowed_transitions(&self) -> Option<&[State]> {
+        self.allowed_transitions.as_deref()
+    }
+
+    /// Whether the issues of the column may be moved to `state`. Staying in the column is always
+    /// allowed.
+    pub fn allows_transition_to(&self, state: &State) -> bool {
+        &self.state() == state || self.allowed_transitions.as_ref()
+            .is_none_or(|allowed| allowed.contains(state))
+    }
+
+    pub fn with_stale_after_days(mut self, stale_after_days: Option<u32>) -> Self {
+        self.stale_after_days = stale_after_days;
+        self
+    }
+
+    pub fn stale_after_days(&self) -> Option<u32> {
+        self.stale_after_days
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn state(&self) -> State {
+        State::from(self.name.as_str())
+    }
+}
+
+/// Ordered list of the columns of a board. The first column is where new issues arrive to by
+/// default, the last column is the one that holds the done issues.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Columns(Vec<Column>);
+
+impl Default for Columns {
+    fn default() -> Self {
+        Self(["Open", "Review", "Done"].into_iter().map(Column::new).collect())
+    }
+}
+
+impl Columns {
+    /// Creates the columns of a board. There has to be at least one column, column names have to
+    /// be unique, and the allowed transitions have to lead to columns of the board.
+    pub fn new(columns: Vec<Column>) -> DomainResult<Self> {
+        if columns.is_empty() {
+            return Err(DomainError::InvalidBoard(String::from("board has to have at least one column")));
+        }
+
+        for (index, column) in columns.iter().enumerate() {
+            if columns[..index].iter().any(|c| c.state() == column.state()) {
+                return Err(DomainError::InvalidBoard(format!("column `{}` is defined more than once", column.name)));
+            }
+        }
+
+        for column in &columns {
+            let unknown_target = column.allowed_transitions().unwrap_or_default().iter()
+                .find(|&target| columns.iter().all(|c| &c.state() != target));
+            if let Some(target) = unknown_target {
+                return Err(DomainError::InvalidBoard(format!("column `{}` allows moving to unknown column `{}`", column.name, target)));
+            }
+        }
+
+        Ok(Self(columns))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Column> {
+        self.0.iter()
+    }
+
+    /// State of the column which new issues are added to by default
+    pub fn first_state(&self) -> State {
+        self.0.first().expect("board has at least one column").state()
+    }
+
+    /// State of the last column, which holds the done issues
+    pub fn done_state(&self) -> State {
+        self.0.last().expect("board has at least one column").state()
+    }
+
+    pub fn is_done(&self, state: &State) -> bool {
+        &self.done_state() == state
+    }
+
+    /// State of the column after the column of `state`, if there is one
+    pub fn next_state(&self, state: &State) -> Option<State> {
+        self.0.iter()
+            .skip_while(|c| &c.state() != state)
+            .nth(1)
+            .map(Column::state)
+    }
+
+    pub fn find(&self, state: &State) -> Option<&Column> {
+        self.0.iter().find(|c| &c.state() == state)
+    }
+
+    /// Looks up the column of `state`, and returns the state with the exact name of the column.
+    pub fn resolve(&self, state: &State) -> DomainResult<State> {
+        self.find(state)
+            .map(Column::state)
+            .ok_or_else(|| DomainError::UnknownState {
+                state: state.to_string(),
+                available: self.0.iter().map(|c| c.name.as_str()).collect::<Vec<_>>().join(", "),
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert2::{check, let_assert};
+    use crate::application::domain::column::{Column, Columns};
+    use crate::application::domain::error::DomainError;
+    use crate::application::State;
+
+    #[test]
+    fn test_default_columns() {
+        let columns = Columns::default();
+
+        check!(columns.first_state() == State::from("Open"));
+        check!(columns.done_state() == State::from("Done"));
+        check!(columns.is_done(&State::from("done")));
+        check!(!columns.is_done(&State::from("Review")));
+    }
+
+    #[test]
+    fn test_next_state() {
+        let columns = Columns::default();
+
+        check!(columns.next_state(&State::from("Open")) == Some(State::from("Review")));
+        check!(columns.next_state(&State::from("Done")) == None);
+        check!(columns.next_state(&State::from("Unknown")) == None);
+    }
+
+    #[test]
+    fn test_resolve_is_case_insensitive() {
+        let columns = given_custom_columns();
+
+        let_assert!(Ok(state) = columns.resolve(&State::from("in progress")));
+        check!(state.to_string() == "In Progress");
+    }
+
+    #[test]
+    fn test_resolve_unknown_state() {
+        let columns = given_custom_columns();
+
+        let_assert!(Err(DomainError::UnknownState { state, available }) = columns.resolve(&State::from("Open")));
+        check!(state == "Open");
+        check!(available == "Backlog, In Progress, Review, QA, Done");
+    }
+
+    #[test]
+    fn test_invalid_columns() {
+        let_assert!(Err(DomainError::InvalidBoard(_)) = Columns::new(vec![]));
+        let_assert!(Err(DomainError::InvalidBoard(reason)) = Columns::new(vec![Column::new("Open"), Column::new("open")]));
+        check!(reason == "column `open` is defined more than once");
+    }
+
+    #[test]
+    fn test_allowed_transitions() {
+        let review = Column::new("Review").with_allowed_transitions(Some(vec![State::from("Open"), State::from("Done")]));
+
+        check!(review.allows_transition_to(&State::from("done")));
+        check!(review.allows_transition_to(&State::from("Review")), "Expected staying in the column to be allowed");
+        check!(!Column::new("Open").with_allowed_transitions(Some(vec![])).allows_transition_to(&State::from("Done")));
+        check!(Column::new("Open").allows_transition_to(&State::from("Done")));
+
+        let_assert!(Err(DomainError::InvalidBoard(reason)) = Columns::new(vec![Column::new("Open"), review]));
+        check!(reason == "column `Review` allows moving to unknown column `Done`");
+    }
+
+    fn given_custom_columns() -> Columns {
+        Columns::new(
+            ["Backlog", "In Progress", "Review", "QA", "Done"].into_iter().map(Column::new).collect()
+        ).unwrap()
+    }
+}
@@ -0,0 +1,155 @@
+use std::fmt::{Display, Formatter};
+use crate::application::domain::date_parse::DateParser;
+use crate::application::domain::error::{DomainError, DomainResult};
+use crate::application::ports::time::TodayProvider;
+
+/// Type of the values of a custom field
+#[derive(Debug, PartialEq, Clone)]
+pub enum FieldType {
+    Text,
+    Number,
+    /// Dates are given like due dates, e.g. `2025-03-01` or `friday`
+    Date,
+    /// One of the given values, compared case-insensitively
+    Enum(Vec<String>),
+}
+
+impl Display for FieldType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FieldType::Text => write!(f, "a text"),
+            FieldType::Number => write!(f, "a number"),
+            FieldType::Date => write!(f, "a date"),
+            FieldType::Enum(values) if values.is_empty() => write!(f, "no value"),
+            FieldType::Enum(values) => write!(f, "one of {}", values.join(", ")),
+        }
+    }
+}
+
+/// Metadata that a board declares for its issues, e.g. the customer or the ticket number of an issue
+#[derive(Debug, PartialEq, Clone)]
+pub struct CustomField {
+    pub(crate) name: String,
+    pub(crate) field_type: FieldType,
+
+    /// The values of the field are shown on the board, not only in the details of the issues
+    pub(crate) show: bool,
+}
+
+impl CustomField {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Whether the field is called `name`, ignoring the case
+    pub fn is_named(&self, name: &str) -> bool {
+        self.name.eq_ignore_ascii_case(name.trim())
+    }
+
+    /// The value in its canonical form, e.g. dates as `2025-03-01`. Numbers are kept as given, so
+    /// that ticket numbers like `007` keep their leading zeros. Values that are not of the type of
+    /// the field are rejected.
+    pub fn parse_value<T: TodayProvider>(&self, value: &str, date_parser: &DateParser<T>) -> DomainResult<String> {
+        let value = value.trim();
+        let invalid = || DomainError::InvalidFieldValue {
+            field: self.name.clone(),
+            value: value.to_string(),
+            expected: self.field_type.to_string(),
+        };
+
+        match &self.field_type {
+            FieldType::Text => Ok(value.to_string()),
+            FieldType::Number => value.parse::<f64>().ok()
+                .filter(|number| number.is_finite())
+                .map(|_| value.to_string())
+                .ok_or_else(invalid),
+            FieldType::Date => date_parser.parse(value)
+                .map(|date| date.to_string())
+                .map_err(|_| invalid()),
+            FieldType::Enum(values) => values.iter()
+                .find(|allowed| allowed.eq_ignore_ascii_case(value))
+                .cloned()
+                .ok_or_else(invalid),
+        }
+    }
+}
+
+/// A value given to a custom field, like `customer=acme`. Without a value, the field is unset.
+#[derive(Debug, PartialEq, Clone)]
+pub struct FieldAssignment {
+    pub(crate) name: String,
+    pub(crate) value: Option<String>,
+}
+
+impl FieldAssignment {
+    pub fn new(name: &str, value: Option<&str>) -> Self {
+        Self {
+            name: name.trim().to_string(),
+            value: value.map(str::trim).filter(|value| !value.is_empty()).map(str::to_string),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert2::{check, let_assert};
+    use crate::adapters::time_providers::fake::FakeTodayProvider;
+    use crate::application::domain::custom_field::{CustomField, FieldAssignment, FieldType};
+    use crate::application::domain::date_parse::DateParser;
+    use crate::application::domain::error::DomainError;
+
+    #[test]
+    fn test_parse_values() {
+        let today_provider = FakeTodayProvider::default();
+        let date_parser = DateParser {
+            today_provider: &today_provider,
+        };
+        let field_of = |field_type| CustomField {
+            name: String::from("field"),
+            field_type,
+            show: false,
+        };
+        let environment = field_of(FieldType::Enum(vec![String::from("staging"), String::from("prod")]));
+
+        check!(field_of(FieldType::Text).parse_value(" acme corp ", &date_parser).ok().as_deref() == Some("acme corp"));
+        check!(field_of(FieldType::Number).parse_value(" 1234.0 ", &date_parser).ok().as_deref() == Some("1234.0"));
+        check!(field_of(FieldType::Number).parse_value("007", &date_parser).ok().as_deref() == Some("007"));
+        check!(field_of(FieldType::Number).parse_value("12345678901234567891", &date_parser).ok().as_deref() == Some("12345678901234567891"));
+        check!(field_of(FieldType::Number).parse_value("-2.5", &date_parser).ok().as_deref() == Some("-2.5"));
+        check!(field_of(FieldType::Date).parse_value("friday", &date_parser).ok().as_deref() == Some("2025-02-28"));
+        check!(environment.parse_value("PROD", &date_parser).ok().as_deref() == Some("prod"));
+    }
+
+    #[test]
+    fn test_invalid_values_are_rejected() {
+        let today_provider = FakeTodayProvider::default();
+        let date_parser = DateParser {
+            today_provider: &today_provider,
+        };
+        let environment = CustomField {
+            name: String::from("environment"),
+            field_type: FieldType::Enum(vec![String::from("staging"), String::from("prod")]),
+            show: false,
+        };
+
+        let_assert!(Err(DomainError::InvalidFieldValue { field, value, expected }) = environment.parse_value("dev", &date_parser));
+        check!(field == "environment");
+        check!(value == "dev");
+        check!(expected == "one of staging, prod");
+
+        for (field_type, value) in [(FieldType::Number, "12a"), (FieldType::Number, "NaN"), (FieldType::Date, "someday")] {
+            let field = CustomField {
+                name: String::from("field"),
+                field_type,
+                show: false,
+            };
+            check!(field.parse_value(value, &date_parser).is_err(), "Expected `{}` to be rejected", value);
+        }
+    }
+
+    #[test]
+    fn test_assignment_without_value_unsets_the_field() {
+        check!(FieldAssignment::new("customer", Some(" ")) == FieldAssignment { name: String::from("customer"), value: None });
+        check!(FieldAssignment::new(" customer ", Some("acme")).value == Some(String::from("acme")));
+    }
+}
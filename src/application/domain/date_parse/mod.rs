@@ -1,5 +1,7 @@
 pub mod parse_date;
 pub mod error;
+pub mod recurrence;
 mod parser;
 
-pub use parse_date::DateParser;
\ No newline at end of file
+pub use parse_date::DateParser;
+pub use recurrence::Recurrence;
\ No newline at end of file
@@ -1,6 +1,7 @@
 use time;
 use crate::application::domain::date_parse::parser::{ParsedDate, ParsedDateAst};
 use crate::application::domain::date_parse::error::DateParseError;
+use crate::application::domain::date_parse::recurrence::Recurrence;
 use crate::application::ports::time::TodayProvider;
 use chumsky::Parser;
 
@@ -15,6 +16,7 @@ impl<T: TodayProvider> DateParser<'_, T> {
     /// - Date in the format of `yyyy-mm-dd` or `mm-dd` or `dd`
     ///   + 0 padding is accepted for years, months, days
     /// - "today", "tomorrow"
+    /// - "in 3 days", "in 2 weeks"
     /// - "m" "tu", "w", "th", "f", "sa", "su" for the next occurrence of that weekday (excluding today).
     pub(crate) fn parse(&self, text: &str) -> Result<time::Date, DateParseError> {
             ParsedDateAst::parser()
@@ -25,15 +27,43 @@ impl<T: TodayProvider> DateParser<'_, T> {
                         .map_err(DateParseError::from)
     }
 
+    /// Due date of the next occurrence of a recurring issue, when it is done today.
+    pub(crate) fn next_occurrence(&self, recurrence: &Recurrence) -> Result<time::Date, DateParseError> {
+        self.eval(recurrence.next_due())
+    }
+
     pub fn eval(&self, parsed_date: ParsedDateAst) -> Result<time::Date, DateParseError> {
         match parsed_date {
             ParsedDateAst::Today => Ok(self.today_provider.today()),
             ParsedDateAst::Tomorrow => Ok(self.today_provider.today().next_day().unwrap()),
             ParsedDateAst::RelativeWeekDay(weekday) => Ok(self.today_provider.today().next_occurrence(weekday)),
+            ParsedDateAst::RelativeMonthDay(day) => self.next_month_day(day),
+            ParsedDateAst::InDays(days) => Ok(self.today_provider.today() + time::Duration::days(days.into())),
             ParsedDateAst::ParsedDate(parsed_date) => self.try_from_parsed_date(parsed_date)
         }
     }
 
+    /// The next date with the given day of the month, excluding today. In months that are
+    /// shorter, the last day of the month is taken instead.
+    fn next_month_day(&self, day: u8) -> Result<time::Date, DateParseError> {
+        let today = self.today_provider.today();
+
+        let in_this_month = Self::clamped_date(today.year(), today.month(), day)?;
+        if in_this_month > today {
+            return Ok(in_this_month);
+        }
+
+        match today.month() {
+            time::Month::December => Self::clamped_date(today.year() + 1, time::Month::January, day),
+            month => Self::clamped_date(today.year(), month.next(), day),
+        }
+    }
+
+    fn clamped_date(year: i32, month: time::Month, day: u8) -> Result<time::Date, DateParseError> {
+        time::Date::from_calendar_date(year, month, day.min(month.length(year)))
+            .map_err(DateParseError::from)
+    }
+
     fn try_from_parsed_date(&self, parsed_date: ParsedDate) -> Result<time::Date, DateParseError> {
         let ParsedDate { year, month, day } = parsed_date;
 
@@ -48,8 +78,10 @@ impl<T: TodayProvider> DateParser<'_, T> {
 mod tests {
     use assert2::{check, let_assert};
     use time::macros::date;
+    use time::Weekday;
     use crate::adapters::time_providers::fake::FakeTodayProvider;
     use crate::application::domain::date_parse::parse_date::DateParser;
+    use crate::application::domain::date_parse::recurrence::Recurrence;
 
     #[test]
     fn test_parse_date() {
@@ -75,6 +107,9 @@ mod tests {
             ("f", date!(2025-02-28)),
             ("Saturda", date!(2025-03-01)),
             ("SU", date!(2025-02-23)),
+            ("in 3 days", date!(2025-02-25)),
+            ("in 1 day", date!(2025-02-23)),
+            ("in 2 weeks", date!(2025-03-08)),
         ];
 
         for (input, expected_output) in test_table_success {
@@ -86,7 +121,8 @@ mod tests {
         let test_table_failure = [
             "unparsable","-", "--", "tomorroww", "2034-",
             "2024-23-01", "2025-02-29", "-2-02-02",
-            "t", "-2034", "", "2025-02-29-12", "41", "02025-02-11"];
+            "t", "-2034", "", "2025-02-29-12", "41", "02025-02-11",
+            "in", "in days", "in 3", "in 3 months"];
 
         for input in test_table_failure {
             let result = date_parser.parse(input);
@@ -94,4 +130,39 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_next_occurrence() {
+        // date!(2025-02-22) // saturday
+        let fake_pr = FakeTodayProvider::default();
+        let date_parser = DateParser {
+            today_provider: &fake_pr,
+        };
+
+        let test_table = [
+            (Recurrence::Weekday(Weekday::Monday), date!(2025-02-24)),
+            (Recurrence::Weekday(Weekday::Saturday), date!(2025-03-01)),
+            (Recurrence::Days(1), date!(2025-02-23)),
+            (Recurrence::Weeks(2), date!(2025-03-08)),
+            (Recurrence::MonthDay(1), date!(2025-03-01)),
+            (Recurrence::MonthDay(22), date!(2025-03-22)),
+            (Recurrence::MonthDay(25), date!(2025-02-25)),
+            (Recurrence::MonthDay(31), date!(2025-02-28)),
+        ];
+
+        for (recurrence, expected_output) in test_table {
+            let_assert!(Ok(result) = date_parser.next_occurrence(&recurrence), "recurrence: {}", recurrence);
+            check!(result == expected_output, "recurrence: {}", recurrence);
+        }
+    }
+
+    #[test]
+    fn test_next_monthly_occurrence_at_the_end_of_the_year() {
+        let fake_pr = FakeTodayProvider { fake_today_answer: date!(2025-12-31) };
+        let date_parser = DateParser {
+            today_provider: &fake_pr,
+        };
+
+        check!(date_parser.next_occurrence(&Recurrence::MonthDay(31)).ok() == Some(date!(2026-01-31)));
+    }
+
 }
@@ -1,5 +1,6 @@
 use std::ops::Range;
 use chumsky::{text, Parser};
+use chumsky::text::TextParser;
 use chumsky::error::Simple;
 use chumsky::prelude::{choice, end, filter, just, one_of};
 
@@ -8,6 +9,10 @@ pub enum ParsedDateAst {
     Today,
     Tomorrow,
     RelativeWeekDay(time::Weekday),
+    /// The given day of the month, after today. Used for recurrences, it has no textual form.
+    RelativeMonthDay(u8),
+    /// The given number of days after today, e.g. `in 3 days` or `in 2 weeks`
+    InDays(u32),
     ParsedDate(ParsedDate)
 }
 
@@ -16,18 +21,38 @@ impl ParsedDateAst {
         choice((
             just("today").or(just("now")).to(Self::Today),
             just("tomorrow").to(Self::Tomorrow),
+            Self::in_days_parser(),
             Self::weekday_parser(),
             ParsedDate::parser().map(Self::ParsedDate),
         )).then_ignore(end())
     }
 
+    fn in_days_parser() -> impl Parser<char, Self, Error = Simple<char>> {
+        let unit = choice((
+            just("days").or(just("day")).to(1),
+            just("weeks").or(just("week")).to(7),
+        ));
+
+        just("in")
+            .ignore_then(text::int(10).padded())
+            .then(unit)
+            .try_map(|(count, unit): (String, u32), span| count.parse::<u32>()
+                .ok()
+                .and_then(|count| count.checked_mul(unit))
+                .map(Self::InDays)
+                .ok_or_else(|| Simple::custom(span, "too many days")))
+    }
+
     fn weekday_parser() -> impl Parser<char, Self, Error = Simple<char>> {
+        Self::weekday().map(ParsedDateAst::RelativeWeekDay)
+    }
+
+    pub(super) fn weekday() -> impl Parser<char, time::Weekday, Error = Simple<char>> {
         filter(char::is_ascii_alphabetic)
             .repeated()
             .at_least(1)
             .collect::<String>()
             .try_map(Self::text2weekday)
-            .map(ParsedDateAst::RelativeWeekDay)
     }
 
     fn text2weekday(text: String, span: Range<usize>) -> Result<time::Weekday, Simple<char>> {
@@ -0,0 +1,121 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+use chumsky::{text, Parser};
+use chumsky::error::Simple;
+use chumsky::prelude::{choice, end, just};
+use crate::application::domain::date_parse::error::DateParseError;
+use crate::application::domain::date_parse::parser::ParsedDateAst;
+
+/// Rule of a recurring issue. When the issue is done, its next occurrence is due according to the rule.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum Recurrence {
+    /// "every monday"
+    Weekday(time::Weekday),
+    /// "every day", "every 3 days"
+    Days(u32),
+    /// "every week", "every 2 weeks"
+    Weeks(u32),
+    /// "monthly on 1"
+    MonthDay(u8),
+}
+
+impl Recurrence {
+    fn parser() -> impl Parser<char, Self, Error = Simple<char>> {
+        let space = just(' ').repeated().at_least(1).ignored();
+        let number = || text::int(10)
+            .try_map(|s: String, span| s.parse::<u32>()
+                .map_err(|e| Simple::custom(span, e.to_string())));
+
+        let every = just("every").then(space).ignore_then(choice((
+            just("day").to(Self::Days(1)),
+            just("week").to(Self::Weeks(1)),
+            number()
+                .then_ignore(space)
+                .then(choice((just("days").to(false), just("weeks").to(true))))
+                .try_map(|(count, weeks), span| match (count, weeks) {
+                    (count @ 1..=999, false) => Ok(Self::Days(count)),
+                    (count @ 1..=999, true) => Ok(Self::Weeks(count)),
+                    _ => Err(Simple::custom(span, "count must be between 1 and 999")),
+                }),
+            ParsedDateAst::weekday().map(Self::Weekday),
+        )));
+
+        let monthly = just("monthly")
+            .then(space)
+            .then(just("on"))
+            .then(space)
+            .ignore_then(number())
+            .try_map(|day, span| match u8::try_from(day) {
+                Ok(day @ 1..=31) => Ok(Self::MonthDay(day)),
+                _ => Err(Simple::custom(span, "not a day of the month")),
+            });
+
+        choice((every, monthly)).then_ignore(end())
+    }
+
+    /// The due date of the next occurrence, relative to today.
+    pub(crate) fn next_due(&self) -> ParsedDateAst {
+        match *self {
+            Self::Weekday(weekday) => ParsedDateAst::RelativeWeekDay(weekday),
+            Self::Days(days) => ParsedDateAst::InDays(days),
+            Self::Weeks(weeks) => ParsedDateAst::InDays(weeks * 7),
+            Self::MonthDay(day) => ParsedDateAst::RelativeMonthDay(day),
+        }
+    }
+}
+
+impl FromStr for Recurrence {
+    type Err = DateParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parser()
+            .parse(s.trim().to_lowercase())
+            .map_err(DateParseError::from)
+    }
+}
+
+impl Display for Recurrence {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Weekday(weekday) => write!(f, "every {}", weekday.to_string().to_lowercase()),
+            Self::Days(1) => write!(f, "every day"),
+            Self::Days(days) => write!(f, "every {} days", days),
+            Self::Weeks(1) => write!(f, "every week"),
+            Self::Weeks(weeks) => write!(f, "every {} weeks", weeks),
+            Self::MonthDay(day) => write!(f, "monthly on {}", day),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert2::{check, let_assert};
+    use time::Weekday;
+    use crate::application::domain::date_parse::recurrence::Recurrence;
+
+    #[test]
+    fn test_parse_recurrence() {
+        let test_table = [
+            ("every monday", Recurrence::Weekday(Weekday::Monday), "every monday"),
+            ("Every Fri", Recurrence::Weekday(Weekday::Friday), "every friday"),
+            ("every day", Recurrence::Days(1), "every day"),
+            ("every 3 days", Recurrence::Days(3), "every 3 days"),
+            ("every week", Recurrence::Weeks(1), "every week"),
+            ("every 2 weeks", Recurrence::Weeks(2), "every 2 weeks"),
+            ("every 1 weeks", Recurrence::Weeks(1), "every week"),
+            ("monthly on 1", Recurrence::MonthDay(1), "monthly on 1"),
+            ("monthly on 31", Recurrence::MonthDay(31), "monthly on 31"),
+        ];
+
+        for (input, expected, displayed) in test_table {
+            let_assert!(Ok(recurrence) = input.parse::<Recurrence>(), "input: {}", input);
+            check!(recurrence == expected, "input: {}", input);
+            check!(recurrence.to_string() == displayed);
+            check!(displayed.parse::<Recurrence>().ok() == Some(recurrence), "Expected the displayed form to be parsable");
+        }
+
+        for input in ["", "every", "every 0 days", "every 1000 weeks", "every month", "monthly on 0", "monthly on 32", "weekly", "every mondays", "every 2"] {
+            check!(input.parse::<Recurrence>().is_err(), "input: {}", input);
+        }
+    }
+}
@@ -2,6 +2,8 @@ use std::io;
 use nonempty_collections::NEVec;
 use thiserror::Error;
 use crate::application::domain::date_parse::error::DateParseError;
+use crate::application::domain::board_name::BoardName;
+use crate::application::issue::{IssueRef, ShortId};
 
 pub type DomainResult<T> = Result<T, DomainError>;
 pub type DomainResultMultiError<T> = Result<T, NEVec<DomainError>>;
@@ -10,11 +12,79 @@ pub type DomainResultMultiError<T> = Result<T, NEVec<DomainError>>;
 pub enum DomainError {
     #[error("Index `{0}` is out of range")]
     IndexOutOfRange(usize),
+    #[error("Issue `{0}` does not exist")]
+    ShortIdNotFound(ShortId),
+    #[error("Checklist item `{0}` does not exist")]
+    ChecklistItemNotFound(usize),
+    #[error("Link `{0}` does not exist")]
+    LinkNotFound(usize),
+    #[error("Issue `{issue}` is blocked by {blockers}")]
+    BlockedIssue {
+        issue: ShortId,
+        blockers: String,
+    },
+    #[error("Column `{column}` would exceed its WIP limit of {limit}")]
+    WipLimitExceeded {
+        column: String,
+        limit: usize,
+    },
+    #[error("Issues in `{from}` cannot be moved to `{to}`, allowed targets: {allowed}. Use --force to move them anyway")]
+    TransitionNotAllowed {
+        from: String,
+        to: String,
+        allowed: String,
+    },
+    #[error("Issue `{blocked}` cannot be blocked by `{blocker}`, because it would make a dependency cycle")]
+    DependencyCycle {
+        blocked: ShortId,
+        blocker: ShortId,
+    },
+    #[error("Issue `{parent}` cannot be the parent of `{child}`, because it would make a cycle")]
+    ParentCycle {
+        child: ShortId,
+        parent: ShortId,
+    },
+    #[error("Issue `{issue}` is done, but has open children: {children}")]
+    OpenChildren {
+        issue: ShortId,
+        children: String,
+    },
+    #[error("Comment cannot be empty")]
+    EmptyComment,
+    #[error("Link cannot be empty")]
+    EmptyLink,
+    #[error("Block reason cannot be empty")]
+    EmptyBlockReason,
+    #[error("Issue `{0}` is not blocked")]
+    NotBlocked(IssueRef),
+    #[error("No issue is being tracked")]
+    NoTrackedIssue,
+    #[error("No user is configured, set KANBAN_USER to your name")]
+    UnknownUser,
+    #[error("Board name `{0}` can only have letters, digits, `-` and `_`")]
+    InvalidBoardName(String),
+    #[error("Board `{0}` does not exist")]
+    BoardNotFound(BoardName),
+    #[error("Board `{0}` already exists")]
+    BoardAlreadyExists(BoardName),
+    #[error("Board `{0}` still has issues, use --force to delete it")]
+    BoardNotEmpty(BoardName),
+    #[error("The main board cannot be renamed or deleted")]
+    MainBoardIsPermanent,
+    #[error("Issue is already on board `{0}`")]
+    TransferToSameBoard(BoardName),
+    #[error("Issue `{issue}` is no longer on board `{board}`, so its transfer cannot be undone")]
+    TransferredIssueNotFound {
+        issue: ShortId,
+        board: BoardName,
+    },
     #[error("Editor failed with error: {source}")]
     EditorError {
         #[from]
         source: io::Error
     },
+    #[error("Opening the link failed with error: {0}")]
+    OpenerError(io::Error),
 
     #[error("Invalid board: {0}")]
     InvalidBoard(String),
@@ -24,6 +94,31 @@ pub enum DomainError {
 
     #[error("Parse error: {0}")]
     DateParseError(#[from] DateParseError),
+
+    #[error("Unknown state `{state}`. Available states: {available}")]
+    UnknownState {
+        state: String,
+        available: String,
+    },
+
+    #[error("Unknown template `{name}`. Available templates: {available}")]
+    TemplateNotFound {
+        name: String,
+        available: String,
+    },
+
+    #[error("Unknown field `{name}`. Available fields: {available}")]
+    UnknownField {
+        name: String,
+        available: String,
+    },
+
+    #[error("`{value}` is not a valid value for `{field}`, expected {expected}")]
+    InvalidFieldValue {
+        field: String,
+        value: String,
+        expected: String,
+    },
 }
 
 #[cfg(test)]
@@ -35,13 +130,75 @@ mod tests {
         pub(crate) fn clone_for_testing(&self) -> DomainError {
             match self {
                 DomainError::IndexOutOfRange(e) => DomainError::IndexOutOfRange(*e),
+                DomainError::ShortIdNotFound(e) => DomainError::ShortIdNotFound(*e),
+                DomainError::ChecklistItemNotFound(e) => DomainError::ChecklistItemNotFound(*e),
+                DomainError::LinkNotFound(e) => DomainError::LinkNotFound(*e),
+                DomainError::BlockedIssue { issue, blockers } => DomainError::BlockedIssue {
+                    issue: *issue,
+                    blockers: blockers.clone(),
+                },
+                DomainError::WipLimitExceeded { column, limit } => DomainError::WipLimitExceeded {
+                    column: column.clone(),
+                    limit: *limit,
+                },
+                DomainError::TransitionNotAllowed { from, to, allowed } => DomainError::TransitionNotAllowed {
+                    from: from.clone(),
+                    to: to.clone(),
+                    allowed: allowed.clone(),
+                },
+                DomainError::DependencyCycle { blocked, blocker } => DomainError::DependencyCycle {
+                    blocked: *blocked,
+                    blocker: *blocker,
+                },
+                DomainError::ParentCycle { child, parent } => DomainError::ParentCycle {
+                    child: *child,
+                    parent: *parent,
+                },
+                DomainError::OpenChildren { issue, children } => DomainError::OpenChildren {
+                    issue: *issue,
+                    children: children.clone(),
+                },
+                DomainError::EmptyComment => DomainError::EmptyComment,
+                DomainError::EmptyLink => DomainError::EmptyLink,
+                DomainError::EmptyBlockReason => DomainError::EmptyBlockReason,
+                DomainError::NotBlocked(e) => DomainError::NotBlocked(*e),
+                DomainError::NoTrackedIssue => DomainError::NoTrackedIssue,
+                DomainError::UnknownUser => DomainError::UnknownUser,
+                DomainError::InvalidBoardName(name) => DomainError::InvalidBoardName(name.clone()),
+                DomainError::BoardNotFound(name) => DomainError::BoardNotFound(name.clone()),
+                DomainError::BoardAlreadyExists(name) => DomainError::BoardAlreadyExists(name.clone()),
+                DomainError::BoardNotEmpty(name) => DomainError::BoardNotEmpty(name.clone()),
+                DomainError::MainBoardIsPermanent => DomainError::MainBoardIsPermanent,
+                DomainError::TransferToSameBoard(name) => DomainError::TransferToSameBoard(name.clone()),
+                DomainError::TransferredIssueNotFound { issue, board } => DomainError::TransferredIssueNotFound {
+                    issue: *issue,
+                    board: board.clone(),
+                },
                 DomainError::EditorError { source} => DomainError::EditorError {
                     // Here we lose the error message
                     source: Error::from(source.kind().clone()),
                 },
+                DomainError::OpenerError(e) => DomainError::OpenerError(Error::from(e.kind())),
                 DomainError::InvalidBoard(e) => DomainError::InvalidBoard(e.clone()),
                 DomainError::EmptyHistory => DomainError::EmptyHistory,
                 DomainError::DateParseError(e) => DomainError::DateParseError(e.clone()),
+                DomainError::UnknownState { state, available } => DomainError::UnknownState {
+                    state: state.clone(),
+                    available: available.clone(),
+                },
+                DomainError::TemplateNotFound { name, available } => DomainError::TemplateNotFound {
+                    name: name.clone(),
+                    available: available.clone(),
+                },
+                DomainError::UnknownField { name, available } => DomainError::UnknownField {
+                    name: name.clone(),
+                    available: available.clone(),
+                },
+                DomainError::InvalidFieldValue { field, value, expected } => DomainError::InvalidFieldValue {
+                    field: field.clone(),
+                    value: value.clone(),
+                    expected: expected.clone(),
+                },
             }
         }
     }
@@ -0,0 +1,129 @@
+use time::Date;
+use crate::application::domain::custom_field::FieldAssignment;
+use crate::application::issue::{Assignee, ShortId, Tag};
+use crate::application::Issue;
+
+/// Selects which issues of the board are displayed. An empty filter matches every issue.
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct IssueFilter {
+    /// Issues have to have all of these tags
+    pub(crate) tags: Vec<Tag>,
+    /// Issues have to be assigned to this person
+    pub(crate) assignee: Option<Assignee>,
+    /// Issues that are still waiting on this day are hidden
+    pub(crate) hide_waiting_on: Option<Date>,
+    /// Issues have to be children of this issue
+    pub(crate) parent: Option<ShortId>,
+    /// Issues have to have these values of custom fields. A field without a value has to be unset.
+    pub(crate) fields: Vec<FieldAssignment>,
+}
+
+impl IssueFilter {
+    pub fn matches(&self, issue: &Issue) -> bool {
+        self.tags.iter().all(|tag| issue.has_tag(tag))
+            && self.assignee.as_ref().is_none_or(|assignee| issue.assignee.as_ref() == Some(assignee))
+            && self.hide_waiting_on.is_none_or(|today| !issue.is_waiting(today))
+            && self.parent.is_none_or(|parent| issue.parent == Some(parent))
+            && self.fields.iter().all(|field| issue.fields.get(&field.name) == field.value.as_ref())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert2::check;
+    use time::macros::date;
+    use crate::application::domain::filter::IssueFilter;
+    use std::collections::BTreeMap;
+    use crate::application::domain::custom_field::FieldAssignment;
+    use crate::application::issue::{Assignee, ShortId, Tag};
+    use crate::application::Issue;
+
+    #[test]
+    fn test_filter_by_tags() {
+        let issue = Issue {
+            tags: vec![Tag::from("backend"), Tag::from("urgent")],
+            ..Default::default()
+        };
+
+        for (filter_tags, expected_match) in [
+            (vec![], true),
+            (vec!["backend"], true),
+            (vec!["URGENT", "backend"], true),
+            (vec!["frontend"], false),
+            (vec!["backend", "frontend"], false),
+        ] {
+            let filter = IssueFilter {
+                tags: filter_tags.iter().map(|&t| Tag::from(t)).collect(),
+                ..Default::default()
+            };
+
+            check!(filter.matches(&issue) == expected_match, "filter tags: {:?}", filter_tags);
+        }
+    }
+
+    #[test]
+    fn test_filter_by_assignee() {
+        let issue = Issue {
+            assignee: Some(Assignee::from("alice")),
+            ..Default::default()
+        };
+        let filter_of = |assignee: &str| IssueFilter {
+            assignee: Some(Assignee::from(assignee)),
+            ..Default::default()
+        };
+
+        check!(filter_of("Alice").matches(&issue));
+        check!(!filter_of("bob").matches(&issue));
+        check!(!filter_of("alice").matches(&Issue::default()), "Expected unassigned issues not to match");
+    }
+
+    #[test]
+    fn test_filter_waiting_issues() {
+        let issue = Issue {
+            wait_until: Some(date!(2025-03-01)),
+            ..Default::default()
+        };
+        let filter_on = |today| IssueFilter {
+            hide_waiting_on: Some(today),
+            ..Default::default()
+        };
+
+        check!(!filter_on(date!(2025-02-28)).matches(&issue));
+        check!(filter_on(date!(2025-03-01)).matches(&issue), "Expected the issue to show up on its wait date");
+        check!(IssueFilter::default().matches(&issue), "Expected waiting issues to match without hiding them");
+        check!(filter_on(date!(2025-02-28)).matches(&Issue::default()));
+    }
+
+    #[test]
+    fn test_filter_by_parent() {
+        let issue = Issue {
+            parent: Some(ShortId(12)),
+            ..Default::default()
+        };
+        let filter_of = |parent| IssueFilter {
+            parent: Some(ShortId(parent)),
+            ..Default::default()
+        };
+
+        check!(filter_of(12).matches(&issue));
+        check!(!filter_of(13).matches(&issue));
+        check!(!filter_of(12).matches(&Issue::default()), "Expected issues without parent not to match");
+    }
+
+    #[test]
+    fn test_filter_by_fields() {
+        let issue = Issue {
+            fields: BTreeMap::from([(String::from("customer"), String::from("acme"))]),
+            ..Default::default()
+        };
+        let filter_of = |name: &str, value: Option<&str>| IssueFilter {
+            fields: vec![FieldAssignment::new(name, value)],
+            ..Default::default()
+        };
+
+        check!(filter_of("customer", Some("acme")).matches(&issue));
+        check!(!filter_of("customer", Some("globex")).matches(&issue));
+        check!(!filter_of("customer", None).matches(&issue));
+        check!(filter_of("ticket", None).matches(&issue), "Expected a field without value to match unset fields");
+    }
+}
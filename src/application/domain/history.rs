@@ -1,8 +1,15 @@
+use std::collections::BTreeMap;
+use crate::application::domain::board_name::BoardName;
+use crate::application::domain::date_parse::Recurrence;
+use crate::application::issue::{Assignee, ChecklistItem, Link, Priority, ShortId, Tag};
 use crate::application::State;
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct MoveHistoryElements {
     pub moves: Vec<MoveHistoryElement>,
+    /// Number of next occurrences of recurring issues that were added on the top of the board,
+    /// because they were moved to done
+    pub created_occurrences: usize,
 }
 
 #[derive(Clone, Debug, PartialEq)]
@@ -15,6 +22,10 @@ pub struct MoveHistoryElement {
     /// It can happen that moving changes priorities.
     /// If it does, then new_index is different from original_index.
     pub new_index: usize,
+
+    /// The move broke the allowed transitions of the board, which is recorded in the activity of
+    /// the issue too
+    pub forced: bool,
 }
 
 #[derive(Clone, Debug, PartialEq)]
@@ -26,6 +37,7 @@ pub struct PrioHistoryElement {
 #[derive(Clone, Debug, PartialEq)]
 pub struct EditHistoryElement {
     pub original_description: String,
+    pub original_body: Option<String>,
     pub index: usize,
 }
 
@@ -40,6 +52,25 @@ pub struct DeleteHistoryElement {
     pub(crate) original_position_in_issues: usize,
 }
 
+#[derive(Clone, Debug, PartialEq)]
+pub struct ArchiveHistoryElements {
+    pub archivals: Vec<ArchiveHistoryElement>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ArchiveHistoryElement {
+    /// The position in which the issue had been located just before it was archived.
+    pub(crate) original_position_in_issues: usize,
+}
+
+/// An archived issue that was moved back to the board
+#[derive(Clone, Debug, PartialEq)]
+pub struct UnarchiveHistoryElement {
+    /// Index of the issue on the board after it was restored
+    pub(crate) index: usize,
+    pub(crate) original_position_in_archive: usize,
+}
+
 #[derive(Clone, Debug, PartialEq)]
 pub struct FlushHistoryElement {
     /// Number of issues moved to the deleted elements by the flush command.
@@ -52,6 +83,106 @@ pub struct DueHistoryElement {
     pub(crate) previous_due: Option<time::Date>,
 }
 
+#[derive(Clone, Debug, PartialEq)]
+pub struct TagHistoryElement {
+    pub(crate) index: usize,
+    /// Tags of the issue before they were changed
+    pub(crate) original_tags: Vec<Tag>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ChecklistHistoryElement {
+    pub(crate) index: usize,
+    /// Checklist of the issue before it was changed
+    pub(crate) original_checklist: Vec<ChecklistItem>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct DependencyHistoryElement {
+    pub(crate) index: usize,
+    /// Blockers of the issue before they were changed
+    pub(crate) original_blocked_by: Vec<ShortId>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct RecurHistoryElement {
+    pub(crate) index: usize,
+    pub(crate) original_recurrence: Option<Recurrence>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct EstimateHistoryElement {
+    pub(crate) index: usize,
+    pub(crate) original_estimate: Option<u32>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct AssignHistoryElement {
+    pub(crate) index: usize,
+    pub(crate) original_assignee: Option<Assignee>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct PriorityLevelHistoryElement {
+    pub(crate) index: usize,
+    pub(crate) original_priority: Priority,
+}
+
+/// An issue that was moved to another board. It was added to the bottom of the other board.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TransferHistoryElement {
+    /// Index of the issue before it was transferred
+    pub(crate) index: usize,
+    /// The board that the issue was transferred to
+    pub(crate) board: BoardName,
+    /// Short id of the issue on the other board
+    pub(crate) short_id: ShortId,
+    pub(crate) original_short_id: Option<ShortId>,
+    pub(crate) original_state: State,
+    /// Dependencies and the parent are dropped, because short ids refer to issues of their own board
+    pub(crate) original_blocked_by: Vec<ShortId>,
+    pub(crate) original_parent: Option<ShortId>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct CommentHistoryElement {
+    pub(crate) index: usize,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct LinkHistoryElement {
+    pub(crate) index: usize,
+    /// Links of the issue before they were changed
+    pub(crate) original_links: Vec<Link>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ParentHistoryElement {
+    pub(crate) index: usize,
+    /// Parent of the issue before it was changed
+    pub(crate) original_parent: Option<ShortId>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct FieldsHistoryElement {
+    pub(crate) index: usize,
+    /// Values of the custom fields of the issue before they were changed
+    pub(crate) original_fields: BTreeMap<String, String>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct AddTemplateHistoryElement {
+    /// Number of issues the template added on the top of the board
+    pub(crate) count: usize,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct BlockHistoryElement {
+    pub(crate) index: usize,
+    /// Reason of the block before it was changed, `None` if the issue was not blocked
+    pub(crate) original_reason: Option<String>,
+}
+
 #[derive(Debug, PartialEq, Clone)]
 pub enum UndoableHistoryElement {
     Add,
@@ -61,6 +192,22 @@ pub enum UndoableHistoryElement {
     Edit(EditHistoryElement),
     Flush(FlushHistoryElement),
     Due(DueHistoryElement),
+    Tag(TagHistoryElement),
+    Checklist(ChecklistHistoryElement),
+    Dependency(DependencyHistoryElement),
+    Comment(CommentHistoryElement),
+    Recur(RecurHistoryElement),
+    Estimate(EstimateHistoryElement),
+    Transfer(TransferHistoryElement),
+    Assign(AssignHistoryElement),
+    PriorityLevel(PriorityLevelHistoryElement),
+    Archive(ArchiveHistoryElements),
+    Unarchive(UnarchiveHistoryElement),
+    Link(LinkHistoryElement),
+    Block(BlockHistoryElement),
+    AddTemplate(AddTemplateHistoryElement),
+    Parent(ParentHistoryElement),
+    Fields(FieldsHistoryElement),
 }
 
 
@@ -97,3 +244,21 @@ impl<H> History<H> {
         self.stack.pop()
     }
 }
+
+impl History<UndoableHistoryElement> {
+    /// Makes the transfers to the board `from` refer to the board by its new name `to`. Returns
+    /// whether any transfer was changed.
+    pub fn rename_transfer_target(&mut self, from: &BoardName, to: &BoardName) -> bool {
+        let mut changed = false;
+        for element in self.stack.iter_mut() {
+            if let UndoableHistoryElement::Transfer(transfer) = element {
+                if transfer.board == *from {
+                    transfer.board = to.clone();
+                    changed = true;
+                }
+            }
+        }
+
+        changed
+    }
+}
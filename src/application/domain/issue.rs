@@ -1,21 +1,53 @@
+use std::collections::BTreeMap;
 use std::fmt::{Display, Formatter};
-use std::hash::{Hash};
+use std::hash::{Hash, Hasher};
 use std::ops::{Deref, DerefMut};
 use time::Duration;
 use uuid::Uuid;
+use crate::application::domain::activity::{ActivityEvent, ActivityKind, StatePeriod};
+use crate::application::domain::date_parse::Recurrence;
+use crate::application::domain::time_tracking::WorkInterval;
+use crate::application::domain::error::{DomainError, DomainResult};
 use crate::application::domain::history::Historized;
 use crate::application::domain::history::UndoableHistoryElement;
+use crate::application::ports::time::TodayProvider;
 
-#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone)]
-pub enum State {
-    Open,
-    Review,
-    Done,
-}
+/// State of an issue, which is the name of the column the issue is in.
+///
+/// Column names are compared case-insensitively, so that `review` refers to the same state as
+/// `Review`.
+#[derive(Debug, Clone)]
+pub struct State(String);
 
 impl Default for State {
     fn default() -> Self {
-        Self::Open
+        Self::from("Open")
+    }
+}
+
+impl From<&str> for State {
+    fn from(s: &str) -> Self {
+        Self(s.trim().to_string())
+    }
+}
+
+impl PartialEq for State {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.to_lowercase() == other.0.to_lowercase()
+    }
+}
+
+impl Eq for State {}
+
+impl Hash for State {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.to_lowercase().hash(state)
+    }
+}
+
+impl Display for State {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
     }
 }
 
@@ -45,6 +77,150 @@ impl Display for Description {
     }
 }
 
+/// A label attached to an issue. Tags are case-insensitive, they are stored in lowercase.
+#[derive(Debug, PartialEq, Eq, Clone, Hash, PartialOrd, Ord)]
+pub struct Tag(String);
+
+impl From<&str> for Tag {
+    fn from(s: &str) -> Self {
+        Self(s.trim().to_lowercase())
+    }
+}
+
+impl Display for Tag {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// How important an issue is, independently of its order within its column
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, PartialOrd, Ord, Default)]
+pub enum Priority {
+    Low,
+    #[default]
+    Normal,
+    High,
+    Critical,
+}
+
+impl Priority {
+    /// Marker of the priority on the board. Issues of normal priority are not marked.
+    pub fn marker(&self) -> Option<&'static str> {
+        match self {
+            Priority::Critical => Some("!!"),
+            Priority::High => Some("!"),
+            Priority::Normal => None,
+            Priority::Low => Some("~"),
+        }
+    }
+}
+
+impl Display for Priority {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Priority::Critical => write!(f, "critical"),
+            Priority::High => write!(f, "high"),
+            Priority::Normal => write!(f, "normal"),
+            Priority::Low => write!(f, "low"),
+        }
+    }
+}
+
+/// The person who owns an issue. Names are case-insensitive, they are stored in lowercase.
+#[derive(Debug, PartialEq, Eq, Clone, Hash, PartialOrd, Ord)]
+pub struct Assignee(String);
+
+impl Assignee {
+    /// First letters of the parts of the name, e.g. `AJ` for `alice.jones`
+    pub fn initials(&self) -> String {
+        self.0.split(|c: char| c.is_whitespace() || c == '.' || c == '-' || c == '_')
+            .filter_map(|part| part.chars().next())
+            .flat_map(char::to_uppercase)
+            .collect()
+    }
+}
+
+impl From<&str> for Assignee {
+    fn from(s: &str) -> Self {
+        Self(s.trim().to_lowercase())
+    }
+}
+
+impl Display for Assignee {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// Short, human-friendly identifier of an issue. Unlike the index of an issue, it does not change
+/// when other issues are reordered, moved or deleted, and it is never reused within a board.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, PartialOrd, Ord)]
+pub struct ShortId(pub u32);
+
+impl Display for ShortId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "@{}", self.0)
+    }
+}
+
+/// The way a user refers to an issue: either by its current position on the board, or by its
+/// short id.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum IssueRef {
+    Index(usize),
+    ShortId(ShortId),
+}
+
+impl Display for IssueRef {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            IssueRef::Index(index) => write!(f, "{}", index),
+            IssueRef::ShortId(short_id) => write!(f, "{}", short_id),
+        }
+    }
+}
+
+/// A step of an issue that can be checked off
+#[derive(Debug, PartialEq, Clone, Hash)]
+pub struct ChecklistItem {
+    pub(crate) text: String,
+    pub(crate) done: bool,
+}
+
+impl ChecklistItem {
+    pub fn new(text: &str) -> Self {
+        Self {
+            text: text.trim().to_string(),
+            done: false,
+        }
+    }
+}
+
+/// A reference from an issue to something outside the board: a URL or a local file path
+#[derive(Debug, PartialEq, Clone, Hash)]
+pub struct Link {
+    pub(crate) target: String,
+    pub(crate) label: Option<String>,
+}
+
+impl Link {
+    pub fn new(target: &str, label: Option<&str>) -> Self {
+        Self {
+            target: target.trim().to_string(),
+            label: label.map(str::trim).filter(|label| !label.is_empty()).map(str::to_string),
+        }
+    }
+}
+
+impl Display for Link {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match &self.label {
+            Some(label) => write!(f, "{}: {}", label, self.target),
+            None => write!(f, "{}", self.target),
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct Entity<T> {
     /// Uniquely identifies an `Entity` in a `Board`
@@ -117,19 +293,292 @@ pub struct Issue {
 
     /// Due date of an issue
     pub(crate) due_date: Option<time::Date>,
+
+    /// The issue is hidden from the board until this date, as it is not relevant before
+    pub(crate) wait_until: Option<time::Date>,
+
+    /// Tags (labels) attached to the issue, in the order they were added
+    pub(crate) tags: Vec<Tag>,
+
+    /// Stable identifier of the issue. Issues get one when they are added to a board.
+    pub(crate) short_id: Option<ShortId>,
+
+    /// Ordered steps of the issue
+    pub(crate) checklist: Vec<ChecklistItem>,
+
+    /// Issues that have to be done before this issue can be done
+    pub(crate) blocked_by: Vec<ShortId>,
+
+    /// Why the issue cannot progress, e.g. it waits on someone else. Issues with a reason are blocked.
+    pub(crate) blocked_reason: Option<String>,
+
+    /// The issue this issue is a part of, e.g. an epic
+    pub(crate) parent: Option<ShortId>,
+
+    /// Multi-line notes of the issue (e.g. acceptance criteria), which are shown only in the
+    /// detail view. The description is the one-line title of the issue.
+    pub(crate) body: Option<String>,
+
+    /// What happened to the issue, in chronological order
+    pub(crate) activity: Vec<ActivityEvent>,
+
+    /// When the issue is done, its next occurrence is added according to this rule
+    pub(crate) recurrence: Option<Recurrence>,
+
+    /// Estimated size of the issue (e.g. in story points)
+    pub(crate) estimate: Option<u32>,
+
+    /// Periods of work tracked on the issue, in chronological order
+    pub(crate) work_log: Vec<WorkInterval>,
+
+    /// The person who owns the issue
+    pub(crate) assignee: Option<Assignee>,
+
+    /// How important the issue is
+    pub(crate) priority: Priority,
+
+    /// URLs and file paths related to the issue, in the order they were added
+    pub(crate) links: Vec<Link>,
+
+    /// Values of the custom fields declared by the board, by the name of the field
+    pub(crate) fields: BTreeMap<String, String>,
+}
+
+impl Issue {
+    /// A new issue in `state`, created today according to `time_provider`. Its creation is the
+    /// first event of its activity.
+    pub fn new<T: TodayProvider>(description: Description, state: State, time_provider: &T) -> Self {
+        Self {
+            description,
+            state,
+            time_created: time_provider.today(),
+            due_date: None,
+            wait_until: None,
+            tags: Vec::default(),
+            short_id: None,
+            checklist: Vec::default(),
+            blocked_by: Vec::default(),
+            blocked_reason: None,
+            parent: None,
+            body: None,
+            activity: vec![ActivityEvent::new(time_provider.now(), ActivityKind::Created)],
+            recurrence: None,
+            estimate: None,
+            work_log: Vec::default(),
+            assignee: None,
+            priority: Priority::default(),
+            links: Vec::default(),
+            fields: BTreeMap::default(),
+        }
+    }
+}
+
+/// Issues built in tests are created on the fake today, and have no activity
+#[cfg(test)]
+impl Default for Issue {
+    fn default() -> Self {
+        use crate::adapters::time_providers::fake::FakeTodayProvider;
+
+        Self {
+            activity: Vec::default(),
+            ..Self::new(Description::default(), State::default(), &FakeTodayProvider::default())
+        }
+    }
 }
 
 impl Issue {
+    /// The whole text of the issue: the description in the first line, and the body after an
+    /// empty line.
+    pub fn text(&self) -> String {
+        match &self.body {
+            Some(body) => format!("{}\n\n{}", self.description, body),
+            None => self.description.to_string(),
+        }
+    }
+
+    /// Sets the description to the first line of `text`, and the body to the rest of it.
+    pub fn set_text(&mut self, text: &str) {
+        let (description, body) = text.trim().split_once('\n').unwrap_or((text.trim(), ""));
+
+        self.description.set(description.trim());
+        self.set_body(body);
+    }
+
+    /// Sets the body of the issue. A blank body removes it.
+    pub fn set_body(&mut self, body: &str) {
+        let body = body.trim_matches('\n').trim_end();
+        self.body = (!body.trim().is_empty()).then(|| body.to_string());
+    }
+
+    pub fn record_activity(&mut self, time: time::OffsetDateTime, kind: ActivityKind) {
+        self.activity.push(ActivityEvent::new(time, kind));
+    }
+
+    /// Removes the last activity event, if `is_undone` matches it. Used when the action that
+    /// recorded the event is undone.
+    pub fn remove_last_activity(&mut self, is_undone: impl Fn(&ActivityKind) -> bool) {
+        if self.activity.last().is_some_and(|event| is_undone(&event.kind)) {
+            self.activity.pop();
+        }
+    }
+
+    /// The day the issue was last moved to `state`. Issues that were created in the state, or
+    /// before moves were recorded, entered it when they were created.
+    pub fn entered_state_on(&self, state: &State) -> time::Date {
+        self.state_periods().iter()
+            .rev()
+            .find(|period| &period.state == state)
+            .map(|period| period.entered.date())
+            .unwrap_or(self.time_created)
+    }
+
+    /// The periods the issue spent in each state, in chronological order, derived from the moves in
+    /// its activity. The last period is the one of the current state. Issues created before their
+    /// creation was recorded entered their first state at the start of the day they were created.
+    pub fn state_periods(&self) -> Vec<StatePeriod> {
+        let mut entered = self.activity.iter()
+            .find(|event| event.kind == ActivityKind::Created)
+            .map(|event| event.time)
+            .unwrap_or_else(|| self.time_created.midnight().assume_utc());
+        let mut state = self.activity.iter()
+            .find_map(|event| match &event.kind {
+                ActivityKind::Moved { from, .. } => Some(from.clone()),
+                _ => None,
+            })
+            .unwrap_or_else(|| self.state.clone());
+
+        let mut periods = Vec::new();
+        for event in &self.activity {
+            if let ActivityKind::Moved { to, .. } = &event.kind {
+                periods.push(StatePeriod {
+                    state: std::mem::replace(&mut state, to.clone()),
+                    entered: std::mem::replace(&mut entered, event.time),
+                    left: Some(event.time),
+                });
+            }
+        }
+        periods.push(StatePeriod {
+            state: self.state.clone(),
+            entered,
+            left: None,
+        });
+
+        periods
+    }
+
+    /// The period of the current state of the issue
+    pub fn current_state_period(&self) -> StatePeriod {
+        self.state_periods().pop().expect("there is always a current state")
+    }
+
+    /// Whether the description, the body or a tag of the issue contains `text`, ignoring case
+    pub fn contains_text(&self, text: &str) -> bool {
+        let text = text.to_lowercase();
+
+        self.text().to_lowercase().contains(&text)
+            || self.tags.iter().any(|tag| tag.to_string().contains(&text))
+    }
+
+    /// Whether the timer of the issue is running
+    pub fn is_tracked(&self) -> bool {
+        self.work_log.last().is_some_and(WorkInterval::is_running)
+    }
+
+    /// Starts the timer of the issue, unless it is already running.
+    pub fn start_tracking(&mut self, now: time::OffsetDateTime) {
+        if !self.is_tracked() {
+            self.work_log.push(WorkInterval::started_at(now));
+        }
+    }
+
+    /// Stops the timer of the issue, if it is running.
+    pub fn stop_tracking(&mut self, now: time::OffsetDateTime) {
+        if let Some(interval) = self.work_log.last_mut().filter(|interval| interval.is_running()) {
+            interval.end = Some(now.max(interval.start));
+        }
+    }
+
+    /// Sum of the tracked time on the issue. A running timer counts until `now`.
+    pub fn tracked_time(&self, now: time::OffsetDateTime) -> Duration {
+        self.work_log.iter().map(|interval| interval.duration(now)).sum()
+    }
+
+    pub fn has_tag(&self, tag: &Tag) -> bool {
+        self.tags.contains(tag)
+    }
+
+    /// Adds the tag to the issue, unless the issue already has it.
+    pub fn add_tag(&mut self, tag: Tag) {
+        if !self.has_tag(&tag) {
+            self.tags.push(tag);
+        }
+    }
+
+    pub fn remove_tag(&mut self, tag: &Tag) {
+        self.tags.retain(|t| t != tag);
+    }
+
+    pub fn add_checklist_item(&mut self, text: &str) {
+        self.checklist.push(ChecklistItem::new(text));
+    }
+
+    /// Checks off (or unchecks) an item of the checklist. Items are numbered from 1.
+    pub fn set_checklist_item_done(&mut self, number: usize, done: bool) -> DomainResult<()> {
+        self.checklist_item_mut(number)?.done = done;
+        Ok(())
+    }
+
+    /// Removes an item of the checklist. Items are numbered from 1.
+    pub fn remove_checklist_item(&mut self, number: usize) -> DomainResult<()> {
+        self.checklist_item_mut(number)?;
+        self.checklist.remove(number - 1);
+        Ok(())
+    }
+
+    /// Number of done items and the number of all items of the checklist
+    pub fn checklist_progress(&self) -> (usize, usize) {
+        (self.checklist.iter().filter(|item| item.done).count(), self.checklist.len())
+    }
+
+    fn checklist_item_mut(&mut self, number: usize) -> DomainResult<&mut ChecklistItem> {
+        number.checked_sub(1)
+            .and_then(|index| self.checklist.get_mut(index))
+            .ok_or(DomainError::ChecklistItemNotFound(number))
+    }
+
+    /// Whether the issue is not relevant yet on `today`, because its wait date is still ahead
+    pub fn is_waiting(&self, today: time::Date) -> bool {
+        self.wait_until.is_some_and(|wait_until| wait_until > today)
+    }
+
+    /// Whether the issue is flagged as blocked, i.e. it waits on something outside of the board.
+    /// Issues that are blocked by other issues of the board are not flagged.
+    pub fn is_flagged_blocked(&self) -> bool {
+        self.blocked_reason.is_some()
+    }
+
+    /// The category of the issue according to `policy`. Issues that stay in their state for
+    /// longer than the policy allows are overdue, even without a due date. Issues flagged as blocked are
+    /// neither overdue nor due, as they cannot progress anyway.
+    pub fn category(&self, today: time::Date, policy: &DuePolicy) -> IssueCategory {
+        if self.is_flagged_blocked() {
+            return IssueCategory::Blocked;
+        }
+
+        let time_in_state = today - self.entered_state_on(&self.state);
+        let time_until_due = self.due_date.map(|due_date| due_date - today);
 
-    pub fn category(&self, today: time::Date) -> IssueCategory {
-        let time_since_creation = today - self.time_created;
-        let time_since_due = self.due_date.map(|due_date| due_date - today);
+        let is_stale = policy.stale_after_days
+            .is_some_and(|days| time_in_state > Duration::days(days.into()));
+        let is_due_soon = policy.due_soon_days
+            .is_some_and(|days| time_until_due.is_some_and(|d| d <= Duration::days(days.into())));
 
-        if time_since_creation > Duration::days(13)
-                || time_since_due.is_some_and(|d| d < Duration::default()) {
+        if is_stale || time_until_due.is_some_and(|d| d < Duration::default()) {
             IssueCategory::Overdue
-        } else if time_since_due.is_some_and(|d| d == Duration::default()) {
+        } else if time_until_due.is_some_and(|d| d == Duration::default()) {
             IssueCategory::DueToday
+        } else if is_due_soon {
+            IssueCategory::DueSoon
         } else {
             IssueCategory::Normal
         }
@@ -140,14 +589,41 @@ impl Issue {
 pub enum IssueCategory {
     Normal,
     Overdue,
-    DueToday
+    DueToday,
+    /// Due within the horizon of the board, but not today
+    DueSoon,
+    /// Flagged as blocked, regardless of its due date
+    Blocked,
+}
+
+/// Issues are overdue after this many days in a state, unless the column says otherwise
+pub const DEFAULT_STALE_AFTER_DAYS: u32 = 13;
+
+/// When issues count as overdue or due soon
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct DuePolicy {
+    /// Issues in the same state for longer than this many days are overdue. Without it, only
+    /// the due date makes an issue overdue.
+    pub stale_after_days: Option<u32>,
+
+    /// Issues due within this many days are due soon. Without it, no issue is due soon.
+    pub due_soon_days: Option<u32>,
+}
+
+impl Default for DuePolicy {
+    fn default() -> Self {
+        Self {
+            stale_after_days: Some(DEFAULT_STALE_AFTER_DAYS),
+            due_soon_days: None,
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use assert2::{check};
-    use time::Date;
-    use time::macros::date;
+    use time::{Date, Duration};
+    use time::macros::{date, datetime};
     use super::*;
 
     #[test]
@@ -163,18 +639,148 @@ mod tests {
             ] {
             let issue = given_issue_with(actual_creation_date, actual_due_date);
 
-            let category = issue.category(today);
+            let category = issue.category(today, &DuePolicy::default());
             check!(category == expected_category,
                 "creation_date = {:?}, due_date = {:?}", actual_creation_date, actual_due_date);
         }
     }
 
+    #[test]
+    fn test_category_with_policy() {
+        let today = date!(2025-02-22);
+        let policy = DuePolicy {
+            stale_after_days: Some(3),
+            due_soon_days: Some(2),
+        };
+
+        for (time_created, due_date, expected_category) in [
+            (date!(2025-02-20), Some(date!(2025-02-24)), IssueCategory::DueSoon),
+            (date!(2025-02-20), Some(date!(2025-02-25)), IssueCategory::Normal),
+            (date!(2025-02-20), Some(date!(2025-02-22)), IssueCategory::DueToday),
+            (date!(2025-02-18), None, IssueCategory::Overdue),
+            (date!(2025-02-19), None, IssueCategory::Normal),
+        ] {
+            let issue = given_issue_with(time_created, due_date);
+
+            check!(issue.category(today, &policy) == expected_category, "time_created = {}, due_date = {:?}", time_created, due_date);
+        }
+
+        let old_issue = given_issue_with(date!(2024-01-01), None);
+        check!(old_issue.category(today, &DuePolicy { stale_after_days: None, ..policy }) == IssueCategory::Normal);
+    }
+
+    #[test]
+    fn test_blocked_issues_are_not_overdue() {
+        let issue = Issue {
+            blocked_reason: Some(String::from("waiting for API keys")),
+            ..given_issue_with(date!(2024-01-01), Some(date!(2025-02-01)))
+        };
+
+        check!(issue.category(date!(2025-02-22), &DuePolicy::default()) == IssueCategory::Blocked);
+    }
+
+    #[test]
+    fn test_staleness_counts_from_entering_the_state() {
+        let issue = Issue {
+            state: State::from("Review"),
+            time_created: date!(2025-01-10),
+            activity: vec![
+                ActivityEvent::new(datetime!(2025-02-20 09:00 UTC), ActivityKind::Moved { from: State::from("Open"), to: State::from("Review") }),
+            ],
+            ..Default::default()
+        };
+
+        check!(issue.category(date!(2025-02-22), &DuePolicy::default()) == IssueCategory::Normal);
+        check!(issue.category(date!(2025-03-06), &DuePolicy::default()) == IssueCategory::Overdue);
+    }
+
+    #[test]
+    fn test_state_periods() {
+        let issue = Issue {
+            state: State::from("Review"),
+            time_created: date!(2025-02-10),
+            activity: vec![
+                ActivityEvent::new(datetime!(2025-02-10 09:00 UTC), ActivityKind::Created),
+                ActivityEvent::new(datetime!(2025-02-11 09:00 UTC), ActivityKind::Moved { from: State::from("Open"), to: State::from("Review") }),
+                ActivityEvent::new(datetime!(2025-02-11 12:00 UTC), ActivityKind::Comment(String::from("needs a second look"))),
+                ActivityEvent::new(datetime!(2025-02-12 09:00 UTC), ActivityKind::Moved { from: State::from("Review"), to: State::from("Open") }),
+                ActivityEvent::new(datetime!(2025-02-20 09:00 UTC), ActivityKind::Moved { from: State::from("Open"), to: State::from("Review") }),
+            ],
+            ..Default::default()
+        };
+
+        check!(issue.state_periods() == vec![
+            StatePeriod { state: State::from("Open"), entered: datetime!(2025-02-10 09:00 UTC), left: Some(datetime!(2025-02-11 09:00 UTC)) },
+            StatePeriod { state: State::from("Review"), entered: datetime!(2025-02-11 09:00 UTC), left: Some(datetime!(2025-02-12 09:00 UTC)) },
+            StatePeriod { state: State::from("Open"), entered: datetime!(2025-02-12 09:00 UTC), left: Some(datetime!(2025-02-20 09:00 UTC)) },
+            StatePeriod { state: State::from("Review"), entered: datetime!(2025-02-20 09:00 UTC), left: None },
+        ]);
+        check!(issue.current_state_period().duration(datetime!(2025-02-22 10:00 UTC)) == Duration::days(2) + Duration::hours(1));
+        check!(issue.state_periods()[0].duration(datetime!(2025-02-22 10:00 UTC)) == Duration::days(1));
+    }
+
+    #[test]
+    fn test_state_periods_without_recorded_activity() {
+        let issue = Issue {
+            state: State::from("Review"),
+            time_created: date!(2025-02-10),
+            ..Default::default()
+        };
+
+        check!(issue.state_periods() == vec![
+            StatePeriod { state: State::from("Review"), entered: datetime!(2025-02-10 00:00 UTC), left: None },
+        ]);
+    }
+
+    #[test]
+    fn test_assignee_initials() {
+        for (name, initials) in [
+            ("alice", "A"),
+            ("Alice Jones", "AJ"),
+            ("alice.jones", "AJ"),
+            ("jean-luc_picard", "JLP"),
+        ] {
+            check!(Assignee::from(name).initials() == initials, "name: {}", name);
+        }
+    }
+
+    #[test]
+    fn test_set_text() {
+        let mut issue = given_issue_with(date!(2021 - 9 - 8), None);
+
+        for (text, expected_description, expected_body) in [
+            ("Fix login", "Fix login", None),
+            ("Fix login\n\nSteps:\n  1. open the page\n", "Fix login", Some("Steps:\n  1. open the page")),
+            ("  Fix login  \nno empty line\n\n\n", "Fix login", Some("no empty line")),
+            ("Fix login\n   \n", "Fix login", None),
+        ] {
+            issue.set_text(text);
+
+            check!(issue.description.as_str() == expected_description, "text = {:?}", text);
+            check!(issue.body.as_deref() == expected_body, "text = {:?}", text);
+        }
+    }
+
+    #[test]
+    fn test_text_roundtrip() {
+        let mut issue = given_issue_with(date!(2021 - 9 - 8), None);
+        issue.body = Some(String::from("Steps:\n  1. open the page"));
+
+        let text = issue.text();
+        check!(text == "an issue\n\nSteps:\n  1. open the page");
+
+        issue.set_text(&text);
+        check!(issue.description.as_str() == "an issue");
+        check!(issue.body.as_deref() == Some("Steps:\n  1. open the page"));
+    }
+
     fn given_issue_with(time_created: Date, due_date: Option<Date>) -> Issue {
         let issue = Issue {
             description: Description::from("an issue"),
-            state: State::Open,
+            state: State::from("Open"),
             time_created,
             due_date,
+            ..Default::default()
         };
         issue
     }
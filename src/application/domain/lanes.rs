@@ -0,0 +1,149 @@
+use std::cmp::Reverse;
+use std::fmt::{Display, Formatter};
+use time::{Date, Duration};
+use crate::application::issue::{Assignee, Priority, Tag};
+use crate::application::Issue;
+
+/// Attribute of the issues that splits the columns of the board into horizontal lanes. The state
+/// is not one of them, as the columns are the states already.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum LaneGrouping {
+    Tag,
+    Assignee,
+    Priority,
+    Due,
+    /// A custom field declared by the board
+    Field(String),
+}
+
+impl LaneGrouping {
+    /// The lane of the issue. An issue with several tags is in the lane of its first tag.
+    pub fn lane_of(&self, issue: &Issue, today: Date) -> Lane {
+        match self {
+            LaneGrouping::Tag => issue.tags.first().cloned().map_or(Lane::Untagged, Lane::Tag),
+            LaneGrouping::Assignee => issue.assignee.clone().map_or(Lane::Unassigned, Lane::Assignee),
+            LaneGrouping::Priority => Lane::Priority(Reverse(issue.priority)),
+            LaneGrouping::Due => Lane::Due(DueBucket::of(issue.due_date, today)),
+            LaneGrouping::Field(name) => issue.fields.get(name)
+                .cloned()
+                .map_or_else(|| Lane::WithoutField(name.clone()), Lane::Field),
+        }
+    }
+}
+
+/// Issues by their due date, from the most urgent
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, PartialOrd, Ord)]
+pub enum DueBucket {
+    Overdue,
+    Today,
+    /// Due within the next seven days, but not today
+    ThisWeek,
+    Later,
+    NoDueDate,
+}
+
+impl DueBucket {
+    pub fn of(due_date: Option<Date>, today: Date) -> Self {
+        match due_date.map(|due_date| due_date - today) {
+            None => DueBucket::NoDueDate,
+            Some(until_due) if until_due < Duration::ZERO => DueBucket::Overdue,
+            Some(until_due) if until_due == Duration::ZERO => DueBucket::Today,
+            Some(until_due) if until_due <= Duration::days(7) => DueBucket::ThisWeek,
+            Some(_) => DueBucket::Later,
+        }
+    }
+}
+
+impl Display for DueBucket {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DueBucket::Overdue => write!(f, "overdue"),
+            DueBucket::Today => write!(f, "due today"),
+            DueBucket::ThisWeek => write!(f, "due this week"),
+            DueBucket::Later => write!(f, "due later"),
+            DueBucket::NoDueDate => write!(f, "no due date"),
+        }
+    }
+}
+
+/// A lane of the board. Lanes are ordered as they are displayed: higher priorities and earlier due
+/// dates first, tags, assignees and field values alphabetically, and the issues without the
+/// attribute last.
+#[derive(Debug, PartialEq, Eq, Clone, Hash, PartialOrd, Ord)]
+pub enum Lane {
+    Priority(Reverse<Priority>),
+    Due(DueBucket),
+    Tag(Tag),
+    Assignee(Assignee),
+    /// The value of the custom field
+    Field(String),
+    Untagged,
+    Unassigned,
+    /// The issues without a value for the custom field
+    WithoutField(String),
+}
+
+impl Display for Lane {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Lane::Priority(Reverse(priority)) => write!(f, "{}", priority),
+            Lane::Due(bucket) => write!(f, "{}", bucket),
+            Lane::Tag(tag) => write!(f, "+{}", tag),
+            Lane::Assignee(assignee) => write!(f, "{}", assignee),
+            Lane::Field(value) => write!(f, "{}", value),
+            Lane::Untagged => write!(f, "untagged"),
+            Lane::Unassigned => write!(f, "unassigned"),
+            Lane::WithoutField(name) => write!(f, "no {}", name),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+    use assert2::check;
+    use itertools::Itertools;
+    use time::macros::date;
+    use crate::adapters::time_providers::fake::DEFAULT_FAKE_TODAY;
+    use crate::application::domain::lanes::{DueBucket, LaneGrouping};
+    use crate::application::issue::{Assignee, Priority, Tag};
+    use crate::application::Issue;
+
+    #[test]
+    fn test_lanes_are_ordered_for_display() {
+        let issues = [
+            Issue { tags: vec![Tag::from("frontend")], priority: Priority::Low, due_date: Some(date!(2025-03-10)), ..Default::default() },
+            Issue { assignee: Some(Assignee::from("bob")), fields: BTreeMap::from([(String::from("customer"), String::from("globex"))]), ..Default::default() },
+            Issue { tags: vec![Tag::from("backend"), Tag::from("urgent")], assignee: Some(Assignee::from("alice")), priority: Priority::Critical, due_date: Some(date!(2025-02-25)), fields: BTreeMap::from([(String::from("customer"), String::from("acme"))]), ..Default::default() },
+        ];
+
+        for (grouping, expected_lanes) in [
+            (LaneGrouping::Tag, ["+backend", "+frontend", "untagged"]),
+            (LaneGrouping::Assignee, ["alice", "bob", "unassigned"]),
+            (LaneGrouping::Priority, ["critical", "normal", "low"]),
+            (LaneGrouping::Due, ["due this week", "due later", "no due date"]),
+            (LaneGrouping::Field(String::from("customer")), ["acme", "globex", "no customer"]),
+        ] {
+            let lanes = issues.iter()
+                .map(|issue| grouping.lane_of(issue, DEFAULT_FAKE_TODAY))
+                .sorted()
+                .map(|lane| lane.to_string())
+                .collect::<Vec<_>>();
+
+            check!(lanes == expected_lanes, "grouping: {:?}", grouping);
+        }
+    }
+
+    #[test]
+    fn test_due_buckets() {
+        for (due_date, expected_bucket) in [
+            (None, DueBucket::NoDueDate),
+            (Some(date!(2025-02-21)), DueBucket::Overdue),
+            (Some(date!(2025-02-22)), DueBucket::Today),
+            (Some(date!(2025-03-01)), DueBucket::ThisWeek),
+            (Some(date!(2025-03-02)), DueBucket::Later),
+        ] {
+            check!(DueBucket::of(due_date, DEFAULT_FAKE_TODAY) == expected_bucket, "due date: {:?}", due_date);
+        }
+    }
+}
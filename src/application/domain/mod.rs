@@ -1,6 +1,14 @@
 pub mod issue;
 pub mod board;
+pub mod board_name;
 pub mod error;
 pub mod history;
 pub mod historized_board;
 pub mod date_parse;
+pub mod column;
+pub mod filter;
+pub mod activity;
+pub mod time_tracking;
+pub mod lanes;
+pub mod template;
+pub mod custom_field;
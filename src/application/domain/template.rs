@@ -0,0 +1,150 @@
+use crate::application::domain::column::Columns;
+use crate::application::domain::date_parse::DateParser;
+use crate::application::domain::error::DomainResult;
+use crate::application::issue::{ChecklistItem, Description, Tag};
+use crate::application::ports::time::TodayProvider;
+use crate::application::{Issue, State};
+
+/// Placeholder in the descriptions of a template, replaced by the text given when the template is
+/// used
+pub const TEMPLATE_PLACEHOLDER: &str = "{}";
+
+/// A named recipe for the issues of a kind of work that is done over and over, e.g. a release
+#[derive(Debug, PartialEq, Clone)]
+pub struct Template {
+    pub(crate) name: String,
+
+    /// The issues the template expands to, in the order they are displayed
+    pub(crate) issues: Vec<TemplateIssue>,
+}
+
+/// An issue of a template
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct TemplateIssue {
+    /// Description of the issue, with `{}` standing for the text given when the template is used
+    pub(crate) description: String,
+
+    /// Column of the issue. Without it, the issue goes to the first column.
+    pub(crate) state: Option<State>,
+
+    /// Due date relative to the day the template is used, e.g. `in 7 days` or `friday`
+    pub(crate) due: Option<String>,
+
+    pub(crate) tags: Vec<Tag>,
+
+    /// Items of the checklist of the issue
+    pub(crate) checklist: Vec<String>,
+}
+
+impl Template {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Whether the template is called `name`, ignoring the case
+    pub fn is_named(&self, name: &str) -> bool {
+        self.name.eq_ignore_ascii_case(name.trim())
+    }
+
+    /// The issues of the template for `text`, in the order of the template. Their states are
+    /// resolved by `columns`, and their due dates are resolved by `date_parser` from today.
+    pub fn expand<T: TodayProvider>(&self, text: &str, columns: &Columns, date_parser: &DateParser<T>) -> DomainResult<Vec<Issue>> {
+        let today_provider = date_parser.today_provider;
+
+        self.issues.iter()
+            .map(|template_issue| {
+                let state = match &template_issue.state {
+                    Some(state) => columns.resolve(state)?,
+                    None => columns.first_state(),
+                };
+
+                let due_date = template_issue.due.as_deref()
+                    .map(|due| date_parser.parse(due))
+                    .transpose()?;
+
+                let description = Description::from(template_issue.description.replace(TEMPLATE_PLACEHOLDER, text.trim()).as_str());
+                let mut issue = Issue {
+                    due_date,
+                    checklist: template_issue.checklist.iter()
+                        .map(|item| ChecklistItem::new(item))
+                        .collect(),
+                    ..Issue::new(description, state, today_provider)
+                };
+
+                for tag in &template_issue.tags {
+                    issue.add_tag(tag.clone());
+                }
+
+                Ok(issue)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert2::{check, let_assert};
+    use time::macros::date;
+    use crate::adapters::time_providers::fake::{FakeTodayProvider, DEFAULT_FAKE_TODAY};
+    use crate::application::domain::column::Columns;
+    use crate::application::domain::date_parse::DateParser;
+    use crate::application::domain::error::DomainError;
+    use crate::application::domain::template::{Template, TemplateIssue};
+    use crate::application::issue::{ChecklistItem, Description, Tag};
+    use crate::application::State;
+
+    #[test]
+    fn test_expand_template() {
+        let template = given_release_template();
+        let today_provider = FakeTodayProvider::default();
+        let date_parser = DateParser {
+            today_provider: &today_provider,
+        };
+
+        let_assert!(Ok(issues) = template.expand(" v1.4 ", &Columns::default(), &date_parser));
+
+        check!(issues.len() == 2);
+        check!(issues[0].description == Description::from("Release v1.4"));
+        check!(issues[0].state == State::from("Open"));
+        check!(issues[0].due_date == Some(date!(2025-03-01)));
+        check!(issues[0].time_created == DEFAULT_FAKE_TODAY);
+        check!(issues[0].tags == [Tag::from("release")]);
+        check!(issues[0].checklist == [ChecklistItem::new("tag the commit"), ChecklistItem::new("publish")]);
+        check!(issues[1].description == Description::from("Announce v1.4"));
+        check!(issues[1].state == State::from("Review"));
+        check!(issues[1].due_date == None);
+    }
+
+    #[test]
+    fn test_expand_template_with_unknown_state() {
+        let mut template = given_release_template();
+        template.issues[1].state = Some(State::from("QA"));
+        let today_provider = FakeTodayProvider::default();
+        let date_parser = DateParser {
+            today_provider: &today_provider,
+        };
+
+        let_assert!(Err(DomainError::UnknownState { state, .. }) = template.expand("v1.4", &Columns::default(), &date_parser));
+        check!(state == "QA");
+    }
+
+    fn given_release_template() -> Template {
+        Template {
+            name: String::from("release"),
+            issues: vec![
+                TemplateIssue {
+                    description: String::from("Release {}"),
+                    due: Some(String::from("in 7 days")),
+                    tags: vec![Tag::from("release")],
+                    checklist: vec![String::from("tag the commit"), String::from("publish")],
+                    ..Default::default()
+                },
+                TemplateIssue {
+                    description: String::from("Announce {}"),
+                    state: Some(State::from("review")),
+                    ..Default::default()
+                },
+            ],
+        }
+    }
+}
@@ -0,0 +1,143 @@
+use std::collections::BTreeMap;
+use time::{Date, Duration, OffsetDateTime, Time};
+use uuid::Uuid;
+use crate::application::board::Board;
+use crate::application::Issue;
+
+/// A period of work on an issue. The interval without an end is a running timer.
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
+pub struct WorkInterval {
+    pub(crate) start: OffsetDateTime,
+    pub(crate) end: Option<OffsetDateTime>,
+}
+
+impl WorkInterval {
+    pub fn started_at(start: OffsetDateTime) -> Self {
+        Self {
+            start,
+            end: None,
+        }
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.end.is_none()
+    }
+
+    /// How long the work lasted. A running interval lasts until `now`.
+    pub fn duration(&self, now: OffsetDateTime) -> Duration {
+        (self.end.unwrap_or(now) - self.start).max(Duration::ZERO)
+    }
+
+    /// The duration of the interval split by the (UTC) days it spans.
+    fn duration_per_day(&self, now: OffsetDateTime) -> Vec<(Date, Duration)> {
+        let end = self.end.unwrap_or(now);
+        let mut result = Vec::new();
+        let mut start = self.start;
+
+        while start < end {
+            let next_midnight = start.date()
+                .next_day()
+                .map(|day| day.with_time(Time::MIDNIGHT).assume_offset(start.offset()))
+                .unwrap_or(end);
+            let day_end = next_midnight.min(end);
+
+            result.push((start.date(), day_end - start));
+            start = day_end;
+        }
+
+        result
+    }
+}
+
+/// Sum of the tracked time per issue and per day.
+#[derive(Debug, PartialEq, Clone)]
+pub struct TimeReport {
+    /// Issues with any tracked time, in the order of the board
+    pub per_issue: Vec<(Uuid, Duration)>,
+    /// Days with any tracked time, in chronological order
+    pub per_day: Vec<(Date, Duration)>,
+}
+
+impl TimeReport {
+    pub fn new(board: &Board<Issue>, now: OffsetDateTime) -> Self {
+        let per_issue = board.entities().iter()
+            .map(|issue| (issue.id, issue.tracked_time(now)))
+            .filter(|(_, tracked)| tracked.is_positive())
+            .collect();
+
+        let per_day = board.entities().iter()
+            .flat_map(|issue| issue.work_log.iter())
+            .flat_map(|interval| interval.duration_per_day(now))
+            .fold(BTreeMap::new(), |mut acc, (day, duration)| {
+                *acc.entry(day).or_insert(Duration::ZERO) += duration;
+                acc
+            })
+            .into_iter()
+            .collect();
+
+        Self {
+            per_issue,
+            per_day,
+        }
+    }
+
+    pub fn total(&self) -> Duration {
+        self.per_issue.iter().map(|(_, duration)| *duration).sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert2::check;
+    use time::Duration;
+    use time::macros::{date, datetime};
+    use crate::application::domain::historized_board::HistorizedBoard;
+    use crate::application::domain::time_tracking::{TimeReport, WorkInterval};
+    use crate::application::Issue;
+    use crate::application::issue::Description;
+
+    #[test]
+    fn test_duration_of_running_interval() {
+        let interval = WorkInterval::started_at(datetime!(2025-02-22 09:00 UTC));
+
+        check!(interval.is_running());
+        check!(interval.duration(datetime!(2025-02-22 10:30 UTC)) == Duration::minutes(90));
+    }
+
+    #[test]
+    fn test_time_report() {
+        let board = HistorizedBoard::default()
+            .with_issue(Issue {
+                description: Description::from("Not tracked"),
+                ..Default::default()
+            })
+            .with_issue(Issue {
+                description: Description::from("Worked on over midnight"),
+                work_log: vec![
+                    WorkInterval { start: datetime!(2025-02-21 23:00 UTC), end: Some(datetime!(2025-02-22 01:00 UTC)) },
+                ],
+                ..Default::default()
+            })
+            .with_issue(Issue {
+                description: Description::from("Being worked on"),
+                work_log: vec![
+                    WorkInterval { start: datetime!(2025-02-21 10:00 UTC), end: Some(datetime!(2025-02-21 10:30 UTC)) },
+                    WorkInterval::started_at(datetime!(2025-02-22 09:00 UTC)),
+                ],
+                ..Default::default()
+            });
+        let ids = board.entities().iter().map(|issue| issue.id).collect::<Vec<_>>();
+
+        let report = TimeReport::new(&board, datetime!(2025-02-22 10:00 UTC));
+
+        check!(report.per_issue == vec![
+            (ids[1], Duration::hours(2)),
+            (ids[2], Duration::minutes(90)),
+        ]);
+        check!(report.per_day == vec![
+            (date!(2025-02-21), Duration::minutes(90)),
+            (date!(2025-02-22), Duration::hours(2)),
+        ]);
+        check!(report.total() == Duration::minutes(210));
+    }
+}
@@ -0,0 +1,23 @@
+use crate::application::Issue;
+use crate::application::domain::board_name::BoardName;
+use crate::application::domain::historized_board::HistorizedBoard;
+
+/// Every board of the installation. Whether a board exists is checked by the use cases, the
+/// registry only stores the boards.
+pub trait BoardRegistry {
+    /// Names of all the boards in alphabetical order, including the main board
+    fn boards(&self) -> Vec<BoardName>;
+    /// The board used when no board is selected
+    fn default_board(&self) -> BoardName;
+    fn set_default_board(&self, name: &BoardName);
+    /// The board the commands work on: the selected board, or else the default board
+    fn active_board(&self) -> BoardName;
+    fn load(&self, name: &BoardName) -> HistorizedBoard<Issue>;
+    fn save(&self, name: &BoardName, board: &HistorizedBoard<Issue>);
+    fn rename(&self, from: &BoardName, to: &BoardName);
+    fn delete(&self, name: &BoardName);
+
+    fn exists(&self, name: &BoardName) -> bool {
+        self.boards().contains(name)
+    }
+}
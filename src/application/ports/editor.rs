@@ -1,6 +1,7 @@
 use std::io;
 
 pub(crate) trait Editor {
-    /// An editor opens to edit original `text`, resulting text is returned.
+    /// An editor opens to edit original `text`, resulting text is returned. The text can have
+    /// multiple lines.
     fn open_editor_with(&self, text: &str) -> Result<String, io::Error>;
 }
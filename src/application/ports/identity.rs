@@ -0,0 +1,7 @@
+use crate::application::issue::Assignee;
+
+pub(crate) trait Identity {
+    /// The person using the board, e.g. to show the issues assigned to them. `None` if it is
+    /// not configured.
+    fn current_user(&self) -> Option<Assignee>;
+}
@@ -1,4 +1,7 @@
 pub mod presenter;
 pub mod issue_storage;
 pub mod editor;
-pub mod time;
\ No newline at end of file
+pub mod time;
+pub mod board_registry;
+pub mod identity;
+pub mod opener;
\ No newline at end of file
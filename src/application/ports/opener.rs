@@ -0,0 +1,7 @@
+use std::io;
+
+pub(crate) trait Opener {
+    /// Opens `target`, a URL or a file path, with the application the system associates with it.
+    /// Returns as soon as the application is launched.
+    fn open(&self, target: &str) -> Result<(), io::Error>;
+}
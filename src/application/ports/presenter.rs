@@ -1,11 +1,29 @@
 use nonempty_collections::NEVec;
+use uuid::Uuid;
+use crate::application::domain::board_name::BoardName;
 use crate::application::domain::historized_board::HistorizedBoard;
 use crate::application::domain::error::DomainError;
+use crate::application::domain::filter::IssueFilter;
+use crate::application::domain::lanes::LaneGrouping;
+use crate::application::domain::time_tracking::TimeReport;
 use crate::application::Issue;
 
 pub trait Presenter {
     fn render_board(&self, board: &HistorizedBoard<Issue>);
+    /// Renders only the issues that match `filter`. Issues keep the index they have on the whole board.
+    /// With `lanes`, the columns are split into lanes by the attribute of the issues.
+    fn render_filtered_board(&self, board: &HistorizedBoard<Issue>, filter: &IssueFilter, lanes: Option<LaneGrouping>);
+    /// Renders every detail of the issue with `id`
+    fn render_issue(&self, board: &HistorizedBoard<Issue>, id: Uuid);
+    /// Renders the tracked time of the issues of the board
+    fn render_time_report(&self, board: &HistorizedBoard<Issue>, report: &TimeReport);
+    /// Renders the archived issues at `positions` of the archive
+    fn render_archive(&self, board: &HistorizedBoard<Issue>, positions: &[usize]);
+    /// Renders the names of the boards, marking the default board
+    fn render_boards(&self, boards: &[BoardName], default_board: &BoardName);
     fn render_error(&self, err: &DomainError);
+    /// Renders a problem that did not stop the command, e.g. a parent done before its children
+    fn render_warning(&self, warning: &DomainError);
 
     fn render_errors(&self, errors: &NEVec<DomainError>) {
         for err in errors {
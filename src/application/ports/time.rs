@@ -1,3 +1,6 @@
 pub(crate) trait TodayProvider {
     fn today(&self) -> time::Date;
+
+    /// Current time, e.g. to timestamp the activity of issues
+    fn now(&self) -> time::OffsetDateTime;
 }
\ No newline at end of file
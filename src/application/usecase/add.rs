@@ -1,8 +1,9 @@
 use internal_macros::{PresenterHolder, StorageHolder};
+use itertools::Itertools;
 use crate::application::{Issue, State};
 use crate::application::domain::date_parse::DateParser;
-use crate::application::domain::history::UndoableHistoryElement;
-use crate::application::issue::Description;
+use crate::application::domain::history::{AddTemplateHistoryElement, UndoableHistoryElement};
+use crate::application::issue::{Assignee, Description, Priority, Tag};
 use crate::application::ports::issue_storage::IssueStorage;
 use crate::application::ports::presenter::Presenter;
 use crate::application::ports::time::{TodayProvider};
@@ -11,6 +12,21 @@ use crate::application::usecase::usecase::{HasStorage, HasPresenter, with_board_
 
 // ToDo: use use-case traits
 
+/// An issue as it is given by the user, before it is added to the board
+#[derive(Debug, PartialEq, Clone, Default)]
+pub(crate) struct NewIssue {
+    pub(crate) description: String,
+    /// The first column of the board if not given
+    pub(crate) state: Option<State>,
+    pub(crate) due_date: Option<String>,
+    /// The issue is hidden from the board until that date
+    pub(crate) wait_until: Option<String>,
+    pub(crate) tags: Vec<Tag>,
+    pub(crate) estimate: Option<u32>,
+    pub(crate) assignee: Option<Assignee>,
+    pub(crate) priority: Priority,
+}
+
 #[derive(Default, PresenterHolder, StorageHolder)]
 pub(crate) struct AddUseCase<I: IssueStorage, P: Presenter, T: TodayProvider> {
     pub(crate) storage: I,
@@ -19,26 +35,82 @@ pub(crate) struct AddUseCase<I: IssueStorage, P: Presenter, T: TodayProvider> {
 }
 
 impl<I: IssueStorage, P: Presenter, T: TodayProvider> AddUseCase<I, P, T> {
-    pub(crate) fn execute(&self, description: &str, state: State, due_date: Option<String>) {
+    /// Adds a new issue. Unless `force` is set, the WIP limit of its column is respected.
+    pub(crate) fn execute(&self, new_issue: NewIssue, force: bool) {
+        let NewIssue { description, state, due_date, wait_until, tags, estimate, assignee, priority } = new_issue;
+
         with_board_saved_and_presented_single_error(self, |mut board| {
+            let state = match state {
+                Some(state) => board.columns().resolve(&state)?,
+                None => board.columns().first_state(),
+            };
+
+            if !force {
+                board.check_wip_limit(&state, 1)?;
+            }
+
             let date_parser = DateParser {
                 today_provider: &self.time_provider,
             };
 
             let due_date = due_date.map(|due_text| date_parser.parse(due_text.as_str()))
                 .transpose()?;
+            let wait_until = wait_until.map(|wait_text| date_parser.parse(wait_text.as_str()))
+                .transpose()?;
 
-            board.append_entity(Issue{
-                description: Description::from(description),
-                state,
-                time_created: self.time_provider.today(),
+            let mut issue = Issue {
+                short_id: Some(board.next_short_id()),
                 due_date,
-            });
+                wait_until,
+                estimate,
+                assignee,
+                priority,
+                ..Issue::new(Description::from(description.as_str()), state, &self.time_provider)
+            };
+
+            for tag in tags {
+                issue.add_tag(tag);
+            }
+
+            board.append_entity(issue);
             board.history.add(UndoableHistoryElement::Add);
 
             Ok(board)
         });
     }
+
+    /// Adds the issues of the template called `template`, with `text` in place of `{}` in their
+    /// descriptions. The issues are added on the top of their columns, in the order of the
+    /// template, and they are undone together. Unless `force` is set, the WIP limits are respected.
+    pub(crate) fn execute_template(&self, template: &str, text: &str, force: bool) {
+        with_board_saved_and_presented_single_error(self, |mut board| {
+            let date_parser = DateParser {
+                today_provider: &self.time_provider,
+            };
+
+            let mut issues = board.find_template(template)?
+                .expand(text, board.columns(), &date_parser)?;
+
+            if !force {
+                for state in issues.iter().map(|issue| &issue.state).unique() {
+                    let incoming = issues.iter().filter(|issue| &issue.state == state).count();
+                    board.check_wip_limit(state, incoming)?;
+                }
+            }
+
+            for issue in issues.iter_mut() {
+                issue.short_id = Some(board.next_short_id());
+            }
+
+            let count = issues.len();
+            for issue in issues.into_iter().rev() {
+                board.append_entity(issue);
+            }
+            board.history.add(UndoableHistoryElement::AddTemplate(AddTemplateHistoryElement { count }));
+
+            Ok(board)
+        });
+    }
 }
 
 #[cfg(test)]
@@ -50,11 +122,12 @@ mod tests {
     use crate::adapters::storages::memory_issue_storage::test::MemoryIssueStorage;
     use crate::adapters::time_providers::fake::{FakeTodayProvider, DEFAULT_FAKE_TODAY};
     use crate::application::{Issue, State};
+    use crate::application::domain::error::DomainError;
     use crate::application::domain::historized_board::HistorizedBoard;
-    use crate::application::domain::history::UndoableHistoryElement;
-    use crate::application::issue::Description;
-    use crate::application::usecase::add::AddUseCase;
-    use crate::application::usecase::test_utils::get_stored_and_presented_board;
+    use crate::application::domain::history::{AddTemplateHistoryElement, UndoableHistoryElement};
+    use crate::application::issue::{Assignee, Description, Priority, ShortId, Tag};
+    use crate::application::usecase::add::{AddUseCase, NewIssue};
+    use crate::application::usecase::test_utils::{check_no_errors, get_stored_and_presented_board};
 
     #[test]
     fn test_successful_add_use_case() {
@@ -62,7 +135,16 @@ mod tests {
             HistorizedBoard::default().with_4_typical_issues(),
         );
 
-        add_use_case.execute("New task", State::Review, Some(String::from("2023-01-02")));
+        add_use_case.execute(NewIssue {
+            description: String::from("New task"),
+            state: Some(State::from("review")),
+            due_date: Some(String::from("2023-01-02")),
+            tags: vec![Tag::from("backend"), Tag::from("urgent"), Tag::from("Backend")],
+            estimate: Some(3),
+            assignee: Some(Assignee::from("Bob")),
+            priority: Priority::High,
+            ..Default::default()
+        }, false);
 
         let stored_board = get_stored_and_presented_board(&add_use_case);
 
@@ -71,17 +153,157 @@ mod tests {
 
         let issue = stored_board.get_with_index(0);
         check!(issue.description == Description::from("New task"), "Expected specific description of added issue");
-        check!(issue.state == State::Review, "Expected specific state of added issue");
+        check!(issue.state.to_string() == "Review", "Expected specific state of added issue");
         check!(issue.time_created == DEFAULT_FAKE_TODAY, "Expected creation time to be set");
         check!(issue.due_date == Some(date!(2023-01-02)));
+        check!(issue.tags == [Tag::from("backend"), Tag::from("urgent")], "Expected tags without duplicates");
+        check!(issue.short_id == Some(ShortId(1)), "Expected the added issue to get a short id");
+        check!(issue.estimate == Some(3));
+        check!(issue.assignee == Some(Assignee::from("bob")));
+        check!(issue.priority == Priority::High);
 
         let history = stored_board.history.last();
         let_assert!(Some(history) = history, "Expected to have an item in history");
         assert_eq!(history, &UndoableHistoryElement::Add, "Expected item in history to represent and addition of an issue");
     }
 
+    #[test]
+    fn test_add_waiting_issue() {
+        let add_use_case = given_add_use_case_with(
+            HistorizedBoard::default().with_4_typical_issues(),
+        );
+
+        add_use_case.execute(NewIssue {
+            description: String::from("Renew certificate"),
+            wait_until: Some(String::from("2025-06-01")),
+            ..Default::default()
+        }, false);
+
+        let stored_board = get_stored_and_presented_board(&add_use_case);
+        let issue = stored_board.get_with_index(0);
+        check!(issue.wait_until == Some(date!(2025-06-01)));
+        check!(issue.is_waiting(DEFAULT_FAKE_TODAY));
+    }
+
+    #[test]
+    fn test_add_to_first_custom_column_by_default() {
+        let add_use_case = given_add_use_case_with(
+            HistorizedBoard::default().with_columns(&["Backlog", "In Progress", "Done"]),
+        );
+
+        add_use_case.execute(given_new_issue("New task", None), false);
+
+        let stored_board = get_stored_and_presented_board(&add_use_case);
+        check!(stored_board.get_with_index(0).state.to_string() == "Backlog");
+    }
+
+    #[test]
+    fn test_add_respects_wip_limit() {
+        let add_use_case = given_add_use_case_with(
+            HistorizedBoard::default().with_4_typical_issues().with_wip_limit("Review", 1),
+        );
+
+        add_use_case.execute(given_new_issue("New task", Some("Review")), false);
+
+        let errors = add_use_case.presenter.errors_presented.borrow();
+        let_assert!([DomainError::WipLimitExceeded { column, limit: 1 }] = errors.as_slice());
+        check!(column == "Review");
+        add_use_case.storage.load()
+            .assert_issue_count(4);
+    }
+
+    #[test]
+    fn test_add_over_wip_limit_with_force() {
+        let add_use_case = given_add_use_case_with(
+            HistorizedBoard::default().with_4_typical_issues().with_wip_limit("Review", 1),
+        );
+
+        add_use_case.execute(given_new_issue("New task", Some("Review")), true);
+
+        let stored_board = get_stored_and_presented_board(&add_use_case);
+        stored_board.assert_issue_count(5);
+    }
+
+    #[test]
+    fn test_add_with_unknown_state() {
+        let add_use_case = given_add_use_case_with(
+            HistorizedBoard::default().with_4_typical_issues(),
+        );
+
+        add_use_case.execute(given_new_issue("New task", Some("Backlog")), false);
+
+        let errors = add_use_case.presenter.errors_presented.borrow();
+        let_assert!([DomainError::UnknownState { .. }] = errors.as_slice());
+        add_use_case.storage.load()
+            .assert_issue_count(4);
+    }
+
+    #[test]
+    fn test_add_from_template() {
+        let add_use_case = given_add_use_case_with(
+            HistorizedBoard::default().with_4_typical_issues().with_release_template(),
+        );
+
+        add_use_case.execute_template("Release", "v1.4", false);
+
+        check_no_errors(&add_use_case);
+        let stored_board = get_stored_and_presented_board(&add_use_case);
+        stored_board.assert_issue_count(6);
+
+        let release = stored_board.get_with_index(0);
+        check!(release.description == Description::from("Release v1.4"));
+        check!(release.state.to_string() == "Open");
+        check!(release.due_date == Some(date!(2025-03-01)));
+        check!(release.short_id == Some(ShortId(1)));
+        let announcement = stored_board.get_with_index(1);
+        check!(announcement.description == Description::from("Announce v1.4"));
+        check!(announcement.state.to_string() == "Review");
+        check!(announcement.short_id == Some(ShortId(2)));
+
+        check!(stored_board.history.stack == [UndoableHistoryElement::AddTemplate(AddTemplateHistoryElement { count: 2 })]);
+    }
+
+    #[test]
+    fn test_add_from_unknown_template() {
+        let add_use_case = given_add_use_case_with(
+            HistorizedBoard::default().with_4_typical_issues().with_release_template(),
+        );
+
+        add_use_case.execute_template("onboarding", "Alice", false);
+
+        let errors = add_use_case.presenter.errors_presented.borrow();
+        let_assert!([DomainError::TemplateNotFound { name, available }] = errors.as_slice());
+        check!(name == "onboarding");
+        check!(available == "release");
+        add_use_case.storage.load()
+            .assert_issue_count(4);
+    }
+
+    #[test]
+    fn test_add_from_template_respects_wip_limit() {
+        let add_use_case = given_add_use_case_with(
+            HistorizedBoard::default().with_4_typical_issues().with_release_template().with_wip_limit("Review", 1),
+        );
+
+        add_use_case.execute_template("release", "v1.4", false);
+
+        let errors = add_use_case.presenter.errors_presented.borrow();
+        let_assert!([DomainError::WipLimitExceeded { column, limit: 1 }] = errors.as_slice());
+        check!(column == "Review");
+        add_use_case.storage.load()
+            .assert_issue_count(4);
+    }
+
     // ToDo: failure for Add use case?
 
+    fn given_new_issue(description: &str, state: Option<&str>) -> NewIssue {
+        NewIssue {
+            description: String::from(description),
+            state: state.map(State::from),
+            ..Default::default()
+        }
+    }
+
     fn given_add_use_case_with(board: HistorizedBoard<Issue>) -> AddUseCase<MemoryIssueStorage, NilPresenter, FakeTodayProvider> {
         let storage = MemoryIssueStorage::default();
         storage.save(&board);
@@ -0,0 +1,256 @@
+use internal_macros::{PresenterHolder, StorageHolder};
+use uuid::Uuid;
+use crate::application::domain::historized_board::HistorizedBoard;
+use crate::application::domain::history::{ArchiveHistoryElement, ArchiveHistoryElements, UnarchiveHistoryElement, UndoableHistoryElement};
+use crate::application::Issue;
+use crate::application::issue::IssueRef;
+use crate::application::ports::issue_storage::IssueStorage;
+use crate::application::ports::presenter::Presenter;
+use crate::application::ports::time::TodayProvider;
+use crate::application::usecase::usecase::{with_board_saved_and_presented_multi_error, with_board_saved_and_presented_single_error, HasPresenter, HasStorage};
+
+#[derive(Default, PresenterHolder, StorageHolder)]
+pub(crate) struct ArchiveUseCase<I: IssueStorage, P: Presenter, T: TodayProvider> {
+    storage: I,
+    presenter: P,
+    time_provider: T,
+}
+
+impl<I: IssueStorage, P: Presenter, T: TodayProvider> ArchiveUseCase<I, P, T> {
+    /// Moves the issues out of the active board to its archive. Without issues, the done issues
+    /// that have been done for `older_than_days` are archived. If it is not given, the days of the
+    /// board setting are used, or else every done issue is archived.
+    pub(crate) fn execute(&self, issues: &[IssueRef], older_than_days: Option<u32>) {
+        with_board_saved_and_presented_multi_error(self, |mut board| {
+            let ids = if issues.is_empty() {
+                let days = older_than_days.or(board.archive_after_days()).unwrap_or(0);
+                board.done_for_days(days, self.time_provider.today())
+            } else {
+                board.find_entities_by_refs(issues)?
+            };
+
+            archive_issues(&mut board, ids);
+
+            Ok(board)
+        })
+    }
+
+    /// Moves an archived issue back to the top of its column
+    pub(crate) fn unarchive(&self, issue: IssueRef) {
+        with_board_saved_and_presented_single_error(self, |mut board| {
+            let original_position_in_archive = board.find_archived_position_by_ref(issue)?;
+
+            let id = board.unarchive(original_position_in_archive);
+
+            let index = board.position(id);
+            board.history.add(UndoableHistoryElement::Unarchive(UnarchiveHistoryElement {
+                index,
+                original_position_in_archive,
+            }));
+
+            Ok(board)
+        })
+    }
+
+    /// Presents the archived issues that contain `text` in their description, body or tags.
+    /// Without a text, the whole archive is presented.
+    pub(crate) fn search(&self, text: Option<&str>) {
+        let board = self.storage.load();
+
+        let positions = board.get_archived_entities().iter()
+            .enumerate()
+            .filter(|(_, issue)| text.is_none_or(|text| issue.contains_text(text)))
+            .map(|(position, _)| position)
+            .collect::<Vec<_>>();
+
+        self.presenter.render_archive(&board, &positions);
+    }
+}
+
+/// Archives the issues with `ids`, and records it in the history of the board
+pub(crate) fn archive_issues(board: &mut HistorizedBoard<Issue>, ids: Vec<Uuid>) {
+    if ids.is_empty() {
+        return;
+    }
+
+    let archivals = ids.into_iter().map(|id| {
+        let original_position_in_issues = board.position(id);
+
+        board.archive(id);
+
+        ArchiveHistoryElement { original_position_in_issues }
+    }).collect();
+
+    board.history.add(UndoableHistoryElement::Archive(ArchiveHistoryElements {
+        archivals,
+    }));
+}
+
+#[cfg(test)]
+mod tests {
+    use assert2::{check, let_assert};
+    use time::macros::datetime;
+    use crate::adapters::presenters::nil_presenter::test::NilPresenter;
+    use crate::adapters::storages::IssueStorage;
+    use crate::adapters::storages::memory_issue_storage::test::MemoryIssueStorage;
+    use crate::adapters::time_providers::fake::FakeTodayProvider;
+    use crate::application::{Issue, State};
+    use crate::application::domain::activity::{ActivityEvent, ActivityKind};
+    use crate::application::domain::error::DomainError;
+    use crate::application::domain::historized_board::HistorizedBoard;
+    use crate::application::domain::history::{ArchiveHistoryElement, ArchiveHistoryElements, UnarchiveHistoryElement, UndoableHistoryElement};
+    use crate::application::issue::{Description, IssueRef, ShortId, Tag};
+    use crate::application::usecase::archive::ArchiveUseCase;
+    use crate::application::usecase::test_utils::{check_no_errors, get_stored_and_presented_board};
+
+    #[test]
+    fn test_archive_done_issues_older_than() {
+        let use_case = given_archive_use_case_with(given_board_with_done_issues());
+
+        use_case.execute(&[], Some(7));
+
+        check_no_errors(&use_case);
+        let stored_board = get_stored_and_presented_board(&use_case);
+        let descriptions = stored_board.entities().iter().map(|issue| issue.description.as_str()).collect::<Vec<_>>();
+        check!(descriptions == ["Done yesterday", "Still open"]);
+        let archived = stored_board.get_archived_entities().iter().map(|issue| issue.description.as_str()).collect::<Vec<_>>();
+        check!(archived == ["Created done long ago", "Done two weeks ago"], "Expected the issue archived last to be first");
+        check!(stored_board.history.last() == Some(&UndoableHistoryElement::Archive(ArchiveHistoryElements {
+            archivals: vec![
+                ArchiveHistoryElement { original_position_in_issues: 1 },
+                ArchiveHistoryElement { original_position_in_issues: 1 },
+            ],
+        })));
+    }
+
+    #[test]
+    fn test_archive_after_days_of_the_board() {
+        let mut board = given_board_with_done_issues();
+        board.set_archive_after_days(Some(1));
+        let use_case = given_archive_use_case_with(board);
+
+        use_case.execute(&[], None);
+
+        let stored_board = get_stored_and_presented_board(&use_case);
+        check!(stored_board.entities().len() == 1);
+        check!(stored_board.get_archived_entities().len() == 3);
+    }
+
+    #[test]
+    fn test_archive_selected_issues() {
+        let use_case = given_archive_use_case_with(given_board_with_done_issues());
+
+        use_case.execute(&[IssueRef::Index(3), IssueRef::ShortId(ShortId(1))], None);
+
+        check_no_errors(&use_case);
+        let stored_board = get_stored_and_presented_board(&use_case);
+        let archived = stored_board.get_archived_entities().iter().map(|issue| issue.description.as_str()).collect::<Vec<_>>();
+        check!(archived == ["Done yesterday", "Still open"]);
+    }
+
+    #[test]
+    fn test_nothing_to_archive_is_not_in_history() {
+        let use_case = given_archive_use_case_with(HistorizedBoard::default().with_4_typical_issues());
+
+        use_case.execute(&[], Some(30));
+
+        check_no_errors(&use_case);
+        check!(get_stored_and_presented_board(&use_case).history.last() == None);
+    }
+
+    #[test]
+    fn test_unarchive() {
+        let mut board = HistorizedBoard::default().with_4_typical_issues();
+        board.set_archived_entities(vec![
+            Issue { description: Description::from("Archived review"), state: State::from("Review"), short_id: Some(ShortId(8)), ..Default::default() },
+            Issue { description: Description::from("Archived done"), state: State::from("Done"), short_id: Some(ShortId(7)), ..Default::default() },
+        ]);
+        let use_case = given_archive_use_case_with(board);
+
+        use_case.unarchive(IssueRef::ShortId(ShortId(7)));
+
+        check_no_errors(&use_case);
+        let stored_board = get_stored_and_presented_board(&use_case);
+        check!(stored_board.get_with_index(1).description == Description::from("Archived done"), "Expected the issue on the top of its column");
+        check!(stored_board.get_archived_entities().len() == 1);
+        check!(stored_board.history.last() == Some(&UndoableHistoryElement::Unarchive(UnarchiveHistoryElement {
+            index: 1,
+            original_position_in_archive: 1,
+        })));
+
+        use_case.unarchive(IssueRef::Index(1));
+
+        let errors = use_case.presenter.errors_presented.borrow();
+        let_assert!([DomainError::IndexOutOfRange(1)] = errors.as_slice());
+    }
+
+    #[test]
+    fn test_search_archive() {
+        let mut board = HistorizedBoard::default();
+        board.set_archived_entities(vec![
+            Issue { description: Description::from("Fix login"), ..Default::default() },
+            Issue { description: Description::from("Update the docs"), body: Some(String::from("Mention the LOGIN page")), ..Default::default() },
+            Issue { description: Description::from("Restyle the page"), tags: vec![Tag::from("login")], ..Default::default() },
+            Issue { description: Description::from("Fix logout"), ..Default::default() },
+        ]);
+        let use_case = given_archive_use_case_with(board);
+
+        use_case.search(Some("Login"));
+
+        check!(*use_case.presenter.last_archive_rendered.borrow() == Some(vec![0, 1, 2]));
+
+        use_case.search(None);
+
+        check!(*use_case.presenter.last_archive_rendered.borrow() == Some(vec![0, 1, 2, 3]));
+    }
+
+    /// Today is 2025-02-22
+    fn given_board_with_done_issues() -> HistorizedBoard<Issue> {
+        HistorizedBoard::new(vec![
+            Issue {
+                description: Description::from("Done yesterday"),
+                state: State::from("Done"),
+                time_created: datetime!(2025-01-01 10:00 UTC).date(),
+                short_id: Some(ShortId(1)),
+                activity: vec![
+                    ActivityEvent::new(datetime!(2025-02-21 10:00 UTC), ActivityKind::Moved { from: State::from("Open"), to: State::from("Done") }),
+                ],
+                ..Default::default()
+            },
+            Issue {
+                description: Description::from("Done two weeks ago"),
+                state: State::from("Done"),
+                time_created: datetime!(2025-01-01 10:00 UTC).date(),
+                short_id: Some(ShortId(2)),
+                activity: vec![
+                    ActivityEvent::new(datetime!(2025-02-08 10:00 UTC), ActivityKind::Moved { from: State::from("Open"), to: State::from("Done") }),
+                ],
+                ..Default::default()
+            },
+            Issue {
+                description: Description::from("Created done long ago"),
+                state: State::from("Done"),
+                time_created: datetime!(2025-01-01 10:00 UTC).date(),
+                short_id: Some(ShortId(3)),
+                ..Default::default()
+            },
+            Issue {
+                description: Description::from("Still open"),
+                state: State::from("Open"),
+                time_created: datetime!(2025-01-01 10:00 UTC).date(),
+                short_id: Some(ShortId(4)),
+                ..Default::default()
+            },
+        ], vec![], vec![])
+    }
+
+    fn given_archive_use_case_with(board: HistorizedBoard<Issue>) -> ArchiveUseCase<MemoryIssueStorage, NilPresenter, FakeTodayProvider> {
+        let storage = MemoryIssueStorage::default();
+        storage.save(&board);
+
+        ArchiveUseCase {
+            storage,
+            ..Default::default()
+        }
+    }
+}
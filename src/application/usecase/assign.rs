@@ -0,0 +1,97 @@
+use internal_macros::{PresenterHolder, StorageHolder};
+use crate::application::domain::history::{AssignHistoryElement, UndoableHistoryElement};
+use crate::application::issue::{Assignee, IssueRef};
+use crate::application::ports::issue_storage::IssueStorage;
+use crate::application::ports::presenter::Presenter;
+use crate::application::usecase::usecase::{with_board_saved_and_presented_single_error, HasPresenter, HasStorage};
+
+#[derive(Default, PresenterHolder, StorageHolder)]
+pub(crate) struct AssignUseCase<I: IssueStorage, P: Presenter> {
+    storage: I,
+    presenter: P,
+}
+
+impl<I: IssueStorage, P: Presenter> AssignUseCase<I, P> {
+    /// Assigns the issue to a person. Without an assignee, the issue becomes unassigned.
+    pub(crate) fn execute(&self, issue: IssueRef, assignee: Option<Assignee>) {
+        with_board_saved_and_presented_single_error(self, |mut board| {
+            let id = board.find_entity_id_by_ref(issue)?;
+            let index = board.position(id);
+
+            let issue = board.get_mut(id);
+            if issue.assignee != assignee {
+                let original_assignee = std::mem::replace(&mut issue.assignee, assignee);
+
+                board.history.add(UndoableHistoryElement::Assign(AssignHistoryElement {
+                    index,
+                    original_assignee,
+                }));
+            }
+
+            Ok(board)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert2::check;
+    use crate::adapters::presenters::nil_presenter::test::NilPresenter;
+    use crate::adapters::storages::IssueStorage;
+    use crate::adapters::storages::memory_issue_storage::test::MemoryIssueStorage;
+    use crate::application::Issue;
+    use crate::application::domain::historized_board::HistorizedBoard;
+    use crate::application::domain::history::{AssignHistoryElement, UndoableHistoryElement};
+    use crate::application::issue::{Assignee, IssueRef};
+    use crate::application::usecase::assign::AssignUseCase;
+    use crate::application::usecase::test_utils::{check_no_errors, get_stored_and_presented_board};
+
+    #[test]
+    fn test_reassign_and_unassign() {
+        let use_case = given_assign_use_case_with(
+            HistorizedBoard::default().with_4_typical_issues(),
+        );
+
+        use_case.execute(IssueRef::Index(2), Some(Assignee::from("alice")));
+        use_case.execute(IssueRef::Index(2), Some(Assignee::from("bob")));
+
+        check_no_errors(&use_case);
+        let stored_board = get_stored_and_presented_board(&use_case);
+        check!(stored_board.get_with_index(2).assignee == Some(Assignee::from("bob")));
+        check!(stored_board.history.last() == Some(&UndoableHistoryElement::Assign(AssignHistoryElement {
+            index: 2,
+            original_assignee: Some(Assignee::from("alice")),
+        })));
+
+        use_case.execute(IssueRef::Index(2), None);
+
+        let stored_board = get_stored_and_presented_board(&use_case);
+        check!(stored_board.get_with_index(2).assignee == None);
+        check!(stored_board.history.stack.len() == 3);
+    }
+
+    #[test]
+    fn test_same_assignee_is_not_in_history() {
+        let use_case = given_assign_use_case_with(
+            HistorizedBoard::default().with_issue(Issue {
+                assignee: Some(Assignee::from("alice")),
+                ..Default::default()
+            }),
+        );
+
+        use_case.execute(IssueRef::Index(0), Some(Assignee::from("Alice")));
+
+        check_no_errors(&use_case);
+        check!(get_stored_and_presented_board(&use_case).history.last() == None);
+    }
+
+    fn given_assign_use_case_with(board: HistorizedBoard<Issue>) -> AssignUseCase<MemoryIssueStorage, NilPresenter> {
+        let storage = MemoryIssueStorage::default();
+        storage.save(&board);
+
+        AssignUseCase {
+            storage,
+            ..Default::default()
+        }
+    }
+}
@@ -0,0 +1,145 @@
+use internal_macros::{PresenterHolder, StorageHolder};
+use crate::application::domain::activity::ActivityKind;
+use crate::application::domain::error::DomainError;
+use crate::application::domain::history::{BlockHistoryElement, UndoableHistoryElement};
+use crate::application::issue::IssueRef;
+use crate::application::ports::issue_storage::IssueStorage;
+use crate::application::ports::presenter::Presenter;
+use crate::application::ports::time::TodayProvider;
+use crate::application::usecase::usecase::{with_board_saved_and_presented_single_error, HasPresenter, HasStorage};
+
+#[derive(Debug, PartialEq, Clone)]
+pub(crate) enum BlockAction {
+    /// Flags the issue as blocked for the reason. A blocked issue gets the new reason.
+    Block(String),
+    /// Removes the blocked flag of the issue
+    Unblock,
+}
+
+#[derive(Default, PresenterHolder, StorageHolder)]
+pub(crate) struct BlockUseCase<I: IssueStorage, P: Presenter, T: TodayProvider> {
+    storage: I,
+    presenter: P,
+    time_provider: T,
+}
+
+impl<I: IssueStorage, P: Presenter, T: TodayProvider> BlockUseCase<I, P, T> {
+    /// Flags an issue as blocked, or removes the flag. The change is recorded in the activity log
+    /// of the issue.
+    pub(crate) fn execute(&self, issue_ref: IssueRef, action: BlockAction) {
+        with_board_saved_and_presented_single_error(self, |mut board| {
+            let id = board.find_entity_id_by_ref(issue_ref)?;
+            let index = board.position(id);
+
+            let issue = board.get_mut(id);
+            let original_reason = issue.blocked_reason.clone();
+
+            let (blocked_reason, activity) = match action {
+                BlockAction::Block(reason) => {
+                    let reason = reason.trim();
+                    if reason.is_empty() {
+                        return Err(DomainError::EmptyBlockReason);
+                    }
+                    (Some(reason.to_string()), ActivityKind::Blocked(reason.to_string()))
+                },
+                BlockAction::Unblock => {
+                    if !issue.is_flagged_blocked() {
+                        return Err(DomainError::NotBlocked(issue_ref));
+                    }
+                    (None, ActivityKind::Unblocked)
+                },
+            };
+
+            issue.blocked_reason = blocked_reason;
+            issue.record_activity(self.time_provider.now(), activity);
+
+            board.history.add(UndoableHistoryElement::Block(BlockHistoryElement {
+                index,
+                original_reason,
+            }));
+
+            Ok(board)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert2::{check, let_assert};
+    use crate::adapters::presenters::nil_presenter::test::NilPresenter;
+    use crate::adapters::storages::IssueStorage;
+    use crate::adapters::storages::memory_issue_storage::test::MemoryIssueStorage;
+    use crate::adapters::time_providers::fake::FakeTodayProvider;
+    use crate::application::Issue;
+    use crate::application::domain::activity::ActivityKind;
+    use crate::application::domain::error::DomainError;
+    use crate::application::domain::historized_board::HistorizedBoard;
+    use crate::application::domain::history::{BlockHistoryElement, UndoableHistoryElement};
+    use crate::application::issue::{IssueRef, ShortId};
+    use crate::application::usecase::block::{BlockAction, BlockUseCase};
+    use crate::application::usecase::test_utils::{check_no_errors, get_stored_and_presented_board};
+
+    #[test]
+    fn test_block_and_unblock() {
+        let use_case = given_block_use_case_with(
+            HistorizedBoard::default().with_4_typical_issues(),
+        );
+
+        use_case.execute(IssueRef::Index(2), BlockAction::Block(String::from(" waiting for API keys ")));
+
+        check_no_errors(&use_case);
+        let stored_board = get_stored_and_presented_board(&use_case);
+        let issue = stored_board.get_with_index(2);
+        check!(issue.blocked_reason == Some(String::from("waiting for API keys")));
+        check!(issue.activity.last().map(|event| &event.kind) == Some(&ActivityKind::Blocked(String::from("waiting for API keys"))));
+
+        use_case.execute(IssueRef::Index(2), BlockAction::Unblock);
+
+        check_no_errors(&use_case);
+        let stored_board = get_stored_and_presented_board(&use_case);
+        let issue = stored_board.get_with_index(2);
+        check!(issue.blocked_reason == None);
+        check!(issue.activity.last().map(|event| &event.kind) == Some(&ActivityKind::Unblocked));
+        check!(stored_board.history.last() == Some(&UndoableHistoryElement::Block(BlockHistoryElement {
+            index: 2,
+            original_reason: Some(String::from("waiting for API keys")),
+        })));
+    }
+
+    #[test]
+    fn test_block_without_reason_is_refused() {
+        let use_case = given_block_use_case_with(
+            HistorizedBoard::default().with_4_typical_issues(),
+        );
+
+        use_case.execute(IssueRef::Index(2), BlockAction::Block(String::from("  ")));
+
+        let errors = use_case.presenter.errors_presented.borrow();
+        let_assert!([DomainError::EmptyBlockReason] = errors.as_slice());
+        check!(use_case.storage.load().history.last() == None);
+    }
+
+    #[test]
+    fn test_unblock_issue_that_is_not_blocked() {
+        let mut board = HistorizedBoard::default().with_4_typical_issues();
+        board.assign_missing_short_ids();
+        let use_case = given_block_use_case_with(board);
+
+        use_case.execute(IssueRef::Index(2), BlockAction::Unblock);
+        use_case.execute(IssueRef::ShortId(ShortId(2)), BlockAction::Unblock);
+
+        let errors = use_case.presenter.errors_presented.borrow();
+        let_assert!([DomainError::NotBlocked(IssueRef::Index(2)), DomainError::NotBlocked(IssueRef::ShortId(ShortId(2)))] = errors.as_slice());
+        check!(use_case.storage.load().history.last() == None);
+    }
+
+    fn given_block_use_case_with(board: HistorizedBoard<Issue>) -> BlockUseCase<MemoryIssueStorage, NilPresenter, FakeTodayProvider> {
+        let storage = MemoryIssueStorage::default();
+        storage.save(&board);
+
+        BlockUseCase {
+            storage,
+            ..Default::default()
+        }
+    }
+}
@@ -0,0 +1,219 @@
+use internal_macros::PresenterHolder;
+use crate::application::domain::board_name::BoardName;
+use crate::application::domain::error::{DomainError, DomainResult};
+use crate::application::domain::historized_board::HistorizedBoard;
+use crate::application::ports::board_registry::BoardRegistry;
+use crate::application::ports::presenter::Presenter;
+use crate::application::usecase::usecase::HasPresenter;
+
+#[derive(Debug, PartialEq, Clone)]
+pub(crate) enum BoardsAction {
+    List,
+    Create(BoardName),
+    Rename {
+        from: BoardName,
+        to: BoardName,
+    },
+    /// Deletes the board. Boards that still have issues are only deleted with `force`.
+    Delete {
+        name: BoardName,
+        force: bool,
+    },
+    /// Makes the board the one used when no board is selected
+    SetDefault(BoardName),
+}
+
+#[derive(Default, PresenterHolder)]
+pub(crate) struct BoardsUseCase<R: BoardRegistry, P: Presenter> {
+    registry: R,
+    presenter: P,
+}
+
+impl<R: BoardRegistry, P: Presenter> BoardsUseCase<R, P> {
+    /// Manages the boards of the installation, then presents the list of the boards
+    pub(crate) fn execute(&self, action: BoardsAction) {
+        match self.apply(action) {
+            Ok(()) => self.presenter.render_boards(&self.registry.boards(), &self.registry.default_board()),
+            Err(error) => self.presenter.render_error(&error),
+        }
+    }
+
+    /// Whether the board can be selected for the commands of this run. Returns false, after
+    /// presenting the error, if the board does not exist.
+    pub(crate) fn can_select(&self, name: &BoardName) -> bool {
+        match self.check_exists(name) {
+            Ok(()) => true,
+            Err(error) => {
+                self.presenter.render_error(&error);
+                false
+            },
+        }
+    }
+
+    fn apply(&self, action: BoardsAction) -> DomainResult<()> {
+        match action {
+            BoardsAction::List => {},
+            BoardsAction::Create(name) => {
+                self.check_does_not_exist(&name)?;
+                self.registry.save(&name, &HistorizedBoard::default());
+            },
+            BoardsAction::Rename { from, to } => {
+                self.check_can_be_changed(&from)?;
+                self.check_does_not_exist(&to)?;
+                self.registry.rename(&from, &to);
+
+                // So that transfers to the board can still be undone
+                for name in self.registry.boards() {
+                    let mut board = self.registry.load(&name);
+                    if board.history.rename_transfer_target(&from, &to) {
+                        self.registry.save(&name, &board);
+                    }
+                }
+            },
+            BoardsAction::Delete { name, force } => {
+                self.check_can_be_changed(&name)?;
+                let board = self.registry.load(&name);
+                if !force && (!board.entities().is_empty() || !board.get_archived_entities().is_empty()) {
+                    return Err(DomainError::BoardNotEmpty(name));
+                }
+                self.registry.delete(&name);
+            },
+            BoardsAction::SetDefault(name) => {
+                self.check_exists(&name)?;
+                self.registry.set_default_board(&name);
+            },
+        }
+
+        Ok(())
+    }
+
+    fn check_exists(&self, name: &BoardName) -> DomainResult<()> {
+        if self.registry.exists(name) {
+            Ok(())
+        } else {
+            Err(DomainError::BoardNotFound(name.clone()))
+        }
+    }
+
+    fn check_does_not_exist(&self, name: &BoardName) -> DomainResult<()> {
+        if self.registry.exists(name) {
+            Err(DomainError::BoardAlreadyExists(name.clone()))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// The main board always exists, so that there is always a board to fall back to
+    fn check_can_be_changed(&self, name: &BoardName) -> DomainResult<()> {
+        if name.is_main() {
+            return Err(DomainError::MainBoardIsPermanent);
+        }
+
+        self.check_exists(name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert2::{check, let_assert};
+    use crate::adapters::presenters::nil_presenter::test::NilPresenter;
+    use crate::adapters::storages::memory_board_registry::test::MemoryBoardRegistry;
+    use crate::application::domain::board_name::BoardName;
+    use crate::application::domain::error::DomainError;
+    use crate::application::State;
+    use crate::application::domain::historized_board::HistorizedBoard;
+    use crate::application::domain::history::{TransferHistoryElement, UndoableHistoryElement};
+    use crate::application::issue::ShortId;
+    use crate::application::ports::board_registry::BoardRegistry;
+    use crate::application::usecase::boards::{BoardsAction, BoardsUseCase};
+
+    #[test]
+    fn test_create_rename_and_delete_board() {
+        let use_case = BoardsUseCase::<MemoryBoardRegistry, NilPresenter>::default();
+        let work = BoardName::try_from("Work").unwrap();
+
+        use_case.execute(BoardsAction::Create(work.clone()));
+        check!(*use_case.presenter.boards_presented.borrow() == vec![BoardName::main(), work.clone()]);
+
+        use_case.execute(BoardsAction::SetDefault(work.clone()));
+        use_case.execute(BoardsAction::Rename { from: work, to: BoardName::try_from("office").unwrap() });
+        check!(use_case.registry.default_board() == BoardName::try_from("office").unwrap());
+
+        use_case.execute(BoardsAction::Delete { name: BoardName::try_from("office").unwrap(), force: false });
+        check!(*use_case.presenter.boards_presented.borrow() == vec![BoardName::main()]);
+        check!(use_case.registry.default_board() == BoardName::main());
+        check!(use_case.presenter.errors_presented.borrow().is_empty());
+    }
+
+    #[test]
+    fn test_rename_board_keeps_transfers_to_it() {
+        let transfer = |board: &str| UndoableHistoryElement::Transfer(TransferHistoryElement {
+            index: 0,
+            board: BoardName::try_from(board).unwrap(),
+            short_id: ShortId(7),
+            original_short_id: Some(ShortId(2)),
+            original_state: State::from("Open"),
+            original_blocked_by: vec![],
+            original_parent: None,
+        });
+        let use_case = given_boards_use_case_with(MemoryBoardRegistry::default()
+            .with_board("work", HistorizedBoard::new(vec![], vec![], vec![transfer("personal"), transfer("home")]))
+            .with_board("personal", HistorizedBoard::default()));
+
+        use_case.execute(BoardsAction::Rename { from: BoardName::try_from("personal").unwrap(), to: BoardName::try_from("private").unwrap() });
+        use_case.execute(BoardsAction::Create(BoardName::try_from("personal").unwrap()));
+
+        check!(use_case.presenter.errors_presented.borrow().is_empty());
+        check!(use_case.registry.load(&BoardName::try_from("work").unwrap()).history.stack == [transfer("private"), transfer("home")]);
+    }
+
+    #[test]
+    fn test_board_names_are_checked() {
+        let use_case = given_boards_use_case_with(MemoryBoardRegistry::default().with_board("work", HistorizedBoard::default()));
+
+        use_case.execute(BoardsAction::Create(BoardName::try_from("work").unwrap()));
+        use_case.execute(BoardsAction::Rename { from: BoardName::try_from("personal").unwrap(), to: BoardName::try_from("home").unwrap() });
+        use_case.execute(BoardsAction::Delete { name: BoardName::main(), force: true });
+        use_case.execute(BoardsAction::SetDefault(BoardName::try_from("personal").unwrap()));
+
+        let errors = use_case.presenter.errors_presented.borrow();
+        let_assert!([
+            DomainError::BoardAlreadyExists(_),
+            DomainError::BoardNotFound(_),
+            DomainError::MainBoardIsPermanent,
+            DomainError::BoardNotFound(_),
+        ] = errors.as_slice());
+    }
+
+    #[test]
+    fn test_delete_board_with_issues_needs_force() {
+        let use_case = given_boards_use_case_with(MemoryBoardRegistry::default().with_board("work", HistorizedBoard::default().with_4_typical_issues()));
+
+        use_case.execute(BoardsAction::Delete { name: BoardName::try_from("work").unwrap(), force: false });
+
+        check!(matches!(use_case.presenter.errors_presented.borrow().as_slice(), [DomainError::BoardNotEmpty(_)]));
+        check!(use_case.registry.exists(&BoardName::try_from("work").unwrap()));
+
+        use_case.execute(BoardsAction::Delete { name: BoardName::try_from("work").unwrap(), force: true });
+
+        check!(!use_case.registry.exists(&BoardName::try_from("work").unwrap()));
+    }
+
+    #[test]
+    fn test_select_board() {
+        let use_case = given_boards_use_case_with(MemoryBoardRegistry::default().with_board("work", HistorizedBoard::default()));
+
+        check!(!use_case.can_select(&BoardName::try_from("personal").unwrap()));
+        check!(matches!(use_case.presenter.errors_presented.borrow().as_slice(), [DomainError::BoardNotFound(_)]));
+
+        check!(use_case.can_select(&BoardName::try_from("work").unwrap()));
+        check!(use_case.presenter.errors_presented.borrow().len() == 1);
+    }
+
+    fn given_boards_use_case_with(registry: MemoryBoardRegistry) -> BoardsUseCase<MemoryBoardRegistry, NilPresenter> {
+        BoardsUseCase {
+            registry,
+            ..Default::default()
+        }
+    }
+}
@@ -0,0 +1,151 @@
+use internal_macros::{PresenterHolder, StorageHolder};
+use crate::application::domain::history::{ChecklistHistoryElement, UndoableHistoryElement};
+use crate::application::issue::IssueRef;
+use crate::application::ports::issue_storage::IssueStorage;
+use crate::application::ports::presenter::Presenter;
+use crate::application::usecase::usecase::{with_board_saved_and_presented_single_error, HasPresenter, HasStorage};
+
+/// Change of the checklist of an issue. Items are numbered from 1, in the order they were added.
+#[derive(Debug, PartialEq, Clone)]
+pub(crate) enum ChecklistChange {
+    Add(String),
+    Done(usize),
+    Undone(usize),
+    Remove(usize),
+}
+
+#[derive(Default, PresenterHolder, StorageHolder)]
+pub(crate) struct ChecklistUseCase<I: IssueStorage, P: Presenter> {
+    storage: I,
+    presenter: P,
+}
+
+impl<I: IssueStorage, P: Presenter> ChecklistUseCase<I, P> {
+    pub(crate) fn execute(&self, issue: IssueRef, change: &ChecklistChange) {
+        with_board_saved_and_presented_single_error(self, |mut board| {
+            let id = board.find_entity_id_by_ref(issue)?;
+            let index = board.position(id);
+
+            let original_checklist = board.get(id).checklist.clone();
+
+            let issue = board.get_mut(id);
+            match change {
+                ChecklistChange::Add(text) => issue.add_checklist_item(text),
+                ChecklistChange::Done(number) => issue.set_checklist_item_done(*number, true)?,
+                ChecklistChange::Undone(number) => issue.set_checklist_item_done(*number, false)?,
+                ChecklistChange::Remove(number) => issue.remove_checklist_item(*number)?,
+            }
+
+            if issue.checklist != original_checklist {
+                board.history.add(UndoableHistoryElement::Checklist(ChecklistHistoryElement {
+                    index,
+                    original_checklist,
+                }));
+            }
+
+            Ok(board)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert2::{check, let_assert};
+    use crate::adapters::presenters::nil_presenter::test::NilPresenter;
+    use crate::adapters::storages::IssueStorage;
+    use crate::adapters::storages::memory_issue_storage::test::MemoryIssueStorage;
+    use crate::application::domain::error::DomainError;
+    use crate::application::domain::historized_board::HistorizedBoard;
+    use crate::application::domain::history::{ChecklistHistoryElement, UndoableHistoryElement};
+    use crate::application::Issue;
+    use crate::application::issue::{ChecklistItem, Description, IssueRef};
+    use crate::application::usecase::checklist::{ChecklistChange, ChecklistUseCase};
+    use crate::application::usecase::test_utils::{check_no_errors, get_stored_and_presented_board};
+
+    #[test]
+    fn test_add_checklist_item() {
+        let use_case = given_checklist_use_case_with(
+            HistorizedBoard::default().with_issue(issue_with_checklist()),
+        );
+
+        use_case.execute(IssueRef::Index(0), &ChecklistChange::Add(String::from("write tests")));
+
+        check_no_errors(&use_case);
+        let stored_board = get_stored_and_presented_board(&use_case);
+
+        let checklist = &stored_board.get_with_index(0).checklist;
+        check!(checklist.len() == 3);
+        check!(checklist[2] == ChecklistItem::new("write tests"));
+        check!(stored_board.history.last() == Some(&UndoableHistoryElement::Checklist(ChecklistHistoryElement {
+            index: 0,
+            original_checklist: issue_with_checklist().checklist,
+        })));
+    }
+
+    #[test]
+    fn test_check_off_and_remove_items() {
+        let use_case = given_checklist_use_case_with(
+            HistorizedBoard::default().with_issue(issue_with_checklist()),
+        );
+
+        use_case.execute(IssueRef::Index(0), &ChecklistChange::Done(2));
+        use_case.execute(IssueRef::Index(0), &ChecklistChange::Undone(1));
+        use_case.execute(IssueRef::Index(0), &ChecklistChange::Remove(1));
+
+        check_no_errors(&use_case);
+        let stored_board = get_stored_and_presented_board(&use_case);
+
+        let issue = stored_board.get_with_index(0);
+        check!(issue.checklist == [ChecklistItem { text: String::from("update docs"), done: true }]);
+        check!(issue.checklist_progress() == (1, 1));
+        check!(stored_board.history.stack.len() == 3);
+    }
+
+    #[test]
+    fn test_change_without_effect_is_not_in_history() {
+        let use_case = given_checklist_use_case_with(
+            HistorizedBoard::default().with_issue(issue_with_checklist()),
+        );
+
+        use_case.execute(IssueRef::Index(0), &ChecklistChange::Done(1));
+
+        check_no_errors(&use_case);
+        let stored_board = get_stored_and_presented_board(&use_case);
+        check!(stored_board.history.last() == None);
+    }
+
+    #[test]
+    fn test_checklist_item_not_found() {
+        let use_case = given_checklist_use_case_with(
+            HistorizedBoard::default().with_issue(issue_with_checklist()),
+        );
+
+        for number in [0, 3] {
+            use_case.execute(IssueRef::Index(0), &ChecklistChange::Done(number));
+        }
+
+        let errors = use_case.presenter.errors_presented.borrow();
+        let_assert!([DomainError::ChecklistItemNotFound(0), DomainError::ChecklistItemNotFound(3)] = errors.as_slice());
+    }
+
+    fn issue_with_checklist() -> Issue {
+        Issue {
+            description: Description::from("Fix login"),
+            checklist: vec![
+                ChecklistItem { text: String::from("reproduce"), done: true },
+                ChecklistItem::new("update docs"),
+            ],
+            ..Default::default()
+        }
+    }
+
+    fn given_checklist_use_case_with(board: HistorizedBoard<Issue>) -> ChecklistUseCase<MemoryIssueStorage, NilPresenter> {
+        let storage = MemoryIssueStorage::default();
+        storage.save(&board);
+
+        ChecklistUseCase {
+            storage,
+            ..Default::default()
+        }
+    }
+}
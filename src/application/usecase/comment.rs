@@ -0,0 +1,95 @@
+use internal_macros::{PresenterHolder, StorageHolder};
+use crate::application::domain::activity::ActivityKind;
+use crate::application::domain::error::DomainError;
+use crate::application::domain::history::{CommentHistoryElement, UndoableHistoryElement};
+use crate::application::issue::IssueRef;
+use crate::application::ports::issue_storage::IssueStorage;
+use crate::application::ports::presenter::Presenter;
+use crate::application::ports::time::TodayProvider;
+use crate::application::usecase::usecase::{with_board_saved_and_presented_single_error, HasPresenter, HasStorage};
+
+#[derive(Default, PresenterHolder, StorageHolder)]
+pub(crate) struct CommentUseCase<I: IssueStorage, P: Presenter, T: TodayProvider> {
+    storage: I,
+    presenter: P,
+    time_provider: T,
+}
+
+impl<I: IssueStorage, P: Presenter, T: TodayProvider> CommentUseCase<I, P, T> {
+    /// Adds a timestamped comment to the activity log of the issue.
+    pub(crate) fn execute(&self, issue: IssueRef, text: &str) {
+        with_board_saved_and_presented_single_error(self, |mut board| {
+            let text = text.trim();
+            if text.is_empty() {
+                return Err(DomainError::EmptyComment);
+            }
+
+            let id = board.find_entity_id_by_ref(issue)?;
+            let index = board.position(id);
+
+            board.get_mut(id).record_activity(self.time_provider.now(), ActivityKind::Comment(text.to_string()));
+            board.history.add(UndoableHistoryElement::Comment(CommentHistoryElement { index }));
+
+            Ok(board)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert2::{check, let_assert};
+    use time::macros::datetime;
+    use crate::adapters::presenters::nil_presenter::test::NilPresenter;
+    use crate::adapters::storages::IssueStorage;
+    use crate::adapters::storages::memory_issue_storage::test::MemoryIssueStorage;
+    use crate::adapters::time_providers::fake::FakeTodayProvider;
+    use crate::application::Issue;
+    use crate::application::domain::activity::{ActivityEvent, ActivityKind};
+    use crate::application::domain::error::DomainError;
+    use crate::application::domain::historized_board::HistorizedBoard;
+    use crate::application::domain::history::{CommentHistoryElement, UndoableHistoryElement};
+    use crate::application::issue::IssueRef;
+    use crate::application::usecase::comment::CommentUseCase;
+    use crate::application::usecase::test_utils::{check_no_errors, get_stored_and_presented_board};
+
+    #[test]
+    fn test_comment() {
+        let use_case = given_comment_use_case_with(
+            HistorizedBoard::default().with_4_typical_issues(),
+        );
+
+        use_case.execute(IssueRef::Index(1), " waiting on infra ");
+
+        check_no_errors(&use_case);
+        let stored_board = get_stored_and_presented_board(&use_case);
+        check!(stored_board.get_with_index(1).activity == vec![
+            ActivityEvent::new(datetime!(2025-02-22 10:00 UTC), ActivityKind::Comment(String::from("waiting on infra"))),
+        ]);
+        check!(stored_board.history.last() == Some(&UndoableHistoryElement::Comment(CommentHistoryElement {
+            index: 1,
+        })));
+    }
+
+    #[test]
+    fn test_empty_comment_is_refused() {
+        let use_case = given_comment_use_case_with(
+            HistorizedBoard::default().with_4_typical_issues(),
+        );
+
+        use_case.execute(IssueRef::Index(1), "  ");
+
+        let errors = use_case.presenter.errors_presented.borrow();
+        let_assert!([DomainError::EmptyComment] = errors.as_slice());
+        check!(use_case.storage.load().history.last() == None);
+    }
+
+    fn given_comment_use_case_with(board: HistorizedBoard<Issue>) -> CommentUseCase<MemoryIssueStorage, NilPresenter, FakeTodayProvider> {
+        let storage = MemoryIssueStorage::default();
+        storage.save(&board);
+
+        CommentUseCase {
+            storage,
+            ..Default::default()
+        }
+    }
+}
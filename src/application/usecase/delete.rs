@@ -1,5 +1,6 @@
 use internal_macros::{PresenterHolder, StorageHolder};
 use crate::application::domain::history::{DeleteHistoryElement, DeleteHistoryElements, UndoableHistoryElement};
+use crate::application::issue::IssueRef;
 use crate::application::ports::issue_storage::IssueStorage;
 use crate::application::ports::presenter::Presenter;
 use crate::application::usecase::usecase::{with_board_saved_and_presented_multi_error, HasPresenter, HasStorage};
@@ -11,9 +12,9 @@ pub(crate) struct DeleteUseCase<I: IssueStorage, P: Presenter> {
 }
 
 impl<I: IssueStorage, P: Presenter> DeleteUseCase<I, P> {
-    pub(crate) fn execute(&mut self, indices: &[usize]) {
+    pub(crate) fn execute(&mut self, issues: &[IssueRef]) {
         with_board_saved_and_presented_multi_error(self, |mut board| {
-            let ids = board.find_entities_by_indices(indices)?;
+            let ids = board.find_entities_by_refs(issues)?;
 
             let history_elements = ids.into_iter().map(|id| {
                 let original_index = board.position(id);
@@ -38,7 +39,7 @@ impl<I: IssueStorage, P: Presenter> DeleteUseCase<I, P> {
 mod tests {
     use assert2::{check, let_assert};
     use crate::application::Issue;
-    use crate::application::issue::Description;
+    use crate::application::issue::{Description, IssueRef};
     use crate::adapters::presenters::nil_presenter::test::NilPresenter;
     use crate::adapters::storages::IssueStorage;
     use crate::adapters::storages::memory_issue_storage::test::MemoryIssueStorage;
@@ -55,7 +56,7 @@ mod tests {
         );
 
         // When second, fourth and first issue are deleted
-        let _ = sut.execute(&vec![1, 3, 0]);
+        let _ = sut.execute(&[IssueRef::Index(1), IssueRef::Index(3), IssueRef::Index(0)]);
 
         // Then
         let stored_board = get_stored_and_presented_board(&sut);
@@ -90,7 +91,7 @@ mod tests {
             HistorizedBoard::default().with_4_typical_issues(),
         );
 
-        delete_use_case.execute(&vec![1, 4, 5]);
+        delete_use_case.execute(&[IssueRef::Index(1), IssueRef::Index(4), IssueRef::Index(5)]);
 
         let cell = delete_use_case.presenter.errors_presented.borrow();
         let errors = cell.as_slice();
@@ -0,0 +1,134 @@
+use internal_macros::{PresenterHolder, StorageHolder};
+use crate::application::domain::history::{DependencyHistoryElement, UndoableHistoryElement};
+use crate::application::issue::IssueRef;
+use crate::application::ports::issue_storage::IssueStorage;
+use crate::application::ports::presenter::Presenter;
+use crate::application::usecase::usecase::{with_board_saved_and_presented_single_error, HasPresenter, HasStorage};
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub(crate) enum DependencyAction {
+    Add,
+    Remove,
+}
+
+#[derive(Default, PresenterHolder, StorageHolder)]
+pub(crate) struct DependencyUseCase<I: IssueStorage, P: Presenter> {
+    storage: I,
+    presenter: P,
+}
+
+impl<I: IssueStorage, P: Presenter> DependencyUseCase<I, P> {
+    /// Adds or removes the `blockers` of `issue`. An issue cannot be moved to done while its
+    /// blockers are not done.
+    pub(crate) fn execute(&self, issue: IssueRef, blockers: &[IssueRef], action: DependencyAction) {
+        with_board_saved_and_presented_single_error(self, |mut board| {
+            let id = board.find_entity_id_by_ref(issue)?;
+            let index = board.position(id);
+
+            let original_blocked_by = board.get(id).blocked_by.clone();
+
+            for &blocker in blockers {
+                let blocker_id = board.find_entity_id_by_ref(blocker)?;
+
+                match action {
+                    DependencyAction::Add => board.add_blocker(id, blocker_id)?,
+                    DependencyAction::Remove => board.remove_blocker(id, blocker_id)?,
+                }
+            }
+
+            if board.get(id).blocked_by != original_blocked_by {
+                board.history.add(UndoableHistoryElement::Dependency(DependencyHistoryElement {
+                    index,
+                    original_blocked_by,
+                }));
+            }
+
+            Ok(board)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert2::{check, let_assert};
+    use crate::adapters::presenters::nil_presenter::test::NilPresenter;
+    use crate::adapters::storages::IssueStorage;
+    use crate::adapters::storages::memory_issue_storage::test::MemoryIssueStorage;
+    use crate::application::domain::error::DomainError;
+    use crate::application::domain::historized_board::HistorizedBoard;
+    use crate::application::domain::history::{DependencyHistoryElement, UndoableHistoryElement};
+    use crate::application::Issue;
+    use crate::application::issue::{IssueRef, ShortId};
+    use crate::application::usecase::dependency::{DependencyAction, DependencyUseCase};
+    use crate::application::usecase::test_utils::{check_no_errors, get_stored_and_presented_board};
+
+    #[test]
+    fn test_add_blockers() {
+        let use_case = given_dependency_use_case_with(given_board_with_short_ids());
+
+        use_case.execute(IssueRef::Index(0), &[IssueRef::ShortId(ShortId(2)), IssueRef::Index(3)], DependencyAction::Add);
+
+        check_no_errors(&use_case);
+        let stored_board = get_stored_and_presented_board(&use_case);
+
+        check!(stored_board.get_with_index(0).blocked_by == [ShortId(2), ShortId(4)]);
+        check!(stored_board.history.last() == Some(&UndoableHistoryElement::Dependency(DependencyHistoryElement {
+            index: 0,
+            original_blocked_by: vec![],
+        })));
+    }
+
+    #[test]
+    fn test_remove_blocker() {
+        let mut board = given_board_with_short_ids();
+        let id = board.find_entity_id_by_index(0).unwrap();
+        board.get_mut(id).blocked_by = vec![ShortId(2), ShortId(4)];
+        let use_case = given_dependency_use_case_with(board);
+
+        use_case.execute(IssueRef::Index(0), &[IssueRef::ShortId(ShortId(2))], DependencyAction::Remove);
+
+        check_no_errors(&use_case);
+        let stored_board = get_stored_and_presented_board(&use_case);
+
+        check!(stored_board.get_with_index(0).blocked_by == [ShortId(4)]);
+    }
+
+    #[test]
+    fn test_cycles_are_rejected() {
+        let mut board = given_board_with_short_ids();
+        let id = board.find_entity_id_by_index(1).unwrap();
+        board.get_mut(id).blocked_by = vec![ShortId(1)];
+        let use_case = given_dependency_use_case_with(board);
+
+        // @1 <- @2 <- @3 is fine, but @3 <- @1 would close the cycle
+        use_case.execute(IssueRef::ShortId(ShortId(3)), &[IssueRef::ShortId(ShortId(2))], DependencyAction::Add);
+        use_case.execute(IssueRef::ShortId(ShortId(1)), &[IssueRef::ShortId(ShortId(3))], DependencyAction::Add);
+        use_case.execute(IssueRef::ShortId(ShortId(4)), &[IssueRef::ShortId(ShortId(4))], DependencyAction::Add);
+
+        let errors = use_case.presenter.errors_presented.borrow();
+        let_assert!([
+            DomainError::DependencyCycle { blocked: ShortId(1), blocker: ShortId(3) },
+            DomainError::DependencyCycle { blocked: ShortId(4), blocker: ShortId(4) },
+        ] = errors.as_slice());
+
+        let stored_board = use_case.storage.load();
+        check!(stored_board.get_with_index(0).blocked_by == []);
+        check!(stored_board.get_with_index(2).blocked_by == [ShortId(2)]);
+    }
+
+    fn given_board_with_short_ids() -> HistorizedBoard<Issue> {
+        let mut board = HistorizedBoard::default().with_4_typical_issues();
+        board.assign_missing_short_ids();
+        board
+    }
+
+    fn given_dependency_use_case_with(board: HistorizedBoard<Issue>) -> DependencyUseCase<MemoryIssueStorage, NilPresenter> {
+        let storage = MemoryIssueStorage::default();
+        storage.save(&board);
+
+        DependencyUseCase {
+            storage,
+            ..Default::default()
+        }
+    }
+}
@@ -1,7 +1,9 @@
 use internal_macros::{PresenterHolder, StorageHolder};
 use crate::adapters::storages::IssueStorage;
+use crate::application::domain::activity::ActivityKind;
 use crate::application::domain::date_parse::DateParser;
 use crate::application::domain::history::{DueHistoryElement, UndoableHistoryElement};
+use crate::application::issue::IssueRef;
 use crate::application::ports::presenter::Presenter;
 use crate::application::ports::time::TodayProvider;
 use crate::application::usecase::usecase::{with_board_saved_and_presented_single_error, HasPresenter, HasStorage};
@@ -14,9 +16,10 @@ pub(crate) struct DueUseCase<I: IssueStorage, P: Presenter, T: TodayProvider> {
 }
 
 impl<I: IssueStorage, P: Presenter, T:TodayProvider> DueUseCase<I, P, T> {
-    pub(crate) fn execute(&self, index: usize, date: Option<&str>) {
+    pub(crate) fn execute(&self, issue: IssueRef, date: Option<&str>) {
         with_board_saved_and_presented_single_error(self, |mut board| {
-            let id = board.find_entity_id_by_index(index)?;
+            let id = board.find_entity_id_by_ref(issue)?;
+            let index = board.position(id);
 
             let date_parser = DateParser {
                 today_provider: &self.today_provider,
@@ -25,7 +28,12 @@ impl<I: IssueStorage, P: Presenter, T:TodayProvider> DueUseCase<I, P, T> {
             let parsed_date = date.map(|d| date_parser.parse(d)).transpose()?;
 
             let previous_due = board.get(id).due_date;
-            board.get_mut(id).due_date = parsed_date;
+            let issue = board.get_mut(id);
+            issue.due_date = parsed_date;
+            issue.record_activity(self.today_provider.now(), ActivityKind::DueChanged {
+                from: previous_due,
+                to: parsed_date,
+            });
 
             let undo_item = UndoableHistoryElement::Due(DueHistoryElement{
                 index,
@@ -52,7 +60,7 @@ mod test {
     use crate::application::domain::historized_board::HistorizedBoard;
     use crate::application::{Issue, State};
     use crate::application::domain::history::{DueHistoryElement, UndoableHistoryElement};
-    use crate::application::issue::Description;
+    use crate::application::issue::{Description, IssueRef};
     use crate::application::usecase::due::{DueUseCase};
     use crate::application::usecase::test_utils::{check_no_errors, get_stored_and_presented_board};
     use crate::application::usecase::usecase::HasPresenter;
@@ -63,7 +71,7 @@ mod test {
             HistorizedBoard::default().with_4_typical_issues(),
         );
 
-        use_case.execute(1, Some("2025-01-26"));
+        use_case.execute(IssueRef::Index(1), Some("2025-01-26"));
 
         let board = get_stored_and_presented_board(&use_case);
         let issue = board.get_with_index(1);
@@ -79,7 +87,7 @@ mod test {
     fn test_index_error() {
         let use_case = DueUseCase::<MemoryIssueStorage, NilPresenter, FakeTodayProvider>::default();
 
-        use_case.execute(1, None);
+        use_case.execute(IssueRef::Index(1), None);
 
         let errors_presented_cell = use_case.presenter_ref().errors_presented.borrow();
         let error = errors_presented_cell.first().expect("error to be presented");
@@ -91,7 +99,7 @@ mod test {
         let use_case = given_due_usecase_with(
             HistorizedBoard::default().with_issue(due_issue()),
         );
-        use_case.execute(0, None);
+        use_case.execute(IssueRef::Index(0), None);
 
         let stored_board = get_stored_and_presented_board(&use_case);
         let issue = stored_board.get_with_index(0);
@@ -108,7 +116,7 @@ mod test {
         let use_case = given_due_usecase_with(
             HistorizedBoard::default().with_issue(due_issue()),
         );
-        use_case.execute(0, Some("tomorrow"));
+        use_case.execute(IssueRef::Index(0), Some("tomorrow"));
 
         check_no_errors(&use_case);
 
@@ -126,9 +134,10 @@ mod test {
     fn due_issue() -> Issue {
         Issue {
             description: Description::from("due issue"),
-            state: State::Open,
+            state: State::from("Open"),
             due_date: Some(date!(1996-01-16)),
             time_created: DEFAULT_FAKE_TODAY,
+            ..Default::default()
         }
     }
 
@@ -2,36 +2,45 @@ use internal_macros::{PresenterHolder, StorageHolder};
 use crate::application::ports::issue_storage::IssueStorage;
 use crate::application::ports::presenter::Presenter;
 use crate::application::ports::editor::Editor;
+use crate::application::ports::time::TodayProvider;
+use crate::application::domain::activity::ActivityKind;
 use crate::application::domain::error::{DomainError};
 use crate::application::domain::history::{EditHistoryElement, UndoableHistoryElement};
+use crate::application::issue::IssueRef;
 use crate::application::usecase::usecase::{with_board_saved_and_presented_single_error, HasPresenter, HasStorage};
 
 #[derive(Default, StorageHolder, PresenterHolder)]
-pub(crate) struct EditUseCase<I: IssueStorage, P: Presenter, E: Editor> {
+pub(crate) struct EditUseCase<I: IssueStorage, P: Presenter, E: Editor, T: TodayProvider> {
     storage: I,
     presenter: P,
     editor: E,
+    time_provider: T,
 }
 
-impl<I: IssueStorage, P: Presenter, E: Editor> EditUseCase<I, P, E> {
-    pub(crate) fn execute(&mut self, index: usize) {
+impl<I: IssueStorage, P: Presenter, E: Editor, T: TodayProvider> EditUseCase<I, P, E, T> {
+    pub(crate) fn execute(&mut self, issue: IssueRef) {
         with_board_saved_and_presented_single_error(self, |mut board| {
-            let id = board.find_entity_id_by_index(index)?;
+            let id = board.find_entity_id_by_ref(issue)?;
+            let index = board.position(id);
 
             let entity = board.get(id);
 
             let original_description = String::from(entity.description.as_str());
+            let original_body = entity.body.clone();
 
-            let edited_description = self.editor
-                .open_editor_with( entity.description.as_str())
+            // The first line of the text is the description, the rest is the body
+            let edited_text = self.editor
+                .open_editor_with(&entity.text())
                 .map_err(|e|DomainError::from(e))?;
 
             let issue = board.get_mut(id);
-            issue.description.set(&edited_description);
+            issue.set_text(&edited_text);
+            issue.record_activity(self.time_provider.now(), ActivityKind::Edited);
 
             board.history.add(UndoableHistoryElement::Edit(
                 EditHistoryElement {
                     original_description,
+                    original_body,
                     index,
                 }
             ));
@@ -44,15 +53,17 @@ impl<I: IssueStorage, P: Presenter, E: Editor> EditUseCase<I, P, E> {
 #[cfg(test)]
 mod tests {
     use std::io::{Error, ErrorKind};
-    use assert2::let_assert;
+    use assert2::{check, let_assert};
     use time::macros::date;
     use crate::adapters::presenters::nil_presenter::test::NilPresenter;
     use crate::adapters::storages::IssueStorage;
     use crate::adapters::storages::memory_issue_storage::test::MemoryIssueStorage;
+    use crate::adapters::time_providers::fake::FakeTodayProvider;
     use crate::application::{Issue, State};
     use crate::application::domain::error::{DomainError};
     use crate::application::domain::historized_board::HistorizedBoard;
-    use crate::application::issue::Entity;
+    use crate::application::domain::history::{EditHistoryElement, UndoableHistoryElement};
+    use crate::application::issue::{Entity, IssueRef};
     use crate::application::ports::editor::Editor;
     use crate::application::usecase::edit::EditUseCase;
     use crate::application::usecase::test_utils::{check_no_errors, get_stored_and_presented_board};
@@ -63,7 +74,7 @@ mod tests {
             HistorizedBoard::default().with_4_typical_issues(),
         );
 
-        edit_use_case.execute(2);
+        edit_use_case.execute(IssueRef::Index(2));
 
         check_no_errors(&edit_use_case);
 
@@ -74,13 +85,34 @@ mod tests {
 
     }
 
+    #[test]
+    fn test_editing_multi_line_text() {
+        let mut edit_use_case = given_edit_usecase_with::<MultiLineEditor>(
+            HistorizedBoard::default().with_4_typical_issues(),
+        );
+
+        edit_use_case.execute(IssueRef::Index(2));
+
+        check_no_errors(&edit_use_case);
+
+        let stored_board = get_stored_and_presented_board(&edit_use_case);
+        let issue = stored_board.get_with_index(2);
+        check!(issue.description.as_str() == "Task inserted second");
+        check!(issue.body.as_deref() == Some("Acceptance criteria:\n- it works"));
+        check!(stored_board.history.last() == Some(&UndoableHistoryElement::Edit(EditHistoryElement {
+            original_description: String::from("Task inserted second"),
+            original_body: None,
+            index: 2,
+        })));
+    }
+
     #[test]
     fn test_editing_issue_with_index_out_of_range() {
         let mut edit_use_case = given_edit_usecase_with::<TestEditor>(
             HistorizedBoard::default().with_4_typical_issues(),
         );
 
-        edit_use_case.execute(4);
+        edit_use_case.execute(IssueRef::Index(4));
 
         then_edited_board(&edit_use_case)
             .assert_has_original_issues();
@@ -95,7 +127,7 @@ mod tests {
             HistorizedBoard::default().with_4_typical_issues(),
         );
 
-        edit_use_case.execute(3);
+        edit_use_case.execute(IssueRef::Index(3));
 
         then_edited_board(&edit_use_case)
             .assert_issue_count(4)
@@ -105,7 +137,7 @@ mod tests {
         let_assert!([DomainError::EditorError{..}] = cell.as_slice());
     }
 
-    fn then_edited_board<E: Editor>(sut: &EditUseCase<MemoryIssueStorage, NilPresenter, E>) -> HistorizedBoard<Issue> {
+    fn then_edited_board<E: Editor>(sut: &EditUseCase<MemoryIssueStorage, NilPresenter, E, FakeTodayProvider>) -> HistorizedBoard<Issue> {
         sut.storage.load()
     }
 
@@ -121,7 +153,7 @@ mod tests {
         }
 
         fn assert_other_issues_did_not_change(&self) -> &Self {
-            assert_eq!(self.state, State::Review);
+            assert_eq!(self.state, State::from("Review"));
             assert_eq!(self.time_created, date!(2025-02-12));
             self
         }
@@ -136,6 +168,15 @@ mod tests {
         }
     }
 
+    #[derive(Default)]
+    struct MultiLineEditor { }
+
+    impl Editor for MultiLineEditor {
+        fn open_editor_with(&self, text: &str) -> Result<String, Error> {
+            Ok(format!("{}\n\nAcceptance criteria:\n- it works\n", text))
+        }
+    }
+
     #[derive(Default)]
     struct CloseAbruptlyEditor {}
 
@@ -145,7 +186,7 @@ mod tests {
         }
     }
 
-    fn given_edit_usecase_with<E: Editor + Default>(board: HistorizedBoard<Issue>) -> EditUseCase<MemoryIssueStorage, NilPresenter, E> {
+    fn given_edit_usecase_with<E: Editor + Default>(board: HistorizedBoard<Issue>) -> EditUseCase<MemoryIssueStorage, NilPresenter, E, FakeTodayProvider> {
         let storage = MemoryIssueStorage::default();
         storage.save(&board);
 
@@ -0,0 +1,97 @@
+use internal_macros::{PresenterHolder, StorageHolder};
+use crate::application::domain::history::{EstimateHistoryElement, UndoableHistoryElement};
+use crate::application::issue::IssueRef;
+use crate::application::ports::issue_storage::IssueStorage;
+use crate::application::ports::presenter::Presenter;
+use crate::application::usecase::usecase::{with_board_saved_and_presented_single_error, HasPresenter, HasStorage};
+
+#[derive(Default, PresenterHolder, StorageHolder)]
+pub(crate) struct EstimateUseCase<I: IssueStorage, P: Presenter> {
+    storage: I,
+    presenter: P,
+}
+
+impl<I: IssueStorage, P: Presenter> EstimateUseCase<I, P> {
+    /// Sets the estimate of the issue. Without an estimate, the issue becomes unestimated.
+    pub(crate) fn execute(&self, issue: IssueRef, estimate: Option<u32>) {
+        with_board_saved_and_presented_single_error(self, |mut board| {
+            let id = board.find_entity_id_by_ref(issue)?;
+            let index = board.position(id);
+
+            let issue = board.get_mut(id);
+            let original_estimate = issue.estimate;
+            issue.estimate = estimate;
+
+            if estimate != original_estimate {
+                board.history.add(UndoableHistoryElement::Estimate(EstimateHistoryElement {
+                    index,
+                    original_estimate,
+                }));
+            }
+
+            Ok(board)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert2::check;
+    use crate::adapters::presenters::nil_presenter::test::NilPresenter;
+    use crate::adapters::storages::IssueStorage;
+    use crate::adapters::storages::memory_issue_storage::test::MemoryIssueStorage;
+    use crate::application::Issue;
+    use crate::application::domain::historized_board::HistorizedBoard;
+    use crate::application::domain::history::{EstimateHistoryElement, UndoableHistoryElement};
+    use crate::application::issue::IssueRef;
+    use crate::application::usecase::estimate::EstimateUseCase;
+    use crate::application::usecase::test_utils::{check_no_errors, get_stored_and_presented_board};
+
+    #[test]
+    fn test_set_and_clear_estimate() {
+        let use_case = given_estimate_use_case_with(
+            HistorizedBoard::default().with_4_typical_issues(),
+        );
+
+        use_case.execute(IssueRef::Index(2), Some(5));
+
+        check_no_errors(&use_case);
+        let stored_board = get_stored_and_presented_board(&use_case);
+        check!(stored_board.get_with_index(2).estimate == Some(5));
+        check!(stored_board.history.last() == Some(&UndoableHistoryElement::Estimate(EstimateHistoryElement {
+            index: 2,
+            original_estimate: None,
+        })));
+
+        use_case.execute(IssueRef::Index(2), None);
+
+        let stored_board = get_stored_and_presented_board(&use_case);
+        check!(stored_board.get_with_index(2).estimate == None);
+        check!(stored_board.history.last() == Some(&UndoableHistoryElement::Estimate(EstimateHistoryElement {
+            index: 2,
+            original_estimate: Some(5),
+        })));
+    }
+
+    #[test]
+    fn test_same_estimate_is_not_in_history() {
+        let use_case = given_estimate_use_case_with(
+            HistorizedBoard::default().with_4_typical_issues(),
+        );
+
+        use_case.execute(IssueRef::Index(2), None);
+
+        check_no_errors(&use_case);
+        check!(get_stored_and_presented_board(&use_case).history.last() == None);
+    }
+
+    fn given_estimate_use_case_with(board: HistorizedBoard<Issue>) -> EstimateUseCase<MemoryIssueStorage, NilPresenter> {
+        let storage = MemoryIssueStorage::default();
+        storage.save(&board);
+
+        EstimateUseCase {
+            storage,
+            ..Default::default()
+        }
+    }
+}
@@ -0,0 +1,142 @@
+use internal_macros::{PresenterHolder, StorageHolder};
+use crate::application::domain::custom_field::FieldAssignment;
+use crate::application::domain::date_parse::DateParser;
+use crate::application::domain::error::DomainResult;
+use crate::application::domain::history::{FieldsHistoryElement, UndoableHistoryElement};
+use crate::application::issue::IssueRef;
+use crate::application::ports::issue_storage::IssueStorage;
+use crate::application::ports::presenter::Presenter;
+use crate::application::ports::time::TodayProvider;
+use crate::application::usecase::usecase::{with_board_saved_and_presented_single_error, HasPresenter, HasStorage};
+
+#[derive(Default, PresenterHolder, StorageHolder)]
+pub(crate) struct FieldUseCase<I: IssueStorage, P: Presenter, T: TodayProvider> {
+    storage: I,
+    presenter: P,
+    time_provider: T,
+}
+
+impl<I: IssueStorage, P: Presenter, T: TodayProvider> FieldUseCase<I, P, T> {
+    /// Sets the values of custom fields of an issue, or unsets the fields given without a value.
+    /// The fields have to be declared by the board, and the values have to be of their type.
+    pub(crate) fn execute(&self, issue: IssueRef, assignments: &[FieldAssignment]) {
+        with_board_saved_and_presented_single_error(self, |mut board| {
+            let id = board.find_entity_id_by_ref(issue)?;
+            let index = board.position(id);
+
+            let date_parser = DateParser {
+                today_provider: &self.time_provider,
+            };
+            let assignments = assignments.iter()
+                .map(|assignment| board.resolve_field_assignment(assignment, &date_parser))
+                .collect::<DomainResult<Vec<_>>>()?;
+
+            let issue = board.get_mut(id);
+            let original_fields = issue.fields.clone();
+
+            for FieldAssignment { name, value } in assignments {
+                match value {
+                    Some(value) => issue.fields.insert(name, value),
+                    None => issue.fields.remove(&name),
+                };
+            }
+
+            if issue.fields != original_fields {
+                board.history.add(UndoableHistoryElement::Fields(FieldsHistoryElement {
+                    index,
+                    original_fields,
+                }));
+            }
+
+            Ok(board)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert2::{check, let_assert};
+    use crate::adapters::presenters::nil_presenter::test::NilPresenter;
+    use crate::adapters::storages::IssueStorage;
+    use crate::adapters::storages::memory_issue_storage::test::MemoryIssueStorage;
+    use crate::adapters::time_providers::fake::FakeTodayProvider;
+    use crate::application::Issue;
+    use crate::application::domain::custom_field::FieldAssignment;
+    use crate::application::domain::error::DomainError;
+    use crate::application::domain::historized_board::HistorizedBoard;
+    use crate::application::domain::history::{FieldsHistoryElement, UndoableHistoryElement};
+    use crate::application::issue::IssueRef;
+    use crate::application::usecase::field::FieldUseCase;
+    use crate::application::usecase::test_utils::{check_no_errors, get_stored_and_presented_board};
+
+    #[test]
+    fn test_set_and_unset_fields() {
+        let use_case = given_field_use_case_with(
+            HistorizedBoard::default().with_4_typical_issues().with_custom_fields(),
+        );
+
+        use_case.execute(IssueRef::Index(2), &[
+            FieldAssignment::new("Customer", Some("acme")),
+            FieldAssignment::new("environment", Some("PROD")),
+            FieldAssignment::new("deadline", Some("friday")),
+        ]);
+
+        check_no_errors(&use_case);
+        let stored_board = get_stored_and_presented_board(&use_case);
+        let fields = &stored_board.get_with_index(2).fields;
+        check!(fields.get("customer").map(String::as_str) == Some("acme"));
+        check!(fields.get("environment").map(String::as_str) == Some("prod"));
+        check!(fields.get("deadline").map(String::as_str) == Some("2025-02-28"));
+
+        use_case.execute(IssueRef::Index(2), &[FieldAssignment::new("customer", None)]);
+
+        check_no_errors(&use_case);
+        let stored_board = get_stored_and_presented_board(&use_case);
+        let fields = &stored_board.get_with_index(2).fields;
+        check!(fields.get("customer") == None);
+        let_assert!(Some(UndoableHistoryElement::Fields(FieldsHistoryElement { index: 2, original_fields })) = stored_board.history.last());
+        check!(original_fields.get("customer").map(String::as_str) == Some("acme"));
+    }
+
+    #[test]
+    fn test_set_unknown_field() {
+        let use_case = given_field_use_case_with(
+            HistorizedBoard::default().with_4_typical_issues().with_custom_fields(),
+        );
+
+        use_case.execute(IssueRef::Index(2), &[FieldAssignment::new("team", Some("core"))]);
+
+        let errors = use_case.presenter.errors_presented.borrow();
+        let_assert!([DomainError::UnknownField { name, available }] = errors.as_slice());
+        check!(name == "team");
+        check!(available == "customer, ticket, environment, deadline");
+        check!(use_case.storage.load().history.last() == None);
+    }
+
+    #[test]
+    fn test_set_invalid_value_changes_nothing() {
+        let use_case = given_field_use_case_with(
+            HistorizedBoard::default().with_4_typical_issues().with_custom_fields(),
+        );
+
+        use_case.execute(IssueRef::Index(2), &[
+            FieldAssignment::new("customer", Some("acme")),
+            FieldAssignment::new("ticket", Some("ABC-12")),
+        ]);
+
+        let errors = use_case.presenter.errors_presented.borrow();
+        let_assert!([DomainError::InvalidFieldValue { field, .. }] = errors.as_slice());
+        check!(field == "ticket");
+        check!(use_case.storage.load().get_with_index(2).fields.is_empty());
+    }
+
+    fn given_field_use_case_with(board: HistorizedBoard<Issue>) -> FieldUseCase<MemoryIssueStorage, NilPresenter, FakeTodayProvider> {
+        let storage = MemoryIssueStorage::default();
+        storage.save(&board);
+
+        FieldUseCase {
+            storage,
+            ..Default::default()
+        }
+    }
+}
@@ -27,7 +27,8 @@ impl<I: IssueStorage, P: Presenter> FlushUseCase<I, P> {
 #[cfg(test)]
 mod tests {
     use assert2::{check, let_assert};
-    use crate::application::Issue;
+    use crate::application::{Issue, State};
+    use crate::adapters::time_providers::fake::DEFAULT_FAKE_TODAY;
     use crate::adapters::presenters::nil_presenter::test::NilPresenter;
     use crate::adapters::storages::IssueStorage;
     use crate::adapters::storages::memory_issue_storage::test::MemoryIssueStorage;
@@ -64,6 +65,31 @@ mod tests {
         let_assert!(Some(UndoableHistoryElement::Flush(FlushHistoryElement{ number_of_issues_affected: 3 })) = stored_board.history.last());
     }
 
+    #[test]
+    fn test_flush_keeps_issues_of_last_custom_column() {
+        let mut sut = given_flush_use_case_with(
+            HistorizedBoard::default()
+                .with_columns(&["Todo", "Doing", "Shipped"])
+                .with_issue(Issue { description: Description::from("Shipped issue"), state: State::from("Shipped"),
+                    time_created: DEFAULT_FAKE_TODAY,
+                    due_date: None,
+                    ..Default::default()
+                })
+                .with_issue(Issue { description: Description::from("Ongoing issue"), state: State::from("Doing"),
+                    time_created: DEFAULT_FAKE_TODAY,
+                    due_date: None,
+                    ..Default::default()
+                })
+        );
+
+        sut.execute();
+
+        let stored_board = get_stored_and_presented_board(&sut);
+        check!(stored_board.entity_count() == 1);
+        check!(stored_board.get_with_index(0).description == Description::from("Shipped issue"));
+        check!(stored_board.get_deleted_entities().len() == 1);
+    }
+
     fn given_flush_use_case_with(board: HistorizedBoard<Issue>) -> FlushUseCase<MemoryIssueStorage, NilPresenter> {
         let storage = MemoryIssueStorage::default();
         storage.save(&board);
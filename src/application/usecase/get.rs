@@ -1,35 +1,96 @@
 use internal_macros::{PresenterHolder, StorageHolder};
+use crate::application::domain::date_parse::DateParser;
+use crate::application::domain::error::DomainError;
+use crate::application::domain::filter::IssueFilter;
+use crate::application::domain::lanes::LaneGrouping;
+use crate::application::ports::identity::Identity;
 use crate::application::ports::issue_storage::IssueStorage;
 use crate::application::ports::presenter::Presenter;
+use crate::application::ports::time::TodayProvider;
 use crate::application::usecase::usecase::{HasPresenter, HasStorage};
 
 #[derive(Default, StorageHolder, PresenterHolder)]
-pub(crate) struct GetUseCase<I: IssueStorage, P: Presenter> {
+pub(crate) struct GetUseCase<I: IssueStorage, P: Presenter, U: Identity, T: TodayProvider> {
     storage: I,
-    presenter: P
+    presenter: P,
+    identity: U,
+    time_provider: T,
 }
 
-impl<I: IssueStorage, P: Presenter> GetUseCase<I, P> {
-    pub(crate) fn execute(&mut self) {
+impl<I: IssueStorage, P: Presenter, U: Identity, T: TodayProvider> GetUseCase<I, P, U, T> {
+    /// Presents the issues that match the filter. With `mine`, only the issues assigned to the
+    /// current user are presented. With `lanes`, the columns are split into lanes. Issues waiting
+    /// for their wait date are hidden, unless `all` is set. The custom fields of the filter and of
+    /// the lanes have to be declared by the board.
+    pub(crate) fn execute(&mut self, filter: &IssueFilter, lanes: Option<LaneGrouping>, mine: bool, all: bool) {
+        let mut filter = filter.clone();
+        if !all {
+            filter.hide_waiting_on = Some(self.time_provider.today());
+        }
+
+        if mine {
+            match self.identity.current_user() {
+                Some(user) => filter.assignee = Some(user),
+                None => return self.presenter.render_error(&DomainError::UnknownUser),
+            }
+        }
+
         let board = self.storage.load();
-        self.presenter.render_board(&board);
+
+        let date_parser = DateParser {
+            today_provider: &self.time_provider,
+        };
+        match filter.fields.iter().map(|field| board.resolve_field_assignment(field, &date_parser)).collect() {
+            Ok(fields) => filter.fields = fields,
+            Err(error) => return self.presenter.render_error(&error),
+        }
+
+        let lanes = match lanes {
+            Some(LaneGrouping::Field(name)) => match board.find_field(&name) {
+                Ok(field) => Some(LaneGrouping::Field(field.name().to_string())),
+                Err(error) => return self.presenter.render_error(&error),
+            },
+            lanes => lanes,
+        };
+
+        self.presenter.render_filtered_board(&board, &filter, lanes);
     }
 }
 
 #[cfg(test)]
 mod tests {
     use std::cell::{RefCell};
+    use assert2::{check, let_assert};
+    use crate::application::Issue;
+    use crate::application::domain::custom_field::FieldAssignment;
+    use crate::application::domain::error::DomainError;
+    use crate::application::issue::{Assignee, Tag};
+    use crate::application::ports::identity::Identity;
     use crate::adapters::presenters::nil_presenter::test::NilPresenter;
     use crate::adapters::storages::memory_issue_storage::test::MemoryIssueStorage;
+    use crate::adapters::time_providers::fake::{FakeTodayProvider, DEFAULT_FAKE_TODAY};
     use crate::application::board::test_utils::check_boards_are_equal;
+    use crate::application::domain::filter::IssueFilter;
+    use crate::application::domain::lanes::LaneGrouping;
     use crate::application::domain::historized_board::HistorizedBoard;
     use crate::application::usecase::get::GetUseCase;
     use crate::application::usecase::test_utils::get_stored_and_presented_board;
 
+    #[derive(Default)]
+    struct TestIdentity {
+        user: Option<Assignee>,
+    }
+
+    impl Identity for TestIdentity {
+        fn current_user(&self) -> Option<Assignee> {
+            self.user.clone()
+        }
+    }
+
     #[test]
     fn test_get_usecase_on_typical_board() {
         // ToDo: this is done somewhere different?
-        let mut get_use_case = GetUseCase::<_, NilPresenter> {
+        let mut get_use_case = GetUseCase::<_, NilPresenter, TestIdentity, FakeTodayProvider> {
             storage: MemoryIssueStorage {
                 board: RefCell::new(HistorizedBoard::default().with_4_typical_issues()),
             },
@@ -37,9 +98,118 @@ mod tests {
             ..Default::default()
         };
 
-        get_use_case.execute();
+        get_use_case.execute(&IssueFilter::default(), None, false, false);
 
         let stored_board = get_stored_and_presented_board(&get_use_case);
         check_boards_are_equal(&stored_board, &HistorizedBoard::default().with_4_typical_issues());
     }
+
+    #[test]
+    fn test_get_mine() {
+        let mut get_use_case = GetUseCase::<MemoryIssueStorage, NilPresenter, TestIdentity, FakeTodayProvider> {
+            identity: TestIdentity {
+                user: Some(Assignee::from("alice")),
+            },
+            ..Default::default()
+        };
+        let filter = IssueFilter {
+            tags: vec![Tag::from("backend")],
+            ..Default::default()
+        };
+
+        get_use_case.execute(&filter, None, true, false);
+
+        check!(*get_use_case.presenter.last_filter_used.borrow() == Some(IssueFilter {
+            tags: vec![Tag::from("backend")],
+            assignee: Some(Assignee::from("alice")),
+            hide_waiting_on: Some(DEFAULT_FAKE_TODAY),
+            parent: None,
+            fields: vec![],
+        }));
+    }
+
+    #[test]
+    fn test_get_in_lanes() {
+        let mut get_use_case = GetUseCase::<MemoryIssueStorage, NilPresenter, TestIdentity, FakeTodayProvider>::default();
+
+        get_use_case.execute(&IssueFilter::default(), Some(LaneGrouping::Assignee), false, false);
+
+        check!(*get_use_case.presenter.last_lanes_used.borrow() == Some(LaneGrouping::Assignee));
+    }
+
+    #[test]
+    fn test_get_all_shows_waiting_issues() {
+        let mut get_use_case = GetUseCase::<MemoryIssueStorage, NilPresenter, TestIdentity, FakeTodayProvider>::default();
+
+        get_use_case.execute(&IssueFilter::default(), None, false, true);
+
+        check!(*get_use_case.presenter.last_filter_used.borrow() == Some(IssueFilter::default()));
+    }
+
+    #[test]
+    fn test_get_mine_without_user() {
+        let mut get_use_case = GetUseCase::<MemoryIssueStorage, NilPresenter, TestIdentity, FakeTodayProvider>::default();
+
+        get_use_case.execute(&IssueFilter::default(), None, true, false);
+
+        let errors = get_use_case.presenter.errors_presented.borrow();
+        let_assert!([DomainError::UnknownUser] = errors.as_slice());
+        check!(*get_use_case.presenter.last_filter_used.borrow() == None);
+    }
+
+    #[test]
+    fn test_get_by_fields() {
+        let mut get_use_case = given_get_use_case_with(HistorizedBoard::default().with_custom_fields());
+        let filter = IssueFilter {
+            fields: vec![FieldAssignment::new("Environment", Some("PROD")), FieldAssignment::new("customer", None)],
+            ..Default::default()
+        };
+
+        get_use_case.execute(&filter, None, false, true);
+
+        check!(get_use_case.presenter.errors_presented.borrow().is_empty());
+        check!(*get_use_case.presenter.last_filter_used.borrow() == Some(IssueFilter {
+            fields: vec![FieldAssignment::new("environment", Some("prod")), FieldAssignment::new("customer", None)],
+            ..Default::default()
+        }));
+    }
+
+    #[test]
+    fn test_get_by_invalid_field_value() {
+        let mut get_use_case = given_get_use_case_with(HistorizedBoard::default().with_custom_fields());
+        let filter = IssueFilter {
+            fields: vec![FieldAssignment::new("ticket", Some("ABC-12"))],
+            ..Default::default()
+        };
+
+        get_use_case.execute(&filter, None, false, false);
+
+        let errors = get_use_case.presenter.errors_presented.borrow();
+        let_assert!([DomainError::InvalidFieldValue { .. }] = errors.as_slice());
+        check!(*get_use_case.presenter.last_filter_used.borrow() == None);
+    }
+
+    #[test]
+    fn test_get_in_lanes_by_field() {
+        let mut get_use_case = given_get_use_case_with(HistorizedBoard::default().with_custom_fields());
+
+        get_use_case.execute(&IssueFilter::default(), Some(LaneGrouping::Field(String::from("Customer"))), false, false);
+
+        check!(get_use_case.presenter.errors_presented.borrow().is_empty());
+        check!(*get_use_case.presenter.last_lanes_used.borrow() == Some(LaneGrouping::Field(String::from("customer"))));
+
+        get_use_case.execute(&IssueFilter::default(), Some(LaneGrouping::Field(String::from("team"))), false, false);
+
+        let errors = get_use_case.presenter.errors_presented.borrow();
+        let_assert!([DomainError::UnknownField { .. }] = errors.as_slice());
+    }
+
+    fn given_get_use_case_with(board: HistorizedBoard<Issue>) -> GetUseCase<MemoryIssueStorage, NilPresenter, TestIdentity, FakeTodayProvider> {
+        GetUseCase {
+            storage: MemoryIssueStorage {
+                board: RefCell::new(board),
+            },
+            ..Default::default()
+        }
+    }
 }
@@ -0,0 +1,99 @@
+use internal_macros::{PresenterHolder, StorageHolder};
+use crate::application::domain::error::DomainError;
+use crate::application::domain::history::{LinkHistoryElement, UndoableHistoryElement};
+use crate::application::issue::{IssueRef, Link};
+use crate::application::ports::issue_storage::IssueStorage;
+use crate::application::ports::presenter::Presenter;
+use crate::application::usecase::usecase::{with_board_saved_and_presented_single_error, HasPresenter, HasStorage};
+
+#[derive(Default, PresenterHolder, StorageHolder)]
+pub(crate) struct LinkUseCase<I: IssueStorage, P: Presenter> {
+    storage: I,
+    presenter: P,
+}
+
+impl<I: IssueStorage, P: Presenter> LinkUseCase<I, P> {
+    /// Adds a link (a URL or a file path) to the end of the links of the issue.
+    pub(crate) fn execute(&self, issue: IssueRef, target: &str, label: Option<&str>) {
+        with_board_saved_and_presented_single_error(self, |mut board| {
+            let link = Link::new(target, label);
+            if link.target.is_empty() {
+                return Err(DomainError::EmptyLink);
+            }
+
+            let id = board.find_entity_id_by_ref(issue)?;
+            let index = board.position(id);
+
+            let issue = board.get_mut(id);
+            let original_links = issue.links.clone();
+            issue.links.push(link);
+
+            board.history.add(UndoableHistoryElement::Link(LinkHistoryElement {
+                index,
+                original_links,
+            }));
+
+            Ok(board)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert2::{check, let_assert};
+    use crate::adapters::presenters::nil_presenter::test::NilPresenter;
+    use crate::adapters::storages::IssueStorage;
+    use crate::adapters::storages::memory_issue_storage::test::MemoryIssueStorage;
+    use crate::application::Issue;
+    use crate::application::domain::error::DomainError;
+    use crate::application::domain::historized_board::HistorizedBoard;
+    use crate::application::domain::history::{LinkHistoryElement, UndoableHistoryElement};
+    use crate::application::issue::{IssueRef, Link};
+    use crate::application::usecase::link::LinkUseCase;
+    use crate::application::usecase::test_utils::{check_no_errors, get_stored_and_presented_board};
+
+    #[test]
+    fn test_link() {
+        let use_case = given_link_use_case_with(
+            HistorizedBoard::default().with_4_typical_issues(),
+        );
+
+        use_case.execute(IssueRef::Index(1), "https://example.com/pr/42", Some("PR"));
+        use_case.execute(IssueRef::Index(1), " ~/designs/login.pdf ", None);
+
+        check_no_errors(&use_case);
+        let stored_board = get_stored_and_presented_board(&use_case);
+        check!(stored_board.get_with_index(1).links == vec![
+            Link::new("https://example.com/pr/42", Some("PR")),
+            Link::new("~/designs/login.pdf", None),
+        ]);
+        check!(stored_board.get_with_index(1).links[1].target == "~/designs/login.pdf");
+        check!(stored_board.history.last() == Some(&UndoableHistoryElement::Link(LinkHistoryElement {
+            index: 1,
+            original_links: vec![Link::new("https://example.com/pr/42", Some("PR"))],
+        })));
+    }
+
+    #[test]
+    fn test_empty_link_is_refused() {
+        let use_case = given_link_use_case_with(
+            HistorizedBoard::default().with_4_typical_issues(),
+        );
+
+        use_case.execute(IssueRef::Index(1), "  ", Some("PR"));
+
+        let errors = use_case.presenter.errors_presented.borrow();
+        let_assert!([DomainError::EmptyLink] = errors.as_slice());
+        check!(use_case.storage.load().history.last() == None);
+    }
+
+    fn given_link_use_case_with(board: HistorizedBoard<Issue>) -> LinkUseCase<MemoryIssueStorage, NilPresenter> {
+        let storage = MemoryIssueStorage::default();
+        storage.save(&board);
+
+        LinkUseCase {
+            storage,
+            ..Default::default()
+        }
+    }
+}
@@ -7,6 +7,26 @@ pub mod get;
 pub mod undo;
 pub mod flush;
 pub mod due;
+pub mod tag;
+pub mod checklist;
+pub mod dependency;
+pub mod note;
+pub mod show;
+pub mod comment;
+pub mod recur;
+pub mod estimate;
+pub mod track;
+pub mod report;
+pub mod boards;
+pub mod transfer;
+pub mod assign;
+pub mod priority_level;
+pub mod archive;
+pub mod link;
+pub mod open;
+pub mod block;
+pub mod parent;
+pub mod field;
 #[cfg(test)]
 pub mod test_utils;
 pub mod usecase;
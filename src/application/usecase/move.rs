@@ -1,69 +1,272 @@
 use internal_macros::{PresenterHolder, StorageHolder};
+use itertools::Itertools;
+use nonempty_collections::NEVec;
+use time::OffsetDateTime;
 use uuid::Uuid;
+use crate::application::domain::activity::ActivityKind;
+use crate::application::domain::column::Column;
+use crate::application::domain::date_parse::DateParser;
 use crate::application::domain::history::{MoveHistoryElement, MoveHistoryElements, UndoableHistoryElement};
 use crate::application::Issue;
+use crate::application::domain::error::{DomainError, DomainResult, DomainResultMultiError};
+use crate::application::issue::{ChecklistItem, IssueRef};
 use crate::application::domain::historized_board::HistorizedBoard;
 use crate::application::ports::issue_storage::IssueStorage;
 use crate::application::ports::presenter::Presenter;
+use crate::application::ports::time::TodayProvider;
 use crate::application::State;
+use crate::application::usecase::archive::archive_issues;
 use crate::application::usecase::usecase::{HasStorage, HasPresenter, with_board_saved_and_presented_multi_error};
 
 
 #[derive(Default, StorageHolder, PresenterHolder)]
-pub(crate) struct MoveUseCase<I: IssueStorage, P: Presenter> {
+pub(crate) struct MoveUseCase<I: IssueStorage, P: Presenter, T: TodayProvider> {
     storage: I,
     presenter: P,
+    time_provider: T,
 }
 
-impl<I: IssueStorage, P: Presenter> MoveUseCase<I, P> {
-    pub(crate) fn execute(&mut self, indices: &[usize], state: State) {
+impl<I: IssueStorage, P: Presenter, T: TodayProvider> MoveUseCase<I, P, T> {
+    /// Moves the issues to `state`. Unless `force` is set, the WIP limit of the column and the
+    /// allowed transitions of the board are respected. A forced move that breaks the allowed
+    /// transitions is recorded in the activity of the issue and in the history of the board.
+    /// Parents moved to done before their children are moved anyway, with a warning.
+    ///
+    /// When a recurring issue is moved to done, its next occurrence is added to the board. If the
+    /// board archives done issues after some days, the other issues done for long enough are
+    /// archived before the move. The archiving is undone separately, after the move.
+    pub(crate) fn execute(&mut self, issues: &[IssueRef], state: State, force: bool) {
+        let mut warnings = Vec::new();
+
         with_board_saved_and_presented_multi_error(self, |mut board| {
-            let ids = board.find_entities_by_indices(indices)?;
+            let state = board.columns().resolve(&state).map_err(NEVec::new)?;
+            let ids = board.find_entities_by_refs(issues)?;
+
+            if let Some(days) = board.archive_after_days() {
+                let done_for_long = board.done_for_days(days, self.time_provider.today()).into_iter()
+                    .filter(|id| !ids.contains(id))
+                    .collect();
+                archive_issues(&mut board, done_for_long);
+            }
+
+            let open_children = if board.columns().is_done(&state) {
+                IssueMover::<T>::find_open_children(&board, &ids)
+            } else {
+                Vec::new()
+            };
+
+            let issue_mover = IssueMover {
+                time_provider: &self.time_provider,
+            };
+            issue_mover.move_issues(&mut board, ids, state, force)?;
+
+            warnings = open_children;
+            Ok(board)
+        });
+
+        for warning in &warnings {
+            self.presenter.render_warning(warning);
+        }
+    }
+}
 
-            let history_for_undo = ids.into_iter()
-                .map(|id| Self::move_issue(&mut board, id, state))
-                .flatten()
-                .collect();
+/// Moves issues between the columns of a board, and records the moves in the history of the board.
+/// Every command that moves issues goes through it, so that they obey the same rules.
+pub(crate) struct IssueMover<'a, T: TodayProvider> {
+    pub time_provider: &'a T,
+}
 
-            Self::update_history(&mut board, history_for_undo);
+impl<T: TodayProvider> IssueMover<'_, T> {
+    /// Moves the issues with `ids` to `state`, which has to be a column of the board. Unless
+    /// `force` is set, the WIP limit of the column and the allowed transitions are respected.
+    pub(crate) fn move_issues(&self, board: &mut HistorizedBoard<Issue>, ids: Vec<Uuid>, state: State, force: bool) -> DomainResultMultiError<()> {
+        if !force {
+            let incoming = ids.iter()
+                .unique()
+                .filter(|&&id| board.get(id).state != state)
+                .count();
+            board.check_wip_limit(&state, incoming).map_err(NEVec::new)?;
+            Self::check_transitions_allowed(board, &ids, &state)?;
+        }
 
-            Ok(board)
-        })
+        if board.columns().is_done(&state) {
+            Self::check_not_blocked(board, &ids)?;
+        }
+
+        let now = self.time_provider.now();
+        let moves = ids.into_iter()
+            .filter_map(|id| Self::move_issue(board, id, state.clone(), now, force)
+                .map(|history_element| (id, history_element)))
+            .collect::<Vec<_>>();
+
+        let created_occurrences = if board.columns().is_done(&state) {
+            self.add_next_occurrences(board, moves.iter().map(|&(id, _)| id))
+                .map_err(NEVec::new)?
+        } else {
+            0
+        };
+
+        Self::update_history(board, moves.into_iter().map(|(_, h)| h).collect(), created_occurrences);
+
+        Ok(())
+    }
+
+    /// Issues can leave their column only to the states the column allows.
+    fn check_transitions_allowed(board: &HistorizedBoard<Issue>, ids: &[Uuid], state: &State) -> DomainResultMultiError<()> {
+        let mut errors = ids.iter()
+            .filter_map(|&id| board.columns().find(&board.get(id).state))
+            .filter(|column| !column.allows_transition_to(state))
+            .unique_by(|column| column.state())
+            .map(|column| DomainError::TransitionNotAllowed {
+                from: column.name().to_string(),
+                to: state.to_string(),
+                allowed: match column.allowed_transitions().unwrap_or_default() {
+                    [] => String::from("none"),
+                    allowed => allowed.iter().map(|target| format!("`{}`", target)).join(", "),
+                },
+            });
+
+        if let Some(head) = errors.next() {
+            return Err(NEVec::from((head, errors.collect())));
+        }
+
+        Ok(())
+    }
+
+    /// Issues can be done only if their blockers are done, or are moved to done together with them.
+    fn check_not_blocked(board: &HistorizedBoard<Issue>, ids: &[Uuid]) -> DomainResultMultiError<()> {
+        let moved_short_ids = ids.iter()
+            .filter_map(|&id| board.get(id).short_id)
+            .collect::<Vec<_>>();
+
+        let mut errors = ids.iter()
+            .filter_map(|&id| {
+                let blockers = board.open_blockers(id).into_iter()
+                    .filter(|blocker| !moved_short_ids.contains(blocker))
+                    .map(|blocker| format!("`{}`", blocker))
+                    .collect::<Vec<_>>();
+
+                match (board.get(id).short_id, blockers.is_empty()) {
+                    (Some(issue), false) => Some(DomainError::BlockedIssue { issue, blockers: blockers.join(", ") }),
+                    _ => None,
+                }
+            });
+
+        if let Some(head) = errors.next() {
+            return Err(NEVec::from((head, errors.collect())));
+        }
+
+        Ok(())
+    }
+
+    /// The parents among the issues with `ids` whose children are neither done nor moved together
+    /// with them
+    pub(crate) fn find_open_children(board: &HistorizedBoard<Issue>, ids: &[Uuid]) -> Vec<DomainError> {
+        let moved_short_ids = ids.iter()
+            .filter_map(|&id| board.get(id).short_id)
+            .collect::<Vec<_>>();
+
+        ids.iter()
+            .unique()
+            .filter_map(|&id| {
+                let children = board.open_children(id).into_iter()
+                    .filter(|child| !moved_short_ids.contains(child))
+                    .map(|child| format!("`{}`", child))
+                    .collect::<Vec<_>>();
+
+                match (board.get(id).short_id, children.is_empty()) {
+                    (Some(issue), false) => Some(DomainError::OpenChildren { issue, children: children.join(", ") }),
+                    _ => None,
+                }
+            })
+            .collect()
+    }
+
+    /// Adds the next occurrence of the recurring issues among `done_ids` on the top of the board.
+    /// Returns the number of issues added.
+    fn add_next_occurrences(&self, board: &mut HistorizedBoard<Issue>, done_ids: impl Iterator<Item = Uuid>) -> DomainResult<usize> {
+        let date_parser = DateParser {
+            today_provider: self.time_provider,
+        };
+
+        let next_occurrences = done_ids
+            .filter_map(|id| board.get(id).recurrence.map(|recurrence| (id, recurrence)))
+            .map(|(id, recurrence)| {
+                let done_issue = board.get(id);
+                Ok(Issue {
+                    due_date: Some(date_parser.next_occurrence(&recurrence)?),
+                    tags: done_issue.tags.clone(),
+                    checklist: done_issue.checklist.iter()
+                        .map(|item| ChecklistItem::new(&item.text))
+                        .collect(),
+                    body: done_issue.body.clone(),
+                    recurrence: Some(recurrence),
+                    estimate: done_issue.estimate,
+                    assignee: done_issue.assignee.clone(),
+                    priority: done_issue.priority,
+                    links: done_issue.links.clone(),
+                    parent: done_issue.parent,
+                    fields: done_issue.fields.clone(),
+                    ..Issue::new(done_issue.description.clone(), board.columns().first_state(), self.time_provider)
+                })
+            })
+            .collect::<DomainResult<Vec<_>>>()?;
+
+        let count = next_occurrences.len();
+        for mut issue in next_occurrences {
+            issue.short_id = Some(board.next_short_id());
+            board.append_entity(issue);
+        }
+
+        Ok(count)
     }
 
-    fn update_history(board: &mut HistorizedBoard<Issue>, history_elements: Vec<MoveHistoryElement>) {
+    fn update_history(board: &mut HistorizedBoard<Issue>, history_elements: Vec<MoveHistoryElement>, created_occurrences: usize) {
         if !history_elements.is_empty() {
             board.history.add(UndoableHistoryElement::Move(MoveHistoryElements {
                 moves: history_elements,
+                created_occurrences,
             }));
         }
     }
 
-    fn move_issue(board: &mut HistorizedBoard<Issue>, id: Uuid, state: State) -> Option<MoveHistoryElement> {
-        let issue = board.get_mut(id);
-
-        if issue.state == state {
+    fn move_issue(board: &mut HistorizedBoard<Issue>, id: Uuid, state: State, now: OffsetDateTime, force: bool) -> Option<MoveHistoryElement> {
+        if board.get(id).state == state {
             return None
         }
 
-        let original_state = issue.state;
-        issue.state = state;
+        let forced = force && board.columns().find(&board.get(id).state)
+            .is_some_and(|column| !column.allows_transition_to(&state));
+
+        let issue = board.get_mut(id);
+
+        let original_state = issue.state.clone();
+        issue.state = state.clone();
+        issue.record_activity(now, ActivityKind::Moved {
+            from: original_state.clone(),
+            to: state.clone(),
+        });
+        if forced {
+            issue.record_activity(now, ActivityKind::TransitionForced {
+                from: original_state.clone(),
+                to: state.clone(),
+            });
+        }
 
         let original_index = board.position(id);
 
         board.prio_top_in_category(id);
-
-        // If issue is moved to done, I'd like to see it on the top
-        let new_index = if state == State::Done {
-            board.position(id)
-        } else {
-            original_index
-        };
+        if board.columns().find(&state).is_some_and(Column::sorts_by_priority) {
+            board.prio_by_level_in_category(id);
+        }
+        // The issue goes to the top of its new column, so its position changes even when it is not done
+        let new_index = board.position(id);
 
         Some(MoveHistoryElement {
             original_state,
             original_index,
-            new_index
+            new_index,
+            forced,
         })
     }
 }
@@ -75,13 +278,17 @@ mod tests {
     use crate::adapters::presenters::nil_presenter::test::NilPresenter;
     use crate::adapters::storages::IssueStorage;
     use crate::adapters::storages::memory_issue_storage::test::MemoryIssueStorage;
-    use crate::adapters::time_providers::fake::DEFAULT_FAKE_TODAY;
+    use crate::adapters::time_providers::fake::{FakeTodayProvider, DEFAULT_FAKE_TODAY};
+    use time::macros::{date, datetime};
+    use time::Weekday;
+    use crate::application::domain::date_parse::Recurrence;
+    use crate::application::domain::activity::{ActivityEvent, ActivityKind};
     use crate::application::domain::error::DomainError;
     use crate::application::domain::historized_board::HistorizedBoard;
     use crate::application::domain::history::{MoveHistoryElement, MoveHistoryElements, UndoableHistoryElement};
-    use crate::application::issue::Description;
+    use crate::application::issue::{ChecklistItem, Description, IssueRef, Priority, ShortId, Tag};
     use crate::application::usecase::r#move::MoveUseCase;
-    use crate::application::usecase::test_utils::get_stored_and_presented_board;
+    use crate::application::usecase::test_utils::{check_no_errors, get_stored_and_presented_board};
 
     #[test]
     fn test_successful_move_use_case() {
@@ -89,12 +296,12 @@ mod tests {
             HistorizedBoard::default().with_4_typical_issues(),
         );
 
-        move_use_case.execute(&vec![1, 0], State::Done);
+        move_use_case.execute(&[IssueRef::Index(1), IssueRef::Index(0)], State::from("Done"), false);
 
         let stored_board = get_stored_and_presented_board(&move_use_case);
         for index in 0..1 {
             let issue = stored_board.get(stored_board.find_entity_id_by_index(index).unwrap());
-            check!(issue.state == State::Done);
+            check!(issue.state == State::from("Done"));
 
         }
 
@@ -102,14 +309,83 @@ mod tests {
                    &UndoableHistoryElement::Move(MoveHistoryElements {
                        moves: vec![
                            MoveHistoryElement {
-                               original_state: State::Open,
+                               original_state: State::from("Open"),
                                original_index: 0,
                                new_index: 0,
+                               forced: false,
                            },
-                       ]
+                       ],
+                       created_occurrences: 0,
                    }), "Expected a history element with specific content");
     }
 
+    #[test]
+    fn test_move_records_activity() {
+        let mut move_use_case = given_move_use_case_with(
+            HistorizedBoard::default().with_4_typical_issues(),
+        );
+
+        move_use_case.execute(&[IssueRef::Index(3)], State::from("Done"), false);
+
+        let stored_board = get_stored_and_presented_board(&move_use_case);
+        let_assert!(Some(issue) = stored_board.entities().iter().find(|i| i.description == Description::from("Task inserted first")));
+        check!(issue.activity == vec![
+            ActivityEvent::new(datetime!(2025-02-22 10:00 UTC), ActivityKind::Moved {
+                from: State::from("Open"),
+                to: State::from("Done"),
+            }),
+        ]);
+    }
+
+    #[test]
+    fn test_done_recurring_issue_adds_next_occurrence() {
+        let mut board = HistorizedBoard::default().with_issue(Issue {
+            description: Description::from("Weekly report"),
+            state: State::from("Review"),
+            due_date: Some(date!(2025-02-17)),
+            tags: vec![Tag::from("chore")],
+            checklist: vec![ChecklistItem { text: String::from("collect numbers"), done: true }],
+            recurrence: Some(Recurrence::Weekday(Weekday::Monday)),
+            ..Default::default()
+        }).with_4_typical_issues();
+        board.assign_missing_short_ids();
+        let mut move_use_case = given_move_use_case_with(board);
+
+        move_use_case.execute(&[IssueRef::ShortId(ShortId(1))], State::from("Done"), false);
+
+        check_no_errors(&move_use_case);
+        let stored_board = get_stored_and_presented_board(&move_use_case);
+        let next_occurrence = stored_board.get_with_index(0);
+        check!(next_occurrence.description == Description::from("Weekly report"));
+        check!(next_occurrence.state == State::from("Open"));
+        check!(next_occurrence.time_created == DEFAULT_FAKE_TODAY);
+        check!(next_occurrence.due_date == Some(date!(2025-02-24)));
+        check!(next_occurrence.tags == vec![Tag::from("chore")]);
+        check!(next_occurrence.checklist == vec![ChecklistItem::new("collect numbers")]);
+        check!(next_occurrence.recurrence == Some(Recurrence::Weekday(Weekday::Monday)));
+        check!(next_occurrence.short_id == Some(ShortId(6)));
+
+        let_assert!(Some(UndoableHistoryElement::Move(MoveHistoryElements { created_occurrences: 1, .. })) = stored_board.history.last());
+        check!(stored_board.entities().len() == 6);
+    }
+
+    #[test]
+    fn test_recurring_issue_not_moved_to_done_does_not_recur() {
+        let mut move_use_case = given_move_use_case_with(
+            HistorizedBoard::default().with_issue(Issue {
+                description: Description::from("Weekly report"),
+                state: State::from("Open"),
+                recurrence: Some(Recurrence::Days(1)),
+                ..Default::default()
+            }),
+        );
+
+        move_use_case.execute(&[IssueRef::Index(0)], State::from("Review"), false);
+
+        let stored_board = get_stored_and_presented_board(&move_use_case);
+        check!(stored_board.entities().len() == 1);
+    }
+
     /// Tests whether the issue goes on the top of the done list, when being moved there.
     #[test]
     fn test_move_done_results_in_prio_top() {
@@ -117,14 +393,14 @@ mod tests {
             HistorizedBoard::default().with_4_typical_issues(),
         );
 
-        move_use_case.execute(&vec![3], State::Done);
+        move_use_case.execute(&[IssueRef::Index(3)], State::from("Done"), false);
 
         let stored_board = get_stored_and_presented_board(&move_use_case);
 
         for (index, expected_description) in [(1, "Task inserted first"), (2, "Task inserted third")] {
             let issue = stored_board.get(stored_board.find_entity_id_by_index(index).unwrap());
             check!(issue.description.as_str() == expected_description);
-            check!(issue.state == State::Done);
+            check!(issue.state == State::from("Done"));
 
         }
 
@@ -132,15 +408,107 @@ mod tests {
                    &UndoableHistoryElement::Move(MoveHistoryElements {
                        moves: vec![
                            MoveHistoryElement {
-                               original_state: State::Open,
+                               original_state: State::from("Open"),
                                original_index: 3,
                                new_index: 1,
+                               forced: false,
                            },
-                       ]
+                       ],
+                       created_occurrences: 0,
                    }), "Expected a history element with specific content");
 
     }
 
+    /// Undo takes the issue from `new_index` back to `original_index`. The issue goes to the top
+    /// of any column it is moved to, so recording `original_index` as `new_index` made undo reset
+    /// the state of whichever issue ended up at the original index.
+    #[test]
+    fn test_move_to_other_column_records_new_index() {
+        let mut move_use_case = given_move_use_case_with(
+            HistorizedBoard::default().with_4_typical_issues(),
+        );
+
+        move_use_case.execute(&[IssueRef::Index(3)], State::from("Review"), false);
+
+        let stored_board = get_stored_and_presented_board(&move_use_case);
+        let issue = stored_board.get(stored_board.find_entity_id_by_index(2).unwrap());
+        check!(issue.description.as_str() == "Task inserted first");
+        check!(issue.state == State::from("Review"));
+        check!(stored_board.history.last() == Some(&UndoableHistoryElement::Move(MoveHistoryElements {
+            moves: vec![
+                MoveHistoryElement {
+                    original_state: State::from("Open"),
+                    original_index: 3,
+                    new_index: 2,
+                    forced: false,
+                },
+            ],
+            created_occurrences: 0,
+        })));
+    }
+
+    #[test]
+    fn test_move_to_column_sorted_by_priority() {
+        let mut move_use_case = given_move_use_case_with(
+            HistorizedBoard::new(vec![
+                Issue { description: Description::from("Critical in review"), state: State::from("Review"), priority: Priority::Critical, ..Default::default() },
+                Issue { description: Description::from("Normal in review"), state: State::from("Review"), ..Default::default() },
+                Issue { description: Description::from("Low in review"), state: State::from("Review"), priority: Priority::Low, ..Default::default() },
+                Issue { description: Description::from("Normal to review"), state: State::from("Open"), ..Default::default() },
+            ], vec![], vec![]).with_sort_by_priority("Review"),
+        );
+
+        move_use_case.execute(&[IssueRef::Index(3)], State::from("Review"), false);
+
+        check_no_errors(&move_use_case);
+        let stored_board = get_stored_and_presented_board(&move_use_case);
+        let descriptions = stored_board.entities().iter().map(|issue| issue.description.as_str()).collect::<Vec<_>>();
+        check!(descriptions == ["Critical in review", "Normal to review", "Normal in review", "Low in review"],
+            "Expected the issue above the issues of the same priority");
+        check!(stored_board.history.last() == Some(&UndoableHistoryElement::Move(MoveHistoryElements {
+            moves: vec![
+                MoveHistoryElement {
+                    original_state: State::from("Open"),
+                    original_index: 3,
+                    new_index: 1,
+                    forced: false,
+                },
+            ],
+            created_occurrences: 0,
+        })));
+    }
+
+    #[test]
+    fn test_move_archives_issues_done_for_long() {
+        let mut board = HistorizedBoard::default().with_4_typical_issues();
+        board.set_archive_after_days(Some(14));
+        let mut move_use_case = given_move_use_case_with(board);
+
+        move_use_case.execute(&[IssueRef::Index(0)], State::from("Done"), false);
+
+        check_no_errors(&move_use_case);
+        let stored_board = get_stored_and_presented_board(&move_use_case);
+        let_assert!([archived] = stored_board.get_archived_entities());
+        check!(archived.description == Description::from("Task inserted third"), "Expected the issue done since 2025-02-03 to be archived");
+        check!(stored_board.get_with_index(0).description == Description::from("Task inserted fourth"), "Expected the moved issue to stay");
+        let_assert!([UndoableHistoryElement::Archive(_), UndoableHistoryElement::Move(_)] = stored_board.history.stack.as_slice(),
+            "Expected the move to be undone first");
+    }
+
+    #[test]
+    fn test_move_does_not_archive_the_moved_issues() {
+        let mut board = HistorizedBoard::default().with_4_typical_issues();
+        board.set_archive_after_days(Some(14));
+        let mut move_use_case = given_move_use_case_with(board);
+
+        move_use_case.execute(&[IssueRef::Index(1)], State::from("Open"), false);
+
+        check_no_errors(&move_use_case);
+        let stored_board = get_stored_and_presented_board(&move_use_case);
+        check!(stored_board.get_archived_entities().is_empty(), "Expected the issue done since 2025-02-03 to be reopened");
+        check!(stored_board.get_with_index(0).description == Description::from("Task inserted third"));
+    }
+
     /// Open
     /// 1. Lazy to do
     /// 2. I'm doing it now, A
@@ -167,35 +535,39 @@ mod tests {
         // Given
         let mut sut = given_move_use_case_with(
             HistorizedBoard::new(vec![
-                Issue { description: Description::from("I finished this first"), state: State::Done,
+                Issue { description: Description::from("I finished this first"), state: State::from("Done"),
                     time_created: DEFAULT_FAKE_TODAY,
                     due_date: None,
+                    ..Default::default()
                 },
-                Issue { description: Description::from("Lazy to do"), state: State::Open,
+                Issue { description: Description::from("Lazy to do"), state: State::from("Open"),
                     time_created: DEFAULT_FAKE_TODAY,
                     due_date: None,
+                    ..Default::default()
                 },
-                Issue { description: Description::from("I'm doing it now, A"), state: State::Open,
+                Issue { description: Description::from("I'm doing it now, A"), state: State::from("Open"),
                     time_created: DEFAULT_FAKE_TODAY,
                     due_date: None,
+                    ..Default::default()
                 }, // Move this second
-                Issue { description: Description::from("I'm doing it now, B"), state: State::Open,
+                Issue { description: Description::from("I'm doing it now, B"), state: State::from("Open"),
                     time_created: DEFAULT_FAKE_TODAY,
                     due_date: None,
+                    ..Default::default()
                 }, // Move this first
             ], vec![], vec![])
         );
 
         // When
-        sut.execute(&[3, 2], State::Done);
+        sut.execute(&[IssueRef::Index(3), IssueRef::Index(2)], State::from("Done"), false);
 
         // Then
         let stored_board = get_stored_and_presented_board(&sut);
         [
-            (0, State::Done, "I'm doing it now, A"),
-            (1, State::Done, "I'm doing it now, B"),
-            (2, State::Done, "I finished this first"),
-            (3, State::Open, "Lazy to do"),
+            (0, State::from("Done"), "I'm doing it now, A"),
+            (1, State::from("Done"), "I'm doing it now, B"),
+            (2, State::from("Done"), "I finished this first"),
+            (3, State::from("Open"), "Lazy to do"),
         ].into_iter().for_each(|(expected_index, expected_state, expected_description)| {
             let issue = stored_board.get(stored_board.find_entity_id_by_index(expected_index).unwrap());
             check!(issue.state == expected_state);
@@ -206,16 +578,19 @@ mod tests {
                    &UndoableHistoryElement::Move(MoveHistoryElements {
                        moves: vec![
                            MoveHistoryElement {
-                               original_state: State::Open,
+                               original_state: State::from("Open"),
                                original_index: 3,
                                new_index: 0,
+                               forced: false,
                            },
                            MoveHistoryElement {
-                               original_state: State::Open,
+                               original_state: State::from("Open"),
                                original_index: 3,
                                new_index: 0,
+                               forced: false,
                            },
-                       ]
+                       ],
+                       created_occurrences: 0,
                    }), "Expected a history element with specific content");
     }
 
@@ -226,7 +601,7 @@ mod tests {
             HistorizedBoard::default().with_4_typical_issues(),
         );
 
-        move_use_case.execute(&vec![1, 4, 5], State::Done);
+        move_use_case.execute(&[IssueRef::Index(1), IssueRef::Index(4), IssueRef::Index(5)], State::from("Done"), false);
 
         let errors = move_use_case.presenter.errors_presented.borrow();
         let_assert!([DomainError::IndexOutOfRange(4), DomainError::IndexOutOfRange(5)] = errors.as_slice());
@@ -237,7 +612,247 @@ mod tests {
             .assert_has_original_issues();
     }
 
-    fn given_move_use_case_with(board: HistorizedBoard<Issue>) -> MoveUseCase<MemoryIssueStorage, NilPresenter> {
+    #[test]
+    fn test_move_to_unknown_state() {
+        let mut move_use_case = given_move_use_case_with(
+            HistorizedBoard::default().with_4_typical_issues(),
+        );
+
+        move_use_case.execute(&[IssueRef::Index(0)], State::from("QA"), false);
+
+        let errors = move_use_case.presenter.errors_presented.borrow();
+        let_assert!([DomainError::UnknownState { state, available }] = errors.as_slice());
+        check!(state == "QA");
+        check!(available == "Open, Review, Done");
+
+        move_use_case.storage.load()
+            .has_the_original_4_issues_in_order();
+    }
+
+    #[test]
+    fn test_move_by_short_id() {
+        let mut board = HistorizedBoard::default().with_4_typical_issues();
+        board.assign_missing_short_ids();
+        let mut move_use_case = given_move_use_case_with(board);
+
+        move_use_case.execute(&[IssueRef::ShortId(ShortId(4))], State::from("Done"), false);
+
+        let stored_board = get_stored_and_presented_board(&move_use_case);
+        let issue = stored_board.get_with_index(1);
+        check!(issue.description.as_str() == "Task inserted first");
+        check!(issue.state == State::from("Done"));
+        check!(issue.short_id == Some(ShortId(4)));
+    }
+
+    #[test]
+    fn test_move_by_non_existent_short_id() {
+        let mut board = HistorizedBoard::default().with_4_typical_issues();
+        board.assign_missing_short_ids();
+        let mut move_use_case = given_move_use_case_with(board);
+
+        move_use_case.execute(&[IssueRef::ShortId(ShortId(1)), IssueRef::ShortId(ShortId(9))], State::from("Done"), false);
+
+        let errors = move_use_case.presenter.errors_presented.borrow();
+        let_assert!([DomainError::ShortIdNotFound(ShortId(9))] = errors.as_slice());
+
+        let stored_board = move_use_case.storage.load();
+        check!(stored_board.get_with_index(0).state == State::from("Open"), "Expected no issue to be moved");
+        check!(stored_board.history.stack.is_empty());
+    }
+
+    #[test]
+    fn test_move_respects_wip_limit() {
+        let mut move_use_case = given_move_use_case_with(
+            HistorizedBoard::default().with_4_typical_issues().with_wip_limit("Review", 2),
+        );
+
+        // Task inserted second is already in Review, so only one more issue comes to the column
+        move_use_case.execute(&[IssueRef::Index(2), IssueRef::Index(0)], State::from("Review"), false);
+        move_use_case.execute(&[IssueRef::Index(3)], State::from("Review"), false);
+
+        let errors = move_use_case.presenter.errors_presented.borrow();
+        let_assert!([DomainError::WipLimitExceeded { column, limit: 2 }] = errors.as_slice());
+        check!(column == "Review");
+
+        let stored_board = move_use_case.storage.load();
+        check!(stored_board.issue_count_in(&State::from("Review")) == 2);
+    }
+
+    #[test]
+    fn test_move_over_wip_limit_with_force() {
+        let mut move_use_case = given_move_use_case_with(
+            HistorizedBoard::default().with_4_typical_issues().with_wip_limit("Review", 1),
+        );
+
+        move_use_case.execute(&[IssueRef::Index(0), IssueRef::Index(3)], State::from("Review"), true);
+
+        check!(move_use_case.presenter.errors_presented.borrow().is_empty());
+        let stored_board = move_use_case.storage.load();
+        check!(stored_board.issue_count_in(&State::from("Review")) == 3);
+    }
+
+    #[test]
+    fn test_move_respects_allowed_transitions() {
+        let mut move_use_case = given_move_use_case_with(
+            HistorizedBoard::default().with_4_typical_issues().with_allowed_transitions("Open", &["Review"]),
+        );
+
+        move_use_case.execute(&[IssueRef::Index(0), IssueRef::Index(3), IssueRef::Index(2)], State::from("Done"), false);
+
+        let errors = move_use_case.presenter.errors_presented.borrow();
+        let_assert!([DomainError::TransitionNotAllowed { from, to, allowed }] = errors.as_slice());
+        check!(from == "Open");
+        check!(to == "Done");
+        check!(allowed == "`Review`");
+
+        let stored_board = move_use_case.storage.load();
+        check!(stored_board.issue_count_in(&State::from("Done")) == 1);
+    }
+
+    #[test]
+    fn test_force_overrides_allowed_transitions() {
+        let mut move_use_case = given_move_use_case_with(
+            HistorizedBoard::default().with_4_typical_issues().with_allowed_transitions("Open", &["Review"]),
+        );
+
+        move_use_case.execute(&[IssueRef::Index(3), IssueRef::Index(2)], State::from("Done"), true);
+
+        check_no_errors(&move_use_case);
+        let stored_board = get_stored_and_presented_board(&move_use_case);
+        let_assert!(Some(forced) = stored_board.entities().iter().find(|i| i.description == Description::from("Task inserted first")));
+        check!(forced.state == State::from("Done"));
+        let_assert!([.., ActivityEvent { kind: ActivityKind::TransitionForced { from, to }, .. }] = forced.activity.as_slice());
+        check!((from.to_string(), to.to_string()) == (String::from("Open"), String::from("Done")));
+
+        let_assert!(Some(allowed) = stored_board.entities().iter().find(|i| i.description == Description::from("Task inserted second")));
+        check!(allowed.activity.iter().all(|event| !matches!(event.kind, ActivityKind::TransitionForced { .. })));
+
+        let_assert!(Some(UndoableHistoryElement::Move(MoveHistoryElements { moves, .. })) = stored_board.history.last());
+        check!(moves.iter().map(|h| h.forced).collect::<Vec<_>>() == [true, false], "Expected the forced move in the history of the board");
+    }
+
+    #[test]
+    fn test_move_blocked_issue_to_done() {
+        let mut move_use_case = given_move_use_case_with(given_board_with_blocked_issue());
+
+        move_use_case.execute(&[IssueRef::Index(0)], State::from("Done"), false);
+        move_use_case.execute(&[IssueRef::Index(0)], State::from("Review"), false);
+
+        let errors = move_use_case.presenter.errors_presented.borrow();
+        let_assert!([DomainError::BlockedIssue { issue: ShortId(1), blockers }] = errors.as_slice());
+        check!(blockers == "`@3`, `@4`");
+
+        let stored_board = move_use_case.storage.load();
+        check!(stored_board.get_with_index(0).state == State::from("Review"), "Expected blocked issue to be moved within the columns that are not done");
+    }
+
+    #[test]
+    fn test_move_blocked_issue_to_done_with_its_blockers() {
+        let mut move_use_case = given_move_use_case_with(given_board_with_blocked_issue());
+
+        move_use_case.execute(&[IssueRef::ShortId(ShortId(1)), IssueRef::ShortId(ShortId(4)), IssueRef::ShortId(ShortId(3))], State::from("Done"), false);
+
+        check!(move_use_case.presenter.errors_presented.borrow().is_empty());
+        let stored_board = move_use_case.storage.load();
+        check!(stored_board.entities().iter().all(|e| e.state == State::from("Done")));
+    }
+
+    #[test]
+    fn test_move_parent_with_open_children_to_done() {
+        let mut move_use_case = given_move_use_case_with(given_board_with_parent_issue());
+
+        move_use_case.execute(&[IssueRef::ShortId(ShortId(1)), IssueRef::ShortId(ShortId(3))], State::from("Done"), false);
+
+        check_no_errors(&move_use_case);
+        let warnings = move_use_case.presenter.warnings_presented.borrow();
+        let_assert!([DomainError::OpenChildren { issue: ShortId(1), children }] = warnings.as_slice());
+        check!(children == "`@4`");
+
+        let stored_board = move_use_case.storage.load();
+        let_assert!(Some(parent) = stored_board.entities().iter().find(|e| e.short_id == Some(ShortId(1))));
+        check!(parent.state == State::from("Done"));
+    }
+
+    #[test]
+    fn test_move_parent_to_done_with_its_children() {
+        let mut move_use_case = given_move_use_case_with(given_board_with_parent_issue());
+
+        move_use_case.execute(&[IssueRef::ShortId(ShortId(1)), IssueRef::ShortId(ShortId(4)), IssueRef::ShortId(ShortId(3))], State::from("Done"), false);
+
+        check_no_errors(&move_use_case);
+        check!(move_use_case.presenter.warnings_presented.borrow().is_empty());
+        let stored_board = move_use_case.storage.load();
+        check!(stored_board.entities().iter().all(|e| e.state == State::from("Done")));
+    }
+
+    #[test]
+    fn test_no_warning_when_move_of_parent_fails() {
+        let mut move_use_case = given_move_use_case_with(given_board_with_parent_issue());
+
+        move_use_case.execute(&[IssueRef::ShortId(ShortId(1)), IssueRef::ShortId(ShortId(9))], State::from("Done"), false);
+
+        check!(move_use_case.presenter.errors_presented.borrow().len() == 1);
+        check!(move_use_case.presenter.warnings_presented.borrow().is_empty());
+    }
+
+    #[test]
+    fn test_move_between_custom_columns() {
+        let mut move_use_case = given_move_use_case_with(
+            HistorizedBoard::default()
+                .with_columns(&["Backlog", "In Progress", "QA", "Shipped"])
+                .with_issue(Issue { description: Description::from("Shipped issue"), state: State::from("Shipped"),
+                    time_created: DEFAULT_FAKE_TODAY,
+                    due_date: None,
+                    ..Default::default()
+                })
+                .with_issue(Issue { description: Description::from("Issue in progress"), state: State::from("In Progress"),
+                    time_created: DEFAULT_FAKE_TODAY,
+                    due_date: None,
+                    ..Default::default()
+                })
+                .with_issue(Issue { description: Description::from("Issue in QA"), state: State::from("QA"),
+                    time_created: DEFAULT_FAKE_TODAY,
+                    due_date: None,
+                    ..Default::default()
+                }),
+        );
+
+        move_use_case.execute(&[IssueRef::Index(1)], State::from("qa"), false);
+        move_use_case.execute(&[IssueRef::Index(2)], State::from("shipped"), false);
+
+        let stored_board = get_stored_and_presented_board(&move_use_case);
+        [
+            (0, "Shipped", "Issue in QA"), // moved to the top of the last column
+            (1, "Shipped", "Shipped issue"),
+            (2, "QA", "Issue in progress"),
+        ].into_iter().for_each(|(expected_index, expected_state, expected_description)| {
+            let issue = stored_board.get_with_index(expected_index);
+            check!(issue.state.to_string() == expected_state);
+            check!(issue.description.as_str() == expected_description);
+        });
+    }
+
+    /// `@1` in Open is blocked by `@2` (Done), `@3` (Review) and `@4` (Open)
+    fn given_board_with_blocked_issue() -> HistorizedBoard<Issue> {
+        let mut board = HistorizedBoard::default().with_4_typical_issues();
+        board.assign_missing_short_ids();
+        let id = board.find_entity_id_by_index(0).unwrap();
+        board.get_mut(id).blocked_by = vec![ShortId(2), ShortId(3), ShortId(4)];
+        board
+    }
+
+    /// `@1` in Open is the parent of `@2` (Done), `@3` (Review) and `@4` (Open)
+    fn given_board_with_parent_issue() -> HistorizedBoard<Issue> {
+        let mut board = HistorizedBoard::default().with_4_typical_issues();
+        board.assign_missing_short_ids();
+        for index in 1..4 {
+            let id = board.find_entity_id_by_index(index).unwrap();
+            board.get_mut(id).parent = Some(ShortId(1));
+        }
+        board
+    }
+
+    fn given_move_use_case_with(board: HistorizedBoard<Issue>) -> MoveUseCase<MemoryIssueStorage, NilPresenter, FakeTodayProvider> {
         let storage = MemoryIssueStorage::default();
         storage.save(&board);
 
@@ -0,0 +1,147 @@
+use internal_macros::{PresenterHolder, StorageHolder};
+use crate::application::domain::activity::ActivityKind;
+use crate::application::domain::error::DomainError;
+use crate::application::domain::history::{EditHistoryElement, UndoableHistoryElement};
+use crate::application::issue::IssueRef;
+use crate::application::ports::editor::Editor;
+use crate::application::ports::issue_storage::IssueStorage;
+use crate::application::ports::presenter::Presenter;
+use crate::application::ports::time::TodayProvider;
+use crate::application::usecase::usecase::{with_board_saved_and_presented_single_error, HasPresenter, HasStorage};
+
+#[derive(Default, StorageHolder, PresenterHolder)]
+pub(crate) struct NoteUseCase<I: IssueStorage, P: Presenter, E: Editor, T: TodayProvider> {
+    storage: I,
+    presenter: P,
+    editor: E,
+    time_provider: T,
+}
+
+impl<I: IssueStorage, P: Presenter, E: Editor, T: TodayProvider> NoteUseCase<I, P, E, T> {
+    /// Sets the body of the issue to `text`. Without `text`, the body is edited with the editor.
+    pub(crate) fn execute(&self, issue: IssueRef, text: Option<&str>) {
+        with_board_saved_and_presented_single_error(self, |mut board| {
+            let id = board.find_entity_id_by_ref(issue)?;
+            let index = board.position(id);
+
+            let entity = board.get(id);
+
+            let original_description = String::from(entity.description.as_str());
+            let original_body = entity.body.clone();
+
+            let body = match text {
+                Some(text) => text.to_string(),
+                None => self.editor
+                    .open_editor_with(original_body.as_deref().unwrap_or_default())
+                    .map_err(DomainError::from)?,
+            };
+
+            let issue = board.get_mut(id);
+            issue.set_body(&body);
+
+            if issue.body != original_body {
+                issue.record_activity(self.time_provider.now(), ActivityKind::Edited);
+                board.history.add(UndoableHistoryElement::Edit(
+                    EditHistoryElement {
+                        original_description,
+                        original_body,
+                        index,
+                    }
+                ));
+            }
+
+            Ok(board)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Error;
+    use assert2::check;
+    use crate::adapters::presenters::nil_presenter::test::NilPresenter;
+    use crate::adapters::storages::IssueStorage;
+    use crate::adapters::storages::memory_issue_storage::test::MemoryIssueStorage;
+    use crate::adapters::time_providers::fake::FakeTodayProvider;
+    use crate::application::Issue;
+    use crate::application::domain::historized_board::HistorizedBoard;
+    use crate::application::domain::history::{EditHistoryElement, UndoableHistoryElement};
+    use crate::application::issue::{Description, IssueRef};
+    use crate::application::ports::editor::Editor;
+    use crate::application::usecase::note::NoteUseCase;
+    use crate::application::usecase::test_utils::{check_no_errors, get_stored_and_presented_board};
+
+    #[test]
+    fn test_set_note_from_text() {
+        let use_case = given_note_use_case_with(
+            HistorizedBoard::default().with_4_typical_issues(),
+        );
+
+        use_case.execute(IssueRef::Index(1), Some("Pasted logs:\nERROR something failed"));
+
+        check_no_errors(&use_case);
+        let stored_board = get_stored_and_presented_board(&use_case);
+
+        let issue = stored_board.get_with_index(1);
+        check!(issue.description.as_str() == "Task inserted third", "Expected description not to change");
+        check!(issue.body.as_deref() == Some("Pasted logs:\nERROR something failed"));
+        check!(stored_board.history.last() == Some(&UndoableHistoryElement::Edit(EditHistoryElement {
+            original_description: String::from("Task inserted third"),
+            original_body: None,
+            index: 1,
+        })));
+    }
+
+    #[test]
+    fn test_edit_note_with_editor() {
+        let use_case = given_note_use_case_with(
+            HistorizedBoard::default().with_issue(Issue {
+                description: Description::from("Fix login"),
+                body: Some(String::from("First note")),
+                ..Default::default()
+            }),
+        );
+
+        use_case.execute(IssueRef::Index(0), None);
+
+        check_no_errors(&use_case);
+        let stored_board = get_stored_and_presented_board(&use_case);
+        check!(stored_board.get_with_index(0).body.as_deref() == Some("First note\nSecond note"));
+    }
+
+    #[test]
+    fn test_clear_note() {
+        let use_case = given_note_use_case_with(
+            HistorizedBoard::default().with_issue(Issue {
+                description: Description::from("Fix login"),
+                body: Some(String::from("First note")),
+                ..Default::default()
+            }),
+        );
+
+        use_case.execute(IssueRef::Index(0), Some(""));
+
+        check_no_errors(&use_case);
+        let stored_board = get_stored_and_presented_board(&use_case);
+        check!(stored_board.get_with_index(0).body == None);
+    }
+
+    #[derive(Default)]
+    struct AppendingEditor {}
+
+    impl Editor for AppendingEditor {
+        fn open_editor_with(&self, text: &str) -> Result<String, Error> {
+            Ok(format!("{}\nSecond note\n", text))
+        }
+    }
+
+    fn given_note_use_case_with(board: HistorizedBoard<Issue>) -> NoteUseCase<MemoryIssueStorage, NilPresenter, AppendingEditor, FakeTodayProvider> {
+        let storage = MemoryIssueStorage::default();
+        storage.save(&board);
+
+        NoteUseCase {
+            storage,
+            ..Default::default()
+        }
+    }
+}
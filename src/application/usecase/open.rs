@@ -0,0 +1,133 @@
+use internal_macros::{PresenterHolder, StorageHolder};
+use crate::application::domain::error::{DomainError, DomainResult};
+use crate::application::issue::IssueRef;
+use crate::application::ports::issue_storage::IssueStorage;
+use crate::application::ports::opener::Opener;
+use crate::application::ports::presenter::Presenter;
+use crate::application::usecase::usecase::{HasPresenter, HasStorage};
+
+#[derive(Default, StorageHolder, PresenterHolder)]
+pub(crate) struct OpenUseCase<I: IssueStorage, P: Presenter, O: Opener> {
+    storage: I,
+    presenter: P,
+    opener: O,
+}
+
+impl<I: IssueStorage, P: Presenter, O: Opener> OpenUseCase<I, P, O> {
+    /// Opens the link with `number` (counted from 1) of the issue, or its first link without a
+    /// number. The board does not change.
+    pub(crate) fn execute(&self, issue: IssueRef, number: Option<usize>) {
+        if let Err(error) = self.open(issue, number.unwrap_or(1)) {
+            self.presenter.render_error(&error);
+        }
+    }
+
+    fn open(&self, issue: IssueRef, number: usize) -> DomainResult<()> {
+        let board = self.storage.load();
+        let id = board.find_entity_id_by_ref(issue)?;
+
+        let link = number.checked_sub(1)
+            .and_then(|index| board.get(id).links.get(index))
+            .ok_or(DomainError::LinkNotFound(number))?;
+
+        self.opener.open(&link.target).map_err(DomainError::OpenerError)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::io::{Error, ErrorKind};
+    use assert2::{check, let_assert};
+    use crate::adapters::presenters::nil_presenter::test::NilPresenter;
+    use crate::adapters::storages::IssueStorage;
+    use crate::adapters::storages::memory_issue_storage::test::MemoryIssueStorage;
+    use crate::application::Issue;
+    use crate::application::domain::error::DomainError;
+    use crate::application::domain::historized_board::HistorizedBoard;
+    use crate::application::issue::{Description, IssueRef, Link};
+    use crate::application::ports::opener::Opener;
+    use crate::application::usecase::open::OpenUseCase;
+
+    #[test]
+    fn test_open_link() {
+        let use_case = given_open_use_case_with::<RecordingOpener>(given_board_with_links());
+
+        use_case.execute(IssueRef::Index(0), None);
+        use_case.execute(IssueRef::Index(0), Some(2));
+
+        check!(use_case.presenter.errors_presented.borrow().is_empty());
+        check!(*use_case.opener.opened.borrow() == ["https://example.com/pr/42", "~/designs/login.pdf"]);
+    }
+
+    #[test]
+    fn test_open_non_existent_link() {
+        let use_case = given_open_use_case_with::<RecordingOpener>(given_board_with_links());
+
+        for number in [0, 3] {
+            use_case.execute(IssueRef::Index(0), Some(number));
+        }
+        use_case.execute(IssueRef::Index(1), None);
+
+        let errors = use_case.presenter.errors_presented.borrow();
+        let_assert!([DomainError::LinkNotFound(0), DomainError::LinkNotFound(3), DomainError::LinkNotFound(1)] = errors.as_slice());
+        check!(use_case.opener.opened.borrow().is_empty());
+    }
+
+    #[test]
+    fn test_opener_fails() {
+        let use_case = given_open_use_case_with::<FailingOpener>(given_board_with_links());
+
+        use_case.execute(IssueRef::Index(0), None);
+
+        let errors = use_case.presenter.errors_presented.borrow();
+        let_assert!([DomainError::OpenerError(_)] = errors.as_slice());
+    }
+
+    fn given_board_with_links() -> HistorizedBoard<Issue> {
+        HistorizedBoard::default()
+            .with_issue(Issue {
+                description: Description::from("Fix login"),
+                links: vec![
+                    Link::new("https://example.com/pr/42", Some("PR")),
+                    Link::new("~/designs/login.pdf", None),
+                ],
+                ..Default::default()
+            })
+            .with_issue(Issue {
+                description: Description::from("Not linked"),
+                ..Default::default()
+            })
+    }
+
+    #[derive(Default)]
+    struct RecordingOpener {
+        opened: RefCell<Vec<String>>,
+    }
+
+    impl Opener for RecordingOpener {
+        fn open(&self, target: &str) -> Result<(), Error> {
+            self.opened.borrow_mut().push(target.to_string());
+            Ok(())
+        }
+    }
+
+    #[derive(Default)]
+    struct FailingOpener {}
+
+    impl Opener for FailingOpener {
+        fn open(&self, _target: &str) -> Result<(), Error> {
+            Err(Error::new(ErrorKind::NotFound, "xdg-open not found"))
+        }
+    }
+
+    fn given_open_use_case_with<O: Opener + Default>(board: HistorizedBoard<Issue>) -> OpenUseCase<MemoryIssueStorage, NilPresenter, O> {
+        let storage = MemoryIssueStorage::default();
+        storage.save(&board);
+
+        OpenUseCase {
+            storage,
+            ..Default::default()
+        }
+    }
+}
@@ -0,0 +1,144 @@
+use internal_macros::{PresenterHolder, StorageHolder};
+use crate::application::domain::filter::IssueFilter;
+use crate::application::domain::history::{ParentHistoryElement, UndoableHistoryElement};
+use crate::application::issue::IssueRef;
+use crate::application::ports::issue_storage::IssueStorage;
+use crate::application::ports::presenter::Presenter;
+use crate::application::usecase::usecase::{with_board_saved_and_presented_single_error, HasPresenter, HasStorage};
+
+#[derive(Default, PresenterHolder, StorageHolder)]
+pub(crate) struct ParentUseCase<I: IssueStorage, P: Presenter> {
+    storage: I,
+    presenter: P,
+}
+
+impl<I: IssueStorage, P: Presenter> ParentUseCase<I, P> {
+    /// Makes `parent` the parent of `issue`, e.g. an epic it is a part of. Without a parent, the
+    /// issue is detached from its parent.
+    pub(crate) fn execute(&self, issue: IssueRef, parent: Option<IssueRef>) {
+        with_board_saved_and_presented_single_error(self, |mut board| {
+            let id = board.find_entity_id_by_ref(issue)?;
+            let parent_id = parent.map(|parent| board.find_entity_id_by_ref(parent)).transpose()?;
+            let index = board.position(id);
+
+            let original_parent = board.get(id).parent;
+
+            board.set_parent(id, parent_id)?;
+
+            if board.get(id).parent != original_parent {
+                board.history.add(UndoableHistoryElement::Parent(ParentHistoryElement {
+                    index,
+                    original_parent,
+                }));
+            }
+
+            Ok(board)
+        })
+    }
+
+    /// Presents the children of `issue`, including the ones waiting for their wait date
+    pub(crate) fn children(&self, issue: IssueRef) {
+        let board = self.storage.load();
+
+        let short_id = board.find_entity_id_by_ref(issue)
+            .and_then(|id| board.short_id_of(id));
+
+        match short_id {
+            Ok(short_id) => self.presenter.render_filtered_board(&board, &IssueFilter {
+                parent: Some(short_id),
+                ..Default::default()
+            }, None),
+            Err(error) => self.presenter.render_error(&error),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert2::{check, let_assert};
+    use crate::adapters::presenters::nil_presenter::test::NilPresenter;
+    use crate::adapters::storages::IssueStorage;
+    use crate::adapters::storages::memory_issue_storage::test::MemoryIssueStorage;
+    use crate::application::domain::error::DomainError;
+    use crate::application::domain::filter::IssueFilter;
+    use crate::application::domain::historized_board::HistorizedBoard;
+    use crate::application::domain::history::{ParentHistoryElement, UndoableHistoryElement};
+    use crate::application::Issue;
+    use crate::application::issue::{IssueRef, ShortId};
+    use crate::application::usecase::parent::ParentUseCase;
+    use crate::application::usecase::test_utils::{check_no_errors, get_stored_and_presented_board};
+
+    #[test]
+    fn test_set_and_detach_parent() {
+        let use_case = given_parent_use_case_with(given_board_with_short_ids());
+
+        use_case.execute(IssueRef::Index(0), Some(IssueRef::ShortId(ShortId(4))));
+
+        check_no_errors(&use_case);
+        let stored_board = get_stored_and_presented_board(&use_case);
+        check!(stored_board.get_with_index(0).parent == Some(ShortId(4)));
+
+        use_case.execute(IssueRef::Index(0), None);
+
+        check_no_errors(&use_case);
+        let stored_board = get_stored_and_presented_board(&use_case);
+        check!(stored_board.get_with_index(0).parent == None);
+        check!(stored_board.history.last() == Some(&UndoableHistoryElement::Parent(ParentHistoryElement {
+            index: 0,
+            original_parent: Some(ShortId(4)),
+        })));
+    }
+
+    #[test]
+    fn test_parent_cycle_is_refused() {
+        let use_case = given_parent_use_case_with(given_board_with_short_ids());
+
+        use_case.execute(IssueRef::Index(0), Some(IssueRef::Index(1)));
+        use_case.execute(IssueRef::Index(1), Some(IssueRef::Index(2)));
+        use_case.execute(IssueRef::Index(2), Some(IssueRef::Index(0)));
+
+        let errors = use_case.presenter.errors_presented.borrow();
+        let_assert!([DomainError::ParentCycle { child: ShortId(3), parent: ShortId(1) }] = errors.as_slice());
+        check!(use_case.storage.load().get_with_index(2).parent == None);
+    }
+
+    #[test]
+    fn test_issue_cannot_be_its_own_parent() {
+        let use_case = given_parent_use_case_with(given_board_with_short_ids());
+
+        use_case.execute(IssueRef::Index(0), Some(IssueRef::Index(0)));
+
+        let errors = use_case.presenter.errors_presented.borrow();
+        let_assert!([DomainError::ParentCycle { .. }] = errors.as_slice());
+        check!(use_case.storage.load().history.last() == None);
+    }
+
+    #[test]
+    fn test_children() {
+        let use_case = given_parent_use_case_with(given_board_with_short_ids());
+
+        use_case.children(IssueRef::Index(3));
+
+        check!(use_case.presenter.errors_presented.borrow().is_empty());
+        check!(*use_case.presenter.last_filter_used.borrow() == Some(IssueFilter {
+            parent: Some(ShortId(4)),
+            ..Default::default()
+        }));
+    }
+
+    fn given_board_with_short_ids() -> HistorizedBoard<Issue> {
+        let mut board = HistorizedBoard::default().with_4_typical_issues();
+        board.assign_missing_short_ids();
+        board
+    }
+
+    fn given_parent_use_case_with(board: HistorizedBoard<Issue>) -> ParentUseCase<MemoryIssueStorage, NilPresenter> {
+        let storage = MemoryIssueStorage::default();
+        storage.save(&board);
+
+        ParentUseCase {
+            storage,
+            ..Default::default()
+        }
+    }
+}
@@ -6,6 +6,7 @@ use crate::application::ports::issue_storage::IssueStorage;
 use crate::application::ports::presenter::Presenter;
 use crate::application::domain::history::{PrioHistoryElement, UndoableHistoryElement};
 use crate::application::Issue;
+use crate::application::issue::IssueRef;
 use crate::application::usecase::usecase::{HasStorage, HasPresenter, with_board_saved_and_presented_single_error};
 
 pub(crate) trait PriorityModifier: Default {
@@ -56,9 +57,10 @@ pub(crate) struct PriorityUseCase<I: IssueStorage, P: Presenter, PM: PriorityMod
     _priority_modifier: PhantomData<PM>
 }
 impl<I: IssueStorage, P: Presenter, PM: PriorityModifier> PriorityUseCase<I, P, PM> {
-    pub(crate) fn execute(&self, index: usize) {
+    pub(crate) fn execute(&self, issue: IssueRef) {
         with_board_saved_and_presented_single_error(self, |mut board| {
-            let id = board.find_entity_id_by_index(index)?;
+            let id = board.find_entity_id_by_ref(issue)?;
+            let index = board.position(id);
 
             PM::modify_priority(&mut board.board, id);
 
@@ -89,7 +91,7 @@ mod test {
     use crate::application::board::test_utils::check_boards_are_equal;
     use crate::application::domain::error::DomainError;
     use crate::application::domain::history::{PrioHistoryElement, UndoableHistoryElement};
-    use crate::application::issue::Description;
+    use crate::application::issue::{Description, IssueRef};
     use crate::application::usecase::prio::{BottomPriority, DownPriority, PriorityModifier, PriorityUseCase, TopPriority, UpPriority};
     use crate::application::usecase::test_utils::{check_no_errors, get_stored_and_presented_board};
     use crate::application::usecase::usecase::HasPresenter;
@@ -99,7 +101,7 @@ mod test {
         let use_case = given_prio_use_case_with::<TopPriority>(simple_board());
 
         // when
-        use_case.execute(1);
+        use_case.execute(IssueRef::Index(1));
 
         // then
         check_no_errors(&use_case);
@@ -118,7 +120,7 @@ mod test {
         let use_case = given_prio_use_case_with::<TopPriority>(simple_board());
 
         // when
-        use_case.execute(2);
+        use_case.execute(IssueRef::Index(2));
 
         // then
         let cell = use_case.presenter_ref().errors_presented.borrow();
@@ -131,7 +133,7 @@ mod test {
         let use_case = given_prio_use_case_with::<TopPriority>(simple_board());
 
         // when
-        use_case.execute(0);
+        use_case.execute(IssueRef::Index(0));
 
         // then
         check_no_errors(&use_case);
@@ -148,7 +150,7 @@ mod test {
         let use_case = given_prio_use_case_with::<BottomPriority>(simple_board());
 
         // when
-        use_case.execute(0);
+        use_case.execute(IssueRef::Index(0));
 
         // then
         check_no_errors(&use_case);
@@ -161,7 +163,7 @@ mod test {
         let use_case = given_prio_use_case_with::<UpPriority>(simple_board());
 
         // when
-        use_case.execute(1);
+        use_case.execute(IssueRef::Index(1));
 
         // then
         check_no_errors(&use_case);
@@ -176,7 +178,7 @@ mod test {
         let use_case = given_prio_use_case_with::<DownPriority>(simple_board());
 
         // when
-        use_case.execute(0);
+        use_case.execute(IssueRef::Index(0));
 
         // then
         check_no_errors(&use_case);
@@ -202,11 +204,12 @@ mod test {
     fn simple_board() -> HistorizedBoard<Issue> {
         HistorizedBoard::new(
             [
-                ("First Issue", State::Open),
-                ("Second Issue", State::Open)
+                ("First Issue", State::from("Open")),
+                ("Second Issue", State::from("Open"))
             ].into_iter().map(|(d, state)| Issue { description: Description::from(d), state,
                 time_created: DEFAULT_FAKE_TODAY,
                 due_date: None,
+                ..Default::default()
             }).collect(),
             vec![],
             vec![])
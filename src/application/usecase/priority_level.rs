@@ -0,0 +1,92 @@
+use internal_macros::{PresenterHolder, StorageHolder};
+use crate::application::domain::history::{PriorityLevelHistoryElement, UndoableHistoryElement};
+use crate::application::issue::{IssueRef, Priority};
+use crate::application::ports::issue_storage::IssueStorage;
+use crate::application::ports::presenter::Presenter;
+use crate::application::usecase::usecase::{with_board_saved_and_presented_single_error, HasPresenter, HasStorage};
+
+#[derive(Default, PresenterHolder, StorageHolder)]
+pub(crate) struct PriorityLevelUseCase<I: IssueStorage, P: Presenter> {
+    storage: I,
+    presenter: P,
+}
+
+impl<I: IssueStorage, P: Presenter> PriorityLevelUseCase<I, P> {
+    /// Sets the priority level of the issue. Its order within the column stays the same.
+    pub(crate) fn execute(&self, issue: IssueRef, priority: Priority) {
+        with_board_saved_and_presented_single_error(self, |mut board| {
+            let id = board.find_entity_id_by_ref(issue)?;
+            let index = board.position(id);
+
+            let issue = board.get_mut(id);
+            if issue.priority != priority {
+                let original_priority = std::mem::replace(&mut issue.priority, priority);
+
+                board.history.add(UndoableHistoryElement::PriorityLevel(PriorityLevelHistoryElement {
+                    index,
+                    original_priority,
+                }));
+            }
+
+            Ok(board)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert2::check;
+    use crate::adapters::presenters::nil_presenter::test::NilPresenter;
+    use crate::adapters::storages::IssueStorage;
+    use crate::adapters::storages::memory_issue_storage::test::MemoryIssueStorage;
+    use crate::application::Issue;
+    use crate::application::domain::historized_board::HistorizedBoard;
+    use crate::application::domain::history::{PriorityLevelHistoryElement, UndoableHistoryElement};
+    use crate::application::issue::{IssueRef, Priority};
+    use crate::application::usecase::priority_level::PriorityLevelUseCase;
+    use crate::application::usecase::test_utils::{check_no_errors, get_stored_and_presented_board};
+
+    #[test]
+    fn test_set_priority_level() {
+        let use_case = given_priority_level_use_case_with(
+            HistorizedBoard::default().with_4_typical_issues(),
+        );
+
+        use_case.execute(IssueRef::Index(2), Priority::Critical);
+
+        check_no_errors(&use_case);
+        let stored_board = get_stored_and_presented_board(&use_case);
+        check!(stored_board.get_with_index(2).priority == Priority::Critical);
+        check!(stored_board.get_with_index(2).description == HistorizedBoard::default().with_4_typical_issues().get_with_index(2).description,
+            "Expected the order of the issues to stay the same");
+        check!(stored_board.history.last() == Some(&UndoableHistoryElement::PriorityLevel(PriorityLevelHistoryElement {
+            index: 2,
+            original_priority: Priority::Normal,
+        })));
+    }
+
+    #[test]
+    fn test_same_priority_level_is_not_in_history() {
+        let use_case = given_priority_level_use_case_with(
+            HistorizedBoard::default().with_issue(Issue {
+                priority: Priority::High,
+                ..Default::default()
+            }),
+        );
+
+        use_case.execute(IssueRef::Index(0), Priority::High);
+
+        check_no_errors(&use_case);
+        check!(get_stored_and_presented_board(&use_case).history.last() == None);
+    }
+
+    fn given_priority_level_use_case_with(board: HistorizedBoard<Issue>) -> PriorityLevelUseCase<MemoryIssueStorage, NilPresenter> {
+        let storage = MemoryIssueStorage::default();
+        storage.save(&board);
+
+        PriorityLevelUseCase {
+            storage,
+            ..Default::default()
+        }
+    }
+}
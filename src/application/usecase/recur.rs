@@ -0,0 +1,106 @@
+use internal_macros::{PresenterHolder, StorageHolder};
+use crate::application::domain::date_parse::Recurrence;
+use crate::application::domain::history::{RecurHistoryElement, UndoableHistoryElement};
+use crate::application::issue::IssueRef;
+use crate::application::ports::issue_storage::IssueStorage;
+use crate::application::ports::presenter::Presenter;
+use crate::application::usecase::usecase::{with_board_saved_and_presented_single_error, HasPresenter, HasStorage};
+
+#[derive(Default, PresenterHolder, StorageHolder)]
+pub(crate) struct RecurUseCase<I: IssueStorage, P: Presenter> {
+    storage: I,
+    presenter: P,
+}
+
+impl<I: IssueStorage, P: Presenter> RecurUseCase<I, P> {
+    /// Sets the recurrence rule of the issue (e.g. "every monday"). Without a rule, the issue stops recurring.
+    pub(crate) fn execute(&self, issue: IssueRef, rule: Option<&str>) {
+        with_board_saved_and_presented_single_error(self, |mut board| {
+            let id = board.find_entity_id_by_ref(issue)?;
+            let index = board.position(id);
+
+            let recurrence = rule
+                .map(|rule| rule.parse::<Recurrence>())
+                .transpose()?;
+
+            let issue = board.get_mut(id);
+            let original_recurrence = issue.recurrence;
+            issue.recurrence = recurrence;
+
+            if recurrence != original_recurrence {
+                board.history.add(UndoableHistoryElement::Recur(RecurHistoryElement {
+                    index,
+                    original_recurrence,
+                }));
+            }
+
+            Ok(board)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert2::{check, let_assert};
+    use time::Weekday;
+    use crate::adapters::presenters::nil_presenter::test::NilPresenter;
+    use crate::adapters::storages::IssueStorage;
+    use crate::adapters::storages::memory_issue_storage::test::MemoryIssueStorage;
+    use crate::application::Issue;
+    use crate::application::domain::date_parse::Recurrence;
+    use crate::application::domain::error::DomainError;
+    use crate::application::domain::historized_board::HistorizedBoard;
+    use crate::application::domain::history::{RecurHistoryElement, UndoableHistoryElement};
+    use crate::application::issue::IssueRef;
+    use crate::application::usecase::recur::RecurUseCase;
+    use crate::application::usecase::test_utils::{check_no_errors, get_stored_and_presented_board};
+
+    #[test]
+    fn test_set_and_clear_recurrence() {
+        let use_case = given_recur_use_case_with(
+            HistorizedBoard::default().with_4_typical_issues(),
+        );
+
+        use_case.execute(IssueRef::Index(2), Some("every monday"));
+
+        check_no_errors(&use_case);
+        let stored_board = get_stored_and_presented_board(&use_case);
+        check!(stored_board.get_with_index(2).recurrence == Some(Recurrence::Weekday(Weekday::Monday)));
+        check!(stored_board.history.last() == Some(&UndoableHistoryElement::Recur(RecurHistoryElement {
+            index: 2,
+            original_recurrence: None,
+        })));
+
+        use_case.execute(IssueRef::Index(2), None);
+
+        let stored_board = get_stored_and_presented_board(&use_case);
+        check!(stored_board.get_with_index(2).recurrence == None);
+        check!(stored_board.history.last() == Some(&UndoableHistoryElement::Recur(RecurHistoryElement {
+            index: 2,
+            original_recurrence: Some(Recurrence::Weekday(Weekday::Monday)),
+        })));
+    }
+
+    #[test]
+    fn test_invalid_rule() {
+        let use_case = given_recur_use_case_with(
+            HistorizedBoard::default().with_4_typical_issues(),
+        );
+
+        use_case.execute(IssueRef::Index(2), Some("every now and then"));
+
+        let errors = use_case.presenter.errors_presented.borrow();
+        let_assert!([DomainError::DateParseError(_)] = errors.as_slice());
+        check!(use_case.storage.load().get_with_index(2).recurrence == None);
+    }
+
+    fn given_recur_use_case_with(board: HistorizedBoard<Issue>) -> RecurUseCase<MemoryIssueStorage, NilPresenter> {
+        let storage = MemoryIssueStorage::default();
+        storage.save(&board);
+
+        RecurUseCase {
+            storage,
+            ..Default::default()
+        }
+    }
+}
@@ -0,0 +1,56 @@
+use internal_macros::{PresenterHolder, StorageHolder};
+use crate::application::domain::time_tracking::TimeReport;
+use crate::application::ports::issue_storage::IssueStorage;
+use crate::application::ports::presenter::Presenter;
+use crate::application::ports::time::TodayProvider;
+use crate::application::usecase::usecase::{HasPresenter, HasStorage};
+
+#[derive(Default, StorageHolder, PresenterHolder)]
+pub(crate) struct ReportUseCase<I: IssueStorage, P: Presenter, T: TodayProvider> {
+    storage: I,
+    presenter: P,
+    time_provider: T,
+}
+
+impl<I: IssueStorage, P: Presenter, T: TodayProvider> ReportUseCase<I, P, T> {
+    /// Presents the tracked time per issue and per day
+    pub(crate) fn execute(&self) {
+        let board = self.storage.load();
+        let report = TimeReport::new(&board, self.time_provider.now());
+
+        self.presenter.render_time_report(&board, &report);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert2::{check, let_assert};
+    use time::Duration;
+    use time::macros::{date, datetime};
+    use crate::adapters::presenters::nil_presenter::test::NilPresenter;
+    use crate::adapters::storages::IssueStorage;
+    use crate::adapters::storages::memory_issue_storage::test::MemoryIssueStorage;
+    use crate::adapters::time_providers::fake::FakeTodayProvider;
+    use crate::application::Issue;
+    use crate::application::domain::historized_board::HistorizedBoard;
+    use crate::application::domain::time_tracking::WorkInterval;
+    use crate::application::issue::Description;
+    use crate::application::usecase::report::ReportUseCase;
+
+    #[test]
+    fn test_report_includes_running_timer() {
+        let use_case = ReportUseCase::<MemoryIssueStorage, NilPresenter, FakeTodayProvider>::default();
+        use_case.storage.save(&HistorizedBoard::default().with_issue(Issue {
+            description: Description::from("Tracked since the morning"),
+            work_log: vec![WorkInterval::started_at(datetime!(2025-02-22 08:00 UTC))],
+            ..Default::default()
+        }));
+
+        use_case.execute();
+
+        let report = use_case.presenter.last_time_report.borrow();
+        let_assert!(Some(report) = report.as_ref());
+        check!(report.per_day == vec![(date!(2025-02-22), Duration::hours(2))]);
+        check!(report.total() == Duration::hours(2));
+    }
+}
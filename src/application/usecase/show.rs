@@ -0,0 +1,68 @@
+use internal_macros::{PresenterHolder, StorageHolder};
+use crate::application::issue::IssueRef;
+use crate::application::ports::issue_storage::IssueStorage;
+use crate::application::ports::presenter::Presenter;
+use crate::application::usecase::usecase::{HasPresenter, HasStorage};
+
+#[derive(Default, StorageHolder, PresenterHolder)]
+pub(crate) struct ShowUseCase<I: IssueStorage, P: Presenter> {
+    storage: I,
+    presenter: P
+}
+
+impl<I: IssueStorage, P: Presenter> ShowUseCase<I, P> {
+    /// Presents every detail of a single issue
+    pub(crate) fn execute(&self, issue: IssueRef) {
+        let board = self.storage.load();
+
+        match board.find_entity_id_by_ref(issue) {
+            Ok(id) => self.presenter.render_issue(&board, id),
+            Err(error) => self.presenter.render_error(&error),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert2::{check, let_assert};
+    use crate::adapters::presenters::nil_presenter::test::NilPresenter;
+    use crate::adapters::storages::IssueStorage;
+    use crate::adapters::storages::memory_issue_storage::test::MemoryIssueStorage;
+    use crate::application::domain::error::DomainError;
+    use crate::application::domain::historized_board::HistorizedBoard;
+    use crate::application::Issue;
+    use crate::application::issue::IssueRef;
+    use crate::application::usecase::show::ShowUseCase;
+
+    #[test]
+    fn test_show_issue() {
+        let use_case = given_show_use_case_with(HistorizedBoard::default().with_4_typical_issues());
+
+        use_case.execute(IssueRef::Index(2));
+
+        let board = use_case.storage.load();
+        check!(*use_case.presenter.last_issue_rendered.borrow() == Some(board.find_entity_id_by_index(2).unwrap()));
+        check!(use_case.presenter.errors_presented.borrow().is_empty());
+    }
+
+    #[test]
+    fn test_show_non_existent_issue() {
+        let use_case = given_show_use_case_with(HistorizedBoard::default().with_4_typical_issues());
+
+        use_case.execute(IssueRef::Index(4));
+
+        let errors = use_case.presenter.errors_presented.borrow();
+        let_assert!([DomainError::IndexOutOfRange(4)] = errors.as_slice());
+        check!(*use_case.presenter.last_issue_rendered.borrow() == None);
+    }
+
+    fn given_show_use_case_with(board: HistorizedBoard<Issue>) -> ShowUseCase<MemoryIssueStorage, NilPresenter> {
+        let storage = MemoryIssueStorage::default();
+        storage.save(&board);
+
+        ShowUseCase {
+            storage,
+            ..Default::default()
+        }
+    }
+}
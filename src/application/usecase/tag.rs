@@ -0,0 +1,128 @@
+use internal_macros::{PresenterHolder, StorageHolder};
+use crate::application::domain::history::{TagHistoryElement, UndoableHistoryElement};
+use crate::application::issue::{IssueRef, Tag};
+use crate::application::ports::issue_storage::IssueStorage;
+use crate::application::ports::presenter::Presenter;
+use crate::application::usecase::usecase::{with_board_saved_and_presented_single_error, HasPresenter, HasStorage};
+
+#[derive(Debug, PartialEq, Clone)]
+pub(crate) enum TagChange {
+    Add(Tag),
+    Remove(Tag),
+}
+
+#[derive(Default, PresenterHolder, StorageHolder)]
+pub(crate) struct TagUseCase<I: IssueStorage, P: Presenter> {
+    storage: I,
+    presenter: P,
+}
+
+impl<I: IssueStorage, P: Presenter> TagUseCase<I, P> {
+    pub(crate) fn execute(&self, issue: IssueRef, changes: &[TagChange]) {
+        with_board_saved_and_presented_single_error(self, |mut board| {
+            let id = board.find_entity_id_by_ref(issue)?;
+            let index = board.position(id);
+
+            let original_tags = board.get(id).tags.clone();
+
+            let issue = board.get_mut(id);
+            for change in changes {
+                match change {
+                    TagChange::Add(tag) => issue.add_tag(tag.clone()),
+                    TagChange::Remove(tag) => issue.remove_tag(tag),
+                }
+            }
+
+            if issue.tags != original_tags {
+                board.history.add(UndoableHistoryElement::Tag(TagHistoryElement {
+                    index,
+                    original_tags,
+                }));
+            }
+
+            Ok(board)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert2::{check, let_assert};
+    use crate::adapters::presenters::nil_presenter::test::NilPresenter;
+    use crate::adapters::storages::IssueStorage;
+    use crate::adapters::storages::memory_issue_storage::test::MemoryIssueStorage;
+    use crate::application::domain::error::DomainError;
+    use crate::application::domain::historized_board::HistorizedBoard;
+    use crate::application::domain::history::{TagHistoryElement, UndoableHistoryElement};
+    use crate::application::Issue;
+    use crate::application::issue::{Description, IssueRef, Tag};
+    use crate::application::usecase::tag::{TagChange, TagUseCase};
+    use crate::application::usecase::test_utils::{check_no_errors, get_stored_and_presented_board};
+
+    #[test]
+    fn test_add_and_remove_tags() {
+        let use_case = given_tag_use_case_with(
+            HistorizedBoard::default().with_issue(tagged_issue()),
+        );
+
+        use_case.execute(IssueRef::Index(0), &[
+            TagChange::Add(Tag::from("frontend")),
+            TagChange::Remove(Tag::from("urgent")),
+            TagChange::Add(Tag::from("backend")),
+        ]);
+
+        check_no_errors(&use_case);
+        let stored_board = get_stored_and_presented_board(&use_case);
+
+        check!(stored_board.get_with_index(0).tags == [Tag::from("backend"), Tag::from("frontend")]);
+        check!(stored_board.history.last() == Some(&UndoableHistoryElement::Tag(TagHistoryElement {
+            index: 0,
+            original_tags: vec![Tag::from("backend"), Tag::from("urgent")],
+        })));
+    }
+
+    #[test]
+    fn test_tag_change_without_effect_is_not_in_history() {
+        let use_case = given_tag_use_case_with(
+            HistorizedBoard::default().with_issue(tagged_issue()),
+        );
+
+        use_case.execute(IssueRef::Index(0), &[TagChange::Add(Tag::from("Backend")), TagChange::Remove(Tag::from("frontend"))]);
+
+        check_no_errors(&use_case);
+        let stored_board = get_stored_and_presented_board(&use_case);
+
+        check!(stored_board.get_with_index(0).tags == [Tag::from("backend"), Tag::from("urgent")]);
+        check!(stored_board.history.last() == None);
+    }
+
+    #[test]
+    fn test_tag_index_out_of_range() {
+        let use_case = given_tag_use_case_with(
+            HistorizedBoard::default().with_4_typical_issues(),
+        );
+
+        use_case.execute(IssueRef::Index(4), &[TagChange::Add(Tag::from("backend"))]);
+
+        let errors = use_case.presenter.errors_presented.borrow();
+        let_assert!([DomainError::IndexOutOfRange(4)] = errors.as_slice());
+    }
+
+    fn tagged_issue() -> Issue {
+        Issue {
+            description: Description::from("Fix login"),
+            tags: vec![Tag::from("backend"), Tag::from("urgent")],
+            ..Default::default()
+        }
+    }
+
+    fn given_tag_use_case_with(board: HistorizedBoard<Issue>) -> TagUseCase<MemoryIssueStorage, NilPresenter> {
+        let storage = MemoryIssueStorage::default();
+        storage.save(&board);
+
+        TagUseCase {
+            storage,
+            ..Default::default()
+        }
+    }
+}
@@ -0,0 +1,185 @@
+use internal_macros::{PresenterHolder, StorageHolder};
+use nonempty_collections::NEVec;
+use crate::application::domain::error::DomainError;
+use crate::application::issue::IssueRef;
+use crate::application::ports::issue_storage::IssueStorage;
+use crate::application::ports::presenter::Presenter;
+use crate::application::ports::time::TodayProvider;
+use crate::application::usecase::r#move::IssueMover;
+use crate::application::usecase::usecase::{with_board_saved_and_presented_multi_error, HasPresenter, HasStorage};
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub(crate) enum TrackAction {
+    /// Starts the timer of the issue. With `move_forward`, an issue in the first column is moved
+    /// to the next column.
+    Start {
+        issue: IssueRef,
+        move_forward: bool,
+    },
+    /// Stops the running timer
+    Stop,
+}
+
+#[derive(Default, PresenterHolder, StorageHolder)]
+pub(crate) struct TrackUseCase<I: IssueStorage, P: Presenter, T: TodayProvider> {
+    storage: I,
+    presenter: P,
+    time_provider: T,
+}
+
+impl<I: IssueStorage, P: Presenter, T: TodayProvider> TrackUseCase<I, P, T> {
+    /// Starts or stops tracking the time worked on an issue. Only one issue is tracked at a time:
+    /// starting an issue stops the timer of the issue tracked before.
+    ///
+    /// Tracking itself is not recorded in the history, but the move of a started issue is.
+    pub(crate) fn execute(&self, action: TrackAction) {
+        with_board_saved_and_presented_multi_error(self, |mut board| {
+            let now = self.time_provider.now();
+            let tracked = board.find_tracked_issue();
+
+            match action {
+                TrackAction::Start { issue, move_forward } => {
+                    let id = board.find_entity_id_by_ref(issue).map_err(NEVec::new)?;
+
+                    let state = board.get(id).state.clone();
+                    if move_forward && state == board.columns().first_state() {
+                        if let Some(next_state) = board.columns().next_state(&state) {
+                            let issue_mover = IssueMover {
+                                time_provider: &self.time_provider,
+                            };
+                            issue_mover.move_issues(&mut board, vec![id], next_state, false)?;
+                        }
+                    }
+
+                    if let Some(tracked) = tracked.filter(|&tracked| tracked != id) {
+                        board.get_mut(tracked).stop_tracking(now);
+                    }
+                    board.get_mut(id).start_tracking(now);
+                },
+                TrackAction::Stop => {
+                    let tracked = tracked.ok_or(DomainError::NoTrackedIssue).map_err(NEVec::new)?;
+                    board.get_mut(tracked).stop_tracking(now);
+                },
+            }
+
+            Ok(board)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert2::{check, let_assert};
+    use time::macros::datetime;
+    use crate::adapters::presenters::nil_presenter::test::NilPresenter;
+    use crate::adapters::storages::IssueStorage;
+    use crate::adapters::storages::memory_issue_storage::test::MemoryIssueStorage;
+    use crate::adapters::time_providers::fake::FakeTodayProvider;
+    use crate::application::{Issue, State};
+    use crate::application::domain::error::DomainError;
+    use crate::application::domain::historized_board::HistorizedBoard;
+    use crate::application::domain::history::UndoableHistoryElement;
+    use crate::application::domain::time_tracking::WorkInterval;
+    use crate::application::issue::{Description, IssueRef};
+    use crate::application::usecase::test_utils::{check_no_errors, get_stored_and_presented_board};
+    use crate::application::usecase::track::{TrackAction, TrackUseCase};
+
+    #[test]
+    fn test_start_stops_the_previously_tracked_issue() {
+        let use_case = given_track_use_case_with(
+            HistorizedBoard::default()
+                .with_issue(Issue {
+                    description: Description::from("Tracked since the morning"),
+                    state: State::from("Review"),
+                    work_log: vec![WorkInterval::started_at(datetime!(2025-02-22 08:00 UTC))],
+                    ..Default::default()
+                })
+                .with_4_typical_issues(),
+        );
+
+        use_case.execute(TrackAction::Start { issue: IssueRef::Index(2), move_forward: false });
+
+        check_no_errors(&use_case);
+        let stored_board = get_stored_and_presented_board(&use_case);
+        check!(stored_board.get_with_index(0).work_log == vec![
+            WorkInterval { start: datetime!(2025-02-22 08:00 UTC), end: Some(datetime!(2025-02-22 10:00 UTC)) },
+        ]);
+        check!(stored_board.get_with_index(2).work_log == vec![WorkInterval::started_at(datetime!(2025-02-22 10:00 UTC))]);
+        check!(stored_board.find_tracked_issue() == Some(stored_board.find_entity_id_by_index(2).unwrap()));
+        check!(stored_board.history.last() == None, "Expected tracking not to be in the history");
+    }
+
+    #[test]
+    fn test_start_and_move_forward() {
+        let use_case = given_track_use_case_with(
+            HistorizedBoard::default().with_4_typical_issues(),
+        );
+
+        use_case.execute(TrackAction::Start { issue: IssueRef::Index(3), move_forward: true });
+
+        check_no_errors(&use_case);
+        let stored_board = get_stored_and_presented_board(&use_case);
+        let_assert!(Some(issue) = stored_board.entities().iter().find(|i| i.description == Description::from("Task inserted first")));
+        check!(issue.state == State::from("Review"));
+        check!(issue.is_tracked());
+        let_assert!(Some(UndoableHistoryElement::Move(_)) = stored_board.history.last());
+    }
+
+    #[test]
+    fn test_start_does_not_move_issues_out_of_other_columns() {
+        let use_case = given_track_use_case_with(
+            HistorizedBoard::default().with_4_typical_issues(),
+        );
+
+        use_case.execute(TrackAction::Start { issue: IssueRef::Index(2), move_forward: true });
+
+        let stored_board = get_stored_and_presented_board(&use_case);
+        check!(stored_board.get_with_index(2).state == State::from("Review"));
+        check!(stored_board.history.last() == None);
+    }
+
+    #[test]
+    fn test_start_respects_wip_limit_when_moving_forward() {
+        let use_case = given_track_use_case_with(
+            HistorizedBoard::default().with_4_typical_issues().with_wip_limit("Review", 1),
+        );
+
+        use_case.execute(TrackAction::Start { issue: IssueRef::Index(3), move_forward: true });
+
+        let errors = use_case.presenter.errors_presented.borrow();
+        let_assert!([DomainError::WipLimitExceeded { .. }] = errors.as_slice());
+        check!(!use_case.storage.load().get_with_index(3).is_tracked());
+    }
+
+    #[test]
+    fn test_stop() {
+        let use_case = given_track_use_case_with(
+            HistorizedBoard::default().with_issue(Issue {
+                description: Description::from("Tracked since the morning"),
+                work_log: vec![WorkInterval::started_at(datetime!(2025-02-22 08:00 UTC))],
+                ..Default::default()
+            }),
+        );
+
+        use_case.execute(TrackAction::Stop);
+
+        check_no_errors(&use_case);
+        let stored_board = get_stored_and_presented_board(&use_case);
+        check!(stored_board.find_tracked_issue() == None);
+
+        use_case.execute(TrackAction::Stop);
+
+        let errors = use_case.presenter.errors_presented.borrow();
+        let_assert!([DomainError::NoTrackedIssue] = errors.as_slice());
+    }
+
+    fn given_track_use_case_with(board: HistorizedBoard<Issue>) -> TrackUseCase<MemoryIssueStorage, NilPresenter, FakeTodayProvider> {
+        let storage = MemoryIssueStorage::default();
+        storage.save(&board);
+
+        TrackUseCase {
+            storage,
+            ..Default::default()
+        }
+    }
+}
@@ -0,0 +1,198 @@
+use internal_macros::{PresenterHolder, StorageHolder};
+use crate::application::domain::activity::ActivityKind;
+use crate::application::domain::board_name::BoardName;
+use crate::application::domain::error::DomainError;
+use crate::application::domain::history::{TransferHistoryElement, UndoableHistoryElement};
+use crate::application::issue::IssueRef;
+use crate::application::ports::board_registry::BoardRegistry;
+use crate::application::ports::issue_storage::IssueStorage;
+use crate::application::ports::presenter::Presenter;
+use crate::application::ports::time::TodayProvider;
+use crate::application::usecase::usecase::{with_board_saved_and_presented_single_error, HasPresenter, HasStorage};
+
+#[derive(Default, PresenterHolder, StorageHolder)]
+pub(crate) struct TransferUseCase<I: IssueStorage, P: Presenter, R: BoardRegistry, T: TodayProvider> {
+    storage: I,
+    presenter: P,
+    registry: R,
+    time_provider: T,
+}
+
+impl<I: IssueStorage, P: Presenter, R: BoardRegistry, T: TodayProvider> TransferUseCase<I, P, R, T> {
+    /// Works with the given boards, which know the board selected for this run
+    pub(crate) fn with_registry(mut self, registry: R) -> Self {
+        self.registry = registry;
+        self
+    }
+
+    /// Moves the issue to the bottom of another board, where it gets a new short id. Done issues
+    /// stay done, and issues in a column that the other board does not have go to its first column.
+    /// Such a change of state is recorded in the activity of the issue like a move.
+    ///
+    /// The transfer is recorded in the history of the board the issue came from, undoing it brings
+    /// the issue back.
+    pub(crate) fn execute(&self, issue: IssueRef, to: &BoardName) {
+        let mut transferred_to = None;
+
+        with_board_saved_and_presented_single_error(self, |mut board| {
+            if !self.registry.exists(to) {
+                return Err(DomainError::BoardNotFound(to.clone()));
+            }
+            if *to == self.registry.active_board() {
+                return Err(DomainError::TransferToSameBoard(to.clone()));
+            }
+
+            let id = board.find_entity_id_by_ref(issue)?;
+            let index = board.position(id);
+            let was_done = board.columns().is_done(&board.get(id).state);
+            let mut issue = board.remove(id);
+
+            let mut other_board = self.registry.load(to);
+            let original_state = issue.state.clone();
+            issue.state = if was_done {
+                other_board.columns().done_state()
+            } else if other_board.columns().find(&original_state).is_none() {
+                other_board.columns().first_state()
+            } else {
+                original_state.clone()
+            };
+            if issue.state != original_state {
+                let to = issue.state.clone();
+                issue.record_activity(self.time_provider.now(), ActivityKind::Moved {
+                    from: original_state.clone(),
+                    to,
+                });
+            }
+
+            let short_id = other_board.next_short_id();
+            let original_short_id = issue.short_id.replace(short_id);
+            // Short ids refer to the issues of their own board
+            let original_blocked_by = std::mem::take(&mut issue.blocked_by);
+            let original_parent = issue.parent.take();
+
+            // The bottom of the board, so that the indexes in the history of the other board stay valid
+            let position = other_board.entities().len();
+            other_board.insert(position, issue);
+
+            board.history.add(UndoableHistoryElement::Transfer(TransferHistoryElement {
+                index,
+                board: to.clone(),
+                short_id,
+                original_short_id,
+                original_state,
+                original_blocked_by,
+                original_parent,
+            }));
+            transferred_to = Some(other_board);
+
+            Ok(board)
+        });
+
+        // Only after the issue has left its board, so that a failed save cannot lose the issue
+        if let Some(other_board) = transferred_to {
+            self.registry.save(to, &other_board);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert2::{check, let_assert};
+    use crate::adapters::presenters::nil_presenter::test::NilPresenter;
+    use crate::adapters::storages::IssueStorage;
+    use crate::adapters::storages::memory_board_registry::test::MemoryBoardRegistry;
+    use crate::adapters::storages::memory_issue_storage::test::MemoryIssueStorage;
+    use crate::adapters::time_providers::fake::FakeTodayProvider;
+    use crate::application::{Issue, State};
+    use crate::application::domain::activity::{ActivityEvent, ActivityKind};
+    use crate::application::domain::board_name::BoardName;
+    use crate::application::domain::error::DomainError;
+    use crate::application::domain::historized_board::HistorizedBoard;
+    use crate::application::domain::history::{TransferHistoryElement, UndoableHistoryElement};
+    use crate::application::issue::{Description, IssueRef, ShortId};
+    use crate::application::ports::board_registry::BoardRegistry;
+    use crate::application::usecase::test_utils::{check_no_errors, get_stored_and_presented_board};
+    use crate::application::usecase::transfer::TransferUseCase;
+
+    #[test]
+    fn test_transfer_issue_to_the_bottom_of_other_board() {
+        let mut board = HistorizedBoard::default().with_4_typical_issues();
+        board.assign_missing_short_ids();
+        let mut other_board = HistorizedBoard::default()
+            .with_columns(&["Backlog", "Review", "Finished"])
+            .with_issue(Issue {
+                description: Description::from("Buy milk"),
+                state: State::from("Backlog"),
+                ..Default::default()
+            });
+        other_board.assign_missing_short_ids();
+        let use_case = given_transfer_use_case_with(board, other_board);
+
+        use_case.execute(IssueRef::Index(2), &BoardName::try_from("personal").unwrap());
+
+        check_no_errors(&use_case);
+        let stored_board = get_stored_and_presented_board(&use_case);
+        check!(stored_board.entity_count() == 3);
+        check!(stored_board.history.last() == Some(&UndoableHistoryElement::Transfer(TransferHistoryElement {
+            index: 2,
+            board: BoardName::try_from("personal").unwrap(),
+            short_id: ShortId(2),
+            original_short_id: Some(ShortId(3)),
+            original_state: State::from("Review"),
+            original_blocked_by: vec![],
+            original_parent: None,
+        })));
+
+        let other_board = use_case.registry.load(&BoardName::try_from("personal").unwrap());
+        check!(other_board.entity_count() == 2);
+        let transferred = other_board.get_with_index(1);
+        check!(transferred.description == Description::from("Task inserted second"));
+        check!(transferred.state == State::from("Review"));
+        check!(transferred.short_id == Some(ShortId(2)));
+    }
+
+    #[test]
+    fn test_transferred_issue_goes_to_a_column_of_the_other_board() {
+        let use_case = given_transfer_use_case_with(
+            HistorizedBoard::default().with_4_typical_issues(),
+            HistorizedBoard::default().with_columns(&["Backlog", "In Progress", "Finished"]),
+        );
+
+        use_case.execute(IssueRef::Index(1), &BoardName::try_from("personal").unwrap());
+        use_case.execute(IssueRef::Index(0), &BoardName::try_from("personal").unwrap());
+
+        let other_board = use_case.registry.load(&BoardName::try_from("personal").unwrap());
+        check!(other_board.get_with_index(0).state == State::from("Finished"), "Expected done issue to stay done");
+        check!(other_board.get_with_index(1).state == State::from("Backlog"));
+        let_assert!([.., ActivityEvent { kind: ActivityKind::Moved { from, to }, time }] = other_board.get_with_index(1).activity.as_slice(),
+            "Expected the change of state to be recorded like a move");
+        check!((from.to_string(), to.to_string()) == (String::from("Open"), String::from("Backlog")));
+        check!(other_board.get_with_index(1).current_state_period().entered == *time);
+    }
+
+    #[test]
+    fn test_transfer_to_unknown_or_same_board() {
+        let use_case = given_transfer_use_case_with(
+            HistorizedBoard::default().with_4_typical_issues(),
+            HistorizedBoard::default(),
+        );
+
+        use_case.execute(IssueRef::Index(0), &BoardName::try_from("work").unwrap());
+        use_case.execute(IssueRef::Index(0), &BoardName::main());
+
+        let errors = use_case.presenter.errors_presented.borrow();
+        let_assert!([DomainError::BoardNotFound(_), DomainError::TransferToSameBoard(_)] = errors.as_slice());
+        check!(use_case.storage.load().entity_count() == 4);
+    }
+
+    fn given_transfer_use_case_with(board: HistorizedBoard<Issue>, other_board: HistorizedBoard<Issue>) -> TransferUseCase<MemoryIssueStorage, NilPresenter, MemoryBoardRegistry, FakeTodayProvider> {
+        let storage = MemoryIssueStorage::default();
+        storage.save(&board);
+
+        TransferUseCase {
+            storage,
+            registry: MemoryBoardRegistry::default().with_board("personal", other_board),
+            ..Default::default()
+        }
+    }
+}
@@ -1,23 +1,32 @@
 use internal_macros::{PresenterHolder, StorageHolder};
+use time::OffsetDateTime;
 use uuid::Uuid;
 use crate::adapters::storages::IssueStorage;
 use crate::application::board::Board;
+use crate::application::domain::board_name::BoardName;
 use crate::application::domain::error::{DomainError, DomainResult};
-use crate::application::domain::history::{DueHistoryElement, EditHistoryElement, FlushHistoryElement, PrioHistoryElement, UndoableHistoryElement};
-use crate::application::Issue;
+use crate::application::domain::activity::{ActivityEvent, ActivityKind};
+use crate::application::domain::history::{AddTemplateHistoryElement, ArchiveHistoryElements, AssignHistoryElement, BlockHistoryElement, ChecklistHistoryElement, CommentHistoryElement, DependencyHistoryElement, DueHistoryElement, EditHistoryElement, EstimateHistoryElement, FieldsHistoryElement, FlushHistoryElement, LinkHistoryElement, ParentHistoryElement, PrioHistoryElement, PriorityLevelHistoryElement, RecurHistoryElement, TagHistoryElement, TransferHistoryElement, UnarchiveHistoryElement, UndoableHistoryElement};
+use crate::application::{Issue, State};
 use crate::application::domain::historized_board::HistorizedBoard;
 use crate::application::issue::{Description, Entity};
+use crate::application::ports::board_registry::BoardRegistry;
 use crate::application::ports::presenter::Presenter;
+use crate::application::ports::time::TodayProvider;
 use crate::application::usecase::usecase::{HasStorage, HasPresenter, with_board_saved_and_presented_single_error};
 
 #[derive(Default, PresenterHolder, StorageHolder)]
-pub(crate) struct UndoUseCase<I: IssueStorage, P: Presenter> {
+pub(crate) struct UndoUseCase<I: IssueStorage, P: Presenter, R: BoardRegistry, T: TodayProvider> {
     storage: I,
     presenter: P,
+    registry: R,
+    time_provider: T,
 }
 
-impl<I: IssueStorage, P: Presenter> UndoUseCase<I, P> {
+impl<I: IssueStorage, P: Presenter, R: BoardRegistry, T: TodayProvider> UndoUseCase<I, P, R, T> {
     pub(crate) fn execute(&self) {
+        let mut transferred_back_from = None;
+
         with_board_saved_and_presented_single_error(self, |board| {
             let HistorizedBoard {
                 mut board,
@@ -27,7 +36,7 @@ impl<I: IssueStorage, P: Presenter> UndoUseCase<I, P> {
             let event_to_undo = history.last()
                 .ok_or(DomainError::EmptyHistory)?;
 
-            Self::undo_event(&mut board, event_to_undo)?;
+            transferred_back_from = self.undo_event(&mut board, event_to_undo)?;
 
             // When successful, we need to remove the history element that has been undone.
             history.pop();
@@ -38,11 +47,18 @@ impl<I: IssueStorage, P: Presenter> UndoUseCase<I, P> {
             };
 
             Ok(historized_board)
-        })
+        });
+
+        // Only after the issue is back on its board, so that a failed save cannot lose the issue
+        if let Some((other_board_name, other_board)) = transferred_back_from {
+            self.registry.save(&other_board_name, &other_board);
+        }
     }
 
     /// Undoes and event based on the history element. It does not mutate the history.
-    fn undo_event(board: &mut Board<Issue>, history: &UndoableHistoryElement) -> DomainResult<()> {
+    ///
+    /// Undoing a transfer returns the other board, which the caller saves after this board.
+    fn undo_event(&self, board: &mut Board<Issue>, history: &UndoableHistoryElement) -> DomainResult<Option<(BoardName, HistorizedBoard<Issue>)>> {
         match history {
             UndoableHistoryElement::Add => {
                 let id = board
@@ -52,6 +68,32 @@ impl<I: IssueStorage, P: Presenter> UndoUseCase<I, P> {
 
                 board.remove(id);
             },
+            UndoableHistoryElement::Parent(
+                ParentHistoryElement {
+                    index, original_parent
+                }
+            ) => {
+                let id = Self::try_get_id_or_invalid_board(board, *index)?;
+                let issue = board.get_mut(id);
+
+                issue.parent = *original_parent;
+            },
+            UndoableHistoryElement::Fields(
+                FieldsHistoryElement {
+                    index, original_fields
+                }
+            ) => {
+                let id = Self::try_get_id_or_invalid_board(board, *index)?;
+                let issue = board.get_mut(id);
+
+                issue.fields = original_fields.clone();
+            },
+            UndoableHistoryElement::AddTemplate(AddTemplateHistoryElement { count }) => {
+                for _ in 0..*count {
+                    let id = Self::try_get_id_or_invalid_board(board, 0)?;
+                    board.remove(id);
+                }
+            },
             UndoableHistoryElement::Delete(info) => {
                 if board.get_deleted_entities().len() < info.deletions.len() {
                     return Err(DomainError::InvalidBoard(format!("has {} deleted issues in file, and history suggests to restore {} number of entries",
@@ -87,13 +129,22 @@ impl<I: IssueStorage, P: Presenter> UndoUseCase<I, P> {
             },
             UndoableHistoryElement::Edit(EditHistoryElement {
                                              original_description,
+                                             original_body,
                                              index }) => {
                 let id = Self::try_get_id_or_invalid_board(board, *index)?;
                 let issue = board.get_mut(id);
 
                 issue.description = Description::from(original_description.as_str());
+                issue.body = original_body.clone();
+                issue.remove_last_activity(|kind| matches!(kind, ActivityKind::Edited));
             },
             UndoableHistoryElement::Move(info) => {
+                // The next occurrences of recurring issues were added on the top, after the moves
+                for _ in 0..info.created_occurrences {
+                    let id = Self::try_get_id_or_invalid_board(board, 0)?;
+                    board.remove(id);
+                }
+
                 for h in info.moves.iter().rev() {
                     if h.original_index != h.new_index {
                         let moved_issue_id = Self::try_get_id_or_invalid_board(board, h.new_index)?;
@@ -106,7 +157,11 @@ impl<I: IssueStorage, P: Presenter> UndoUseCase<I, P> {
                     let id = Self::try_get_id_or_invalid_board(board, h.original_index)?;
 
                     let entity = board.get_mut(id);
-                    entity.state = h.original_state;
+                    entity.state = h.original_state.clone();
+                    if h.forced {
+                        entity.remove_last_activity(|kind| matches!(kind, ActivityKind::TransitionForced { .. }));
+                    }
+                    entity.remove_last_activity(|kind| matches!(kind, ActivityKind::Moved { .. }));
                 }
             },
             UndoableHistoryElement::Flush(
@@ -139,10 +194,191 @@ impl<I: IssueStorage, P: Presenter> UndoUseCase<I, P> {
                 let issue = board.get_mut(id);
 
                 issue.due_date = previous_due.clone();
-            }
+                issue.remove_last_activity(|kind| matches!(kind, ActivityKind::DueChanged { .. }));
+            },
+            UndoableHistoryElement::Tag(
+                TagHistoryElement {
+                    index, original_tags
+                }
+            ) => {
+                let id = Self::try_get_id_or_invalid_board(board, *index)?;
+                let issue = board.get_mut(id);
+
+                issue.tags = original_tags.clone();
+            },
+            UndoableHistoryElement::Checklist(
+                ChecklistHistoryElement {
+                    index, original_checklist
+                }
+            ) => {
+                let id = Self::try_get_id_or_invalid_board(board, *index)?;
+                let issue = board.get_mut(id);
+
+                issue.checklist = original_checklist.clone();
+            },
+            UndoableHistoryElement::Dependency(
+                DependencyHistoryElement {
+                    index, original_blocked_by
+                }
+            ) => {
+                let id = Self::try_get_id_or_invalid_board(board, *index)?;
+                let issue = board.get_mut(id);
+
+                issue.blocked_by = original_blocked_by.clone();
+            },
+            UndoableHistoryElement::Comment(
+                CommentHistoryElement {
+                    index
+                }
+            ) => {
+                let id = Self::try_get_id_or_invalid_board(board, *index)?;
+                let issue = board.get_mut(id);
+
+                issue.remove_last_activity(|kind| matches!(kind, ActivityKind::Comment(_)));
+            },
+            UndoableHistoryElement::Link(
+                LinkHistoryElement {
+                    index, original_links
+                }
+            ) => {
+                let id = Self::try_get_id_or_invalid_board(board, *index)?;
+                let issue = board.get_mut(id);
+
+                issue.links = original_links.clone();
+            },
+            UndoableHistoryElement::Block(
+                BlockHistoryElement {
+                    index, original_reason
+                }
+            ) => {
+                let id = Self::try_get_id_or_invalid_board(board, *index)?;
+                let issue = board.get_mut(id);
+
+                issue.blocked_reason = original_reason.clone();
+                issue.remove_last_activity(|kind| matches!(kind, ActivityKind::Blocked(_) | ActivityKind::Unblocked));
+            },
+            UndoableHistoryElement::Recur(
+                RecurHistoryElement {
+                    index, original_recurrence
+                }
+            ) => {
+                let id = Self::try_get_id_or_invalid_board(board, *index)?;
+                let issue = board.get_mut(id);
+
+                issue.recurrence = *original_recurrence;
+            },
+            UndoableHistoryElement::Estimate(
+                EstimateHistoryElement {
+                    index, original_estimate
+                }
+            ) => {
+                let id = Self::try_get_id_or_invalid_board(board, *index)?;
+                let issue = board.get_mut(id);
+
+                issue.estimate = *original_estimate;
+            },
+            UndoableHistoryElement::Transfer(
+                TransferHistoryElement {
+                    index, board: other_board_name, short_id, original_short_id, original_state, original_blocked_by, original_parent
+                }
+            ) => {
+                if !self.registry.exists(other_board_name) {
+                    return Err(DomainError::BoardNotFound(other_board_name.clone()));
+                }
+
+                // Take the issue back from the other board, with the changes made to it there. The
+                // issue is found by its short id there, as the indexes of that board change.
+                let mut other_board = self.registry.load(other_board_name);
+                let id = other_board.find_entity_id_by_short_id(*short_id)
+                    .map_err(|_| DomainError::TransferredIssueNotFound {
+                        issue: *short_id,
+                        board: other_board_name.clone(),
+                    })?;
+                let mut issue = other_board.remove(id);
+
+                issue.short_id = *original_short_id;
+                if issue.state != *original_state {
+                    Self::record_state_restored(&mut issue, original_state, self.time_provider.now());
+                }
+                issue.blocked_by = original_blocked_by.clone();
+                issue.parent = *original_parent;
+
+                board.try_insert(*index, issue)
+                    .map_err(|e| DomainError::InvalidBoard(e.to_string()))?;
+
+                return Ok(Some((other_board_name.clone(), other_board)));
+            },
+            UndoableHistoryElement::Assign(
+                AssignHistoryElement {
+                    index, original_assignee
+                }
+            ) => {
+                let id = Self::try_get_id_or_invalid_board(board, *index)?;
+                let issue = board.get_mut(id);
+
+                issue.assignee = original_assignee.clone();
+            },
+            UndoableHistoryElement::PriorityLevel(
+                PriorityLevelHistoryElement {
+                    index, original_priority
+                }
+            ) => {
+                let id = Self::try_get_id_or_invalid_board(board, *index)?;
+                let issue = board.get_mut(id);
+
+                issue.priority = *original_priority;
+            },
+            UndoableHistoryElement::Archive(ArchiveHistoryElements { archivals }) => {
+                if board.get_archived_entities().len() < archivals.len() {
+                    return Err(DomainError::InvalidBoard(format!("has {} archived issues in file, and history suggests to restore {} number of entries",
+                                                                 board.get_archived_entities().len(),
+                                                                 archivals.len())));
+                }
+
+                // Like undoing a deletion: the issue archived last is restored first
+                let issues_to_restore = board.get_archived_entities_mut()
+                    .drain(0..archivals.len())
+                    .collect::<Vec<_>>();
+
+                for (issue, history_element) in issues_to_restore.into_iter().zip(archivals.iter().rev()) {
+                    board.try_insert(history_element.original_position_in_issues, issue)
+                        .map_err(|e| DomainError::InvalidBoard(e.to_string()))?;
+                }
+            },
+            UndoableHistoryElement::Unarchive(
+                UnarchiveHistoryElement {
+                    index, original_position_in_archive
+                }
+            ) => {
+                let id = Self::try_get_id_or_invalid_board(board, *index)?;
+
+                if *original_position_in_archive > board.get_archived_entities().len() {
+                    return Err(DomainError::InvalidBoard(format!("Index is out of range: {}", original_position_in_archive)));
+                }
+
+                let issue = board.remove(id);
+                board.get_archived_entities_mut().insert(*original_position_in_archive, issue);
+            },
         };
 
-        Ok(())
+        Ok(None)
+    }
+
+    /// Brings the issue back to `original_state`. If the issue was not moved since its state was
+    /// changed, the change is undone from its activity too, otherwise the way back is recorded.
+    fn record_state_restored(issue: &mut Issue, original_state: &State, now: OffsetDateTime) {
+        let state = std::mem::replace(&mut issue.state, original_state.clone());
+        let was_moved_since = !matches!(issue.activity.last(),
+            Some(ActivityEvent { kind: ActivityKind::Moved { from, to }, .. }) if from == original_state && *to == state);
+
+        if was_moved_since {
+            issue.record_activity(now, ActivityKind::Moved {
+                from: state,
+                to: original_state.clone(),
+            });
+        } else {
+            issue.activity.pop();
+        }
     }
 
     fn try_get_id_or_invalid_board(board: &mut Board<Issue>, index: usize) -> Result<Uuid, DomainError> {
@@ -155,17 +391,27 @@ impl<I: IssueStorage, P: Presenter> UndoUseCase<I, P> {
 
 #[cfg(test)]
 pub(crate) mod tests {
+    use std::collections::BTreeMap;
     use assert2::{check, let_assert};
     use crate::application::{Issue, State};
     use crate::adapters::presenters::nil_presenter::test::NilPresenter;
     use crate::adapters::storages::IssueStorage;
     use crate::adapters::storages::memory_issue_storage::test::MemoryIssueStorage;
-    use crate::adapters::time_providers::fake::{DEFAULT_FAKE_TODAY};
+    use crate::adapters::storages::memory_board_registry::test::MemoryBoardRegistry;
+    use crate::adapters::time_providers::fake::{FakeTodayProvider, DEFAULT_FAKE_TODAY};
+    use time::macros::{date, datetime};
+    use time::Weekday;
+    use crate::application::domain::date_parse::Recurrence;
+    use crate::application::domain::activity::{ActivityEvent, ActivityKind};
     use crate::application::domain::error::DomainError;
     use crate::application::domain::historized_board::HistorizedBoard;
-    use crate::application::domain::history::{DeleteHistoryElement, DeleteHistoryElements, EditHistoryElement, FlushHistoryElement, History, MoveHistoryElement, MoveHistoryElements, PrioHistoryElement, UndoableHistoryElement};
-    use crate::application::issue::Description;
+    use crate::application::domain::history::{AddTemplateHistoryElement, AssignHistoryElement, BlockHistoryElement, ChecklistHistoryElement, CommentHistoryElement, DeleteHistoryElement, DependencyHistoryElement, DeleteHistoryElements, EditHistoryElement, EstimateHistoryElement, FlushHistoryElement, History, LinkHistoryElement, MoveHistoryElement, MoveHistoryElements, FieldsHistoryElement, ParentHistoryElement, PrioHistoryElement, PriorityLevelHistoryElement, TagHistoryElement, TransferHistoryElement, UnarchiveHistoryElement, UndoableHistoryElement};
+    use crate::application::domain::board_name::BoardName;
+    use crate::application::ports::board_registry::BoardRegistry;
+    use crate::application::issue::{Assignee, ChecklistItem, Description, IssueRef, Link, Priority, ShortId, Tag};
     use crate::application::usecase::test_utils::{check_no_errors, get_stored_and_presented_board};
+    use crate::application::usecase::archive::archive_issues;
+    use crate::application::usecase::r#move::IssueMover;
     use crate::application::usecase::undo::UndoUseCase;
     use crate::application::usecase::usecase::HasPresenter;
 
@@ -203,6 +449,49 @@ pub(crate) mod tests {
             .has_original_history();
     }
 
+    #[test]
+    fn test_undo_archive() {
+        let mut board = HistorizedBoard::default().with_4_typical_issues();
+        let ids = vec![board.find_entity_id_by_index(1).unwrap(), board.find_entity_id_by_index(3).unwrap()];
+        archive_issues(&mut board, ids);
+        let undo_use_case = given_undo_usecase_with(board);
+
+        undo_use_case.execute();
+
+        check_no_errors(&undo_use_case);
+        let stored_board = get_stored_and_presented_board(&undo_use_case);
+        check!(stored_board.get_archived_entities().is_empty());
+        stored_board
+            .has_the_original_4_issues_in_order()
+            .has_original_history();
+    }
+
+    #[test]
+    fn test_undo_unarchive() {
+        let mut board = HistorizedBoard::default().with_4_typical_issues();
+        board.set_archived_entities(vec![
+            Issue { description: Description::from("Archived first"), state: State::from("Done"), ..Default::default() },
+            Issue { description: Description::from("Archived second"), state: State::from("Done"), ..Default::default() },
+        ]);
+        let id = board.unarchive(1);
+        let index = board.position(id);
+        board.history.add(UndoableHistoryElement::Unarchive(UnarchiveHistoryElement {
+            index,
+            original_position_in_archive: 1,
+        }));
+        let undo_use_case = given_undo_usecase_with(board);
+
+        undo_use_case.execute();
+
+        check_no_errors(&undo_use_case);
+        let stored_board = get_stored_and_presented_board(&undo_use_case);
+        let archived = stored_board.get_archived_entities().iter().map(|issue| issue.description.as_str()).collect::<Vec<_>>();
+        check!(archived == ["Archived first", "Archived second"]);
+        stored_board
+            .has_the_original_4_issues_in_order()
+            .has_original_history();
+    }
+
     #[test]
     fn test_undo_delete_multiple_issue() {
         let undo_use_case = given_undo_usecase_with(
@@ -221,6 +510,25 @@ pub(crate) mod tests {
             .has_original_history();
     }
 
+    #[test]
+    fn test_undo_delete_restores_blocker() {
+        let mut board = HistorizedBoard::default().with_4_typical_issues();
+        board.assign_missing_short_ids();
+        let id = board.find_entity_id_by_index(0).unwrap();
+        board.get_mut(id).blocked_by = vec![ShortId(3)];
+        let board = board.with_an_issue_deleted();
+        check!(board.open_blockers(id) == [], "Expected deleted issue not to block");
+
+        let undo_use_case = given_undo_usecase_with(board);
+
+        undo_use_case.execute();
+
+        check_no_errors(&undo_use_case);
+        let stored_board = get_stored_and_presented_board(&undo_use_case);
+        let id = stored_board.find_entity_id_by_index(0).unwrap();
+        check!(stored_board.open_blockers(id) == [ShortId(3)]);
+    }
+
     #[test]
     fn test_undo_on_empty_board() {
         let undo_use_case = given_undo_usecase_with( HistorizedBoard::default() );
@@ -268,6 +576,49 @@ pub(crate) mod tests {
             .has_original_history();
     }
 
+    #[test]
+    fn test_undo_forced_move() {
+        let mut board = HistorizedBoard::default()
+            .with_4_typical_issues()
+            .with_allowed_transitions("Open", &["Review"]);
+        let id = board.find_entity_id_by_index(3).unwrap();
+        let issue_mover = IssueMover {
+            time_provider: &FakeTodayProvider::default(),
+        };
+        issue_mover.move_issues(&mut board, vec![id], State::from("Done"), true).unwrap();
+        let undo_use_case = given_undo_usecase_with(board);
+
+        undo_use_case.execute();
+
+        check_no_errors(&undo_use_case);
+        let stored_board = get_stored_and_presented_board(&undo_use_case);
+        check!(stored_board.get_with_index(3).state == State::from("Open"));
+        check!(stored_board.get_with_index(3).activity == vec![], "Expected the move and its override to be undone");
+    }
+
+    #[test]
+    fn test_undo_move_after_forced_move() {
+        let mut board = HistorizedBoard::default()
+            .with_4_typical_issues()
+            .with_allowed_transitions("Open", &["Review"]);
+        let id = board.find_entity_id_by_index(3).unwrap();
+        let issue_mover = IssueMover {
+            time_provider: &FakeTodayProvider::default(),
+        };
+        issue_mover.move_issues(&mut board, vec![id], State::from("Done"), true).unwrap();
+        issue_mover.move_issues(&mut board, vec![id], State::from("Review"), true).unwrap();
+        let undo_use_case = given_undo_usecase_with(board);
+
+        undo_use_case.execute();
+
+        check_no_errors(&undo_use_case);
+        let stored_board = get_stored_and_presented_board(&undo_use_case);
+        let issue = stored_board.get(id);
+        check!(issue.state == State::from("Done"));
+        let_assert!([ActivityEvent { kind: ActivityKind::Moved { .. }, .. }, ActivityEvent { kind: ActivityKind::TransitionForced { .. }, .. }] = issue.activity.as_slice(),
+            "Expected only the move that was not forced to be undone");
+    }
+
     #[test]
     fn test_2_undos_in_sequence() {
         let undo_use_case = given_undo_usecase_with(
@@ -354,16 +705,19 @@ pub(crate) mod tests {
         let board = HistorizedBoard::new( vec![
             Issue {
                 description: Description::from("One task"),
-                state: State::Open,
+                state: State::from("Open"),
                 time_created: DEFAULT_FAKE_TODAY,
-                due_date: None
+                due_date: None,
+                ..Default::default()
             }
         ], vec![], vec![UndoableHistoryElement::Move(MoveHistoryElements{
             moves: vec![MoveHistoryElement{
                 original_index: 1, // History suggests a non-existent second task was moved
-                original_state: State::Review,
+                original_state: State::from("Review"),
                 new_index: 1,
+                forced: false,
             }],
+            created_occurrences: 0,
         })]);
 
         let undo_use_case = given_undo_usecase_with(board);
@@ -379,36 +733,357 @@ pub(crate) mod tests {
 
     }
 
+    #[test]
+    fn test_undo_estimate() {
+        let use_case = given_undo_usecase_with(HistorizedBoard::new(vec![
+            Issue { description: Description::from("An estimated issue"), estimate: Some(8), ..Default::default() }
+        ], vec![], vec![
+            UndoableHistoryElement::Estimate(EstimateHistoryElement{
+                index: 0,
+                original_estimate: Some(5),
+            })
+        ]));
+
+        use_case.execute();
+
+        let stored_board = get_stored_and_presented_board(&use_case);
+        check!(stored_board.get_with_index(0).estimate == Some(5));
+        check!(stored_board.history.stack == []);
+    }
+
+    #[test]
+    fn test_undo_link() {
+        let use_case = given_undo_usecase_with(HistorizedBoard::new(vec![
+            Issue { description: Description::from("A linked issue"), links: vec![Link::new("https://example.com/pr/42", Some("PR"))], ..Default::default() }
+        ], vec![], vec![
+            UndoableHistoryElement::Link(LinkHistoryElement {
+                index: 0,
+                original_links: vec![],
+            })
+        ]));
+
+        use_case.execute();
+
+        let stored_board = get_stored_and_presented_board(&use_case);
+        check!(stored_board.get_with_index(0).links == vec![]);
+        check!(stored_board.history.stack == []);
+    }
+
+    #[test]
+    fn test_undo_add_from_template() {
+        let use_case = given_undo_usecase_with(HistorizedBoard::new(vec![
+            Issue { description: Description::from("Release v1.4"), ..Default::default() },
+            Issue { description: Description::from("Announce v1.4"), ..Default::default() },
+            Issue { description: Description::from("An earlier issue"), ..Default::default() },
+        ], vec![], vec![
+            UndoableHistoryElement::AddTemplate(AddTemplateHistoryElement { count: 2 })
+        ]));
+
+        use_case.execute();
+
+        let stored_board = get_stored_and_presented_board(&use_case);
+        stored_board.assert_issue_count(1);
+        check!(stored_board.get_with_index(0).description == Description::from("An earlier issue"));
+        check!(stored_board.history.stack == []);
+    }
+
+    #[test]
+    fn test_undo_block() {
+        let use_case = given_undo_usecase_with(HistorizedBoard::new(vec![
+            Issue {
+                description: Description::from("A blocked issue"),
+                blocked_reason: Some(String::from("waiting for API keys")),
+                activity: vec![ActivityEvent::new(datetime!(2025-02-22 10:00 UTC), ActivityKind::Blocked(String::from("waiting for API keys")))],
+                ..Default::default()
+            }
+        ], vec![], vec![
+            UndoableHistoryElement::Block(BlockHistoryElement {
+                index: 0,
+                original_reason: None,
+            })
+        ]));
+
+        use_case.execute();
+
+        let stored_board = get_stored_and_presented_board(&use_case);
+        check!(stored_board.get_with_index(0).blocked_reason == None);
+        check!(stored_board.get_with_index(0).activity == vec![]);
+        check!(stored_board.history.stack == []);
+    }
+
+    #[test]
+    fn test_undo_parent() {
+        let use_case = given_undo_usecase_with(HistorizedBoard::new(vec![
+            Issue { description: Description::from("A moved child"), parent: Some(ShortId(5)), ..Default::default() }
+        ], vec![], vec![
+            UndoableHistoryElement::Parent(ParentHistoryElement {
+                index: 0,
+                original_parent: Some(ShortId(3)),
+            })
+        ]));
+
+        use_case.execute();
+
+        let stored_board = get_stored_and_presented_board(&use_case);
+        check!(stored_board.get_with_index(0).parent == Some(ShortId(3)));
+        check!(stored_board.history.stack == []);
+    }
+
+    #[test]
+    fn test_undo_fields() {
+        let use_case = given_undo_usecase_with(HistorizedBoard::new(vec![
+            Issue {
+                description: Description::from("An issue of a customer"),
+                fields: BTreeMap::from([(String::from("customer"), String::from("globex"))]),
+                ..Default::default()
+            }
+        ], vec![], vec![
+            UndoableHistoryElement::Fields(FieldsHistoryElement {
+                index: 0,
+                original_fields: BTreeMap::from([(String::from("customer"), String::from("acme"))]),
+            })
+        ]));
+
+        use_case.execute();
+
+        let stored_board = get_stored_and_presented_board(&use_case);
+        check!(stored_board.get_with_index(0).fields.get("customer").map(String::as_str) == Some("acme"));
+        check!(stored_board.history.stack == []);
+    }
+
+    #[test]
+    fn test_undo_assign() {
+        let use_case = given_undo_usecase_with(HistorizedBoard::new(vec![
+            Issue { description: Description::from("A reassigned issue"), assignee: Some(Assignee::from("bob")), ..Default::default() }
+        ], vec![], vec![
+            UndoableHistoryElement::Assign(AssignHistoryElement {
+                index: 0,
+                original_assignee: Some(Assignee::from("alice")),
+            })
+        ]));
+
+        use_case.execute();
+
+        let stored_board = get_stored_and_presented_board(&use_case);
+        check!(stored_board.get_with_index(0).assignee == Some(Assignee::from("alice")));
+        check!(stored_board.history.stack == []);
+    }
+
+    #[test]
+    fn test_undo_priority_level() {
+        let use_case = given_undo_usecase_with(HistorizedBoard::new(vec![
+            Issue { description: Description::from("A raised issue"), priority: Priority::Critical, ..Default::default() }
+        ], vec![], vec![
+            UndoableHistoryElement::PriorityLevel(PriorityLevelHistoryElement {
+                index: 0,
+                original_priority: Priority::Low,
+            })
+        ]));
+
+        use_case.execute();
+
+        let stored_board = get_stored_and_presented_board(&use_case);
+        check!(stored_board.get_with_index(0).priority == Priority::Low);
+        check!(stored_board.history.stack == []);
+    }
+
+    #[test]
+    fn test_undo_transfer() {
+        let mut use_case = given_undo_usecase_with(HistorizedBoard::new(vec![
+            Issue { description: Description::from("Stayed on the board"), short_id: Some(ShortId(1)), ..Default::default() },
+        ], vec![], vec![
+            UndoableHistoryElement::Transfer(TransferHistoryElement {
+                index: 0,
+                board: BoardName::try_from("personal").unwrap(),
+                short_id: ShortId(7),
+                original_short_id: Some(ShortId(2)),
+                original_state: State::from("Review"),
+                original_blocked_by: vec![ShortId(1)],
+                original_parent: Some(ShortId(1)),
+            })
+        ]));
+        use_case.registry = MemoryBoardRegistry::default().with_board("personal", HistorizedBoard::new(vec![
+            Issue { description: Description::from("Buy milk"), short_id: Some(ShortId(1)), ..Default::default() },
+            Issue { description: Description::from("Transferred"), short_id: Some(ShortId(7)), state: State::from("Backlog"), ..Default::default() },
+        ], vec![], vec![]));
+
+        use_case.execute();
+
+        check_no_errors(&use_case);
+        let stored_board = get_stored_and_presented_board(&use_case);
+        let issue = stored_board.get_with_index(0);
+        check!(issue.description == Description::from("Transferred"));
+        check!(issue.short_id == Some(ShortId(2)));
+        check!(issue.state == State::from("Review"));
+        check!(issue.blocked_by == vec![ShortId(1)]);
+        check!(issue.parent == Some(ShortId(1)));
+        check!(issue.activity == vec![ActivityEvent::new(datetime!(2025-02-22 10:00 UTC), ActivityKind::Moved {
+            from: State::from("Backlog"),
+            to: State::from("Review"),
+        })], "Expected the way back to the original state to be recorded");
+        check!(stored_board.history.stack == []);
+
+        let other_board = use_case.registry.load(&BoardName::try_from("personal").unwrap());
+        check!(other_board.entity_count() == 1);
+        check!(other_board.get_with_index(0).description == Description::from("Buy milk"));
+    }
+
+    #[test]
+    fn test_undo_transfer_undoes_the_change_of_state() {
+        let mut use_case = given_undo_usecase_with(HistorizedBoard::new(vec![], vec![], vec![
+            UndoableHistoryElement::Transfer(TransferHistoryElement {
+                index: 0,
+                board: BoardName::try_from("personal").unwrap(),
+                short_id: ShortId(7),
+                original_short_id: Some(ShortId(2)),
+                original_state: State::from("Review"),
+                original_blocked_by: vec![],
+                original_parent: None,
+            })
+        ]));
+        use_case.registry = MemoryBoardRegistry::default().with_board("personal", HistorizedBoard::new(vec![
+            Issue {
+                description: Description::from("Transferred"),
+                short_id: Some(ShortId(7)),
+                state: State::from("Backlog"),
+                activity: vec![ActivityEvent::new(datetime!(2025-02-21 09:00 UTC), ActivityKind::Moved { from: State::from("Review"), to: State::from("Backlog") })],
+                ..Default::default()
+            },
+        ], vec![], vec![]));
+
+        use_case.execute();
+
+        check_no_errors(&use_case);
+        let stored_board = get_stored_and_presented_board(&use_case);
+        check!(stored_board.get_with_index(0).state == State::from("Review"));
+        check!(stored_board.get_with_index(0).activity == vec![]);
+    }
+
+    #[test]
+    fn test_undo_transfer_of_issue_no_longer_on_the_other_board() {
+        let mut use_case = given_undo_usecase_with(HistorizedBoard::new(vec![], vec![], vec![
+            UndoableHistoryElement::Transfer(TransferHistoryElement {
+                index: 0,
+                board: BoardName::try_from("personal").unwrap(),
+                short_id: ShortId(7),
+                original_short_id: Some(ShortId(2)),
+                original_state: State::from("Open"),
+                original_blocked_by: vec![],
+                original_parent: None,
+            })
+        ]));
+        use_case.registry = MemoryBoardRegistry::default().with_board("personal", HistorizedBoard::new(vec![
+            Issue { description: Description::from("Buy milk"), short_id: Some(ShortId(1)), ..Default::default() },
+        ], vec![], vec![]));
+
+        use_case.execute();
+
+        let errors = use_case.presenter.errors_presented.borrow();
+        let_assert!([DomainError::TransferredIssueNotFound { issue: ShortId(7), board }] = errors.as_slice());
+        check!(*board == BoardName::try_from("personal").unwrap());
+        check!(use_case.storage.load().history.stack.len() == 1);
+        check!(use_case.registry.load(&BoardName::try_from("personal").unwrap()).entity_count() == 1);
+    }
+
+    #[test]
+    fn test_undo_transfer_to_deleted_board() {
+        let use_case = given_undo_usecase_with(HistorizedBoard::new(vec![], vec![], vec![
+            UndoableHistoryElement::Transfer(TransferHistoryElement {
+                index: 0,
+                board: BoardName::try_from("personal").unwrap(),
+                short_id: ShortId(7),
+                original_short_id: Some(ShortId(2)),
+                original_state: State::from("Open"),
+                original_blocked_by: vec![],
+                original_parent: None,
+            })
+        ]));
+
+        use_case.execute();
+
+        let errors = use_case.presenter.errors_presented.borrow();
+        let_assert!([DomainError::BoardNotFound(_)] = errors.as_slice());
+        check!(use_case.storage.load().history.stack.len() == 1);
+    }
+
+    #[test]
+    fn test_undo_move_of_recurring_issue() {
+        // Given: the weekly report was moved from Review to done, and its next occurrence was added on the top
+        let board = HistorizedBoard::new(vec![
+            Issue {
+                description: Description::from("Weekly report"),
+                state: State::from("Open"),
+                time_created: DEFAULT_FAKE_TODAY,
+                due_date: Some(date!(2025-02-24)),
+                recurrence: Some(Recurrence::Weekday(Weekday::Monday)),
+                ..Default::default()
+            },
+            Issue {
+                description: Description::from("Weekly report"),
+                state: State::from("Done"),
+                time_created: DEFAULT_FAKE_TODAY,
+                due_date: Some(date!(2025-02-17)),
+                recurrence: Some(Recurrence::Weekday(Weekday::Monday)),
+                ..Default::default()
+            },
+        ], vec![], vec![UndoableHistoryElement::Move(MoveHistoryElements{
+            moves: vec![MoveHistoryElement{
+                original_index: 0,
+                original_state: State::from("Review"),
+                new_index: 0,
+                forced: false,
+            }],
+            created_occurrences: 1,
+        })]);
+        let undo_use_case = given_undo_usecase_with(board);
+
+        // When
+        undo_use_case.execute();
+
+        // Then
+        check_no_errors(&undo_use_case);
+        let stored_board = get_stored_and_presented_board(&undo_use_case);
+        check!(stored_board.entities().len() == 1);
+        let issue = stored_board.get_with_index(0);
+        check!(issue.state == State::from("Review"));
+        check!(issue.due_date == Some(date!(2025-02-17)));
+        check!(stored_board.history.stack == []);
+    }
+
     /// Testing undoing a command of complicated moves, where multiple issues are moved to done,
     /// which causes priority changes.
     #[test]
     fn test_multi_move_with_prio_change_undo() {
         // Given
         let entities = [
-            (State::Done, "I'm doing it now, A"),
-            (State::Done, "I'm doing it now, B"),
-            (State::Done, "I finished this first"),
-            (State::Open, "Lazy to do"),
+            (State::from("Done"), "I'm doing it now, A"),
+            (State::from("Done"), "I'm doing it now, B"),
+            (State::from("Done"), "I finished this first"),
+            (State::from("Open"), "Lazy to do"),
         ].into_iter().map(|(state, description)| Issue {
             description: Description::from(description),
             state,
             time_created: DEFAULT_FAKE_TODAY,
             due_date: None,
+            ..Default::default()
         }).collect();
 
         let history = vec![UndoableHistoryElement::Move(MoveHistoryElements {
             moves: vec![
                 MoveHistoryElement {
-                    original_state: State::Open,
+                    original_state: State::from("Open"),
                     original_index: 3,
                     new_index: 0,
+                    forced: false,
                 },
                 MoveHistoryElement {
-                    original_state: State::Open,
+                    original_state: State::from("Open"),
                     original_index: 3,
                     new_index: 0,
+                    forced: false,
                 },
-            ]
+            ],
+            created_occurrences: 0,
         })];
 
         let undo_use_case = given_undo_usecase_with(
@@ -423,10 +1098,10 @@ pub(crate) mod tests {
         let stored_board = get_stored_and_presented_board(&undo_use_case);
 
         for (index, expected_description, expected_state) in [
-                (0, "I finished this first", State::Done),
-                (1, "Lazy to do", State::Open),
-                (2, "I'm doing it now, A", State::Open),
-                (3, "I'm doing it now, B", State::Open) ] {
+                (0, "I finished this first", State::from("Done")),
+                (1, "Lazy to do", State::from("Open")),
+                (2, "I'm doing it now, A", State::from("Open")),
+                (3, "I'm doing it now, B", State::from("Open")) ] {
             let issue = stored_board.get(stored_board.find_entity_id_by_index(index).unwrap());
             check!(issue.description.as_str() == expected_description);
             check!(issue.state == expected_state);
@@ -482,9 +1157,10 @@ pub(crate) mod tests {
     fn test_undo_priority_invalid_original_index() {
         // given
         let use_case = given_undo_usecase_with(HistorizedBoard::new(vec![
-            Issue { description: Description::from("An issue"), state: State::Open,
+            Issue { description: Description::from("An issue"), state: State::from("Open"),
                 time_created: DEFAULT_FAKE_TODAY,
                 due_date: None,
+                ..Default::default()
             }
         ], vec![], vec![
             UndoableHistoryElement::Prio(PrioHistoryElement{ original_index: 1, new_index: 0 })
@@ -502,9 +1178,10 @@ pub(crate) mod tests {
     fn test_undo_prority_invalid_new_index() {
         // given
         let use_case = given_undo_usecase_with(HistorizedBoard::new(vec![
-            Issue { description: Description::from("An issue"), state: State::Open,
+            Issue { description: Description::from("An issue"), state: State::from("Open"),
                 time_created: DEFAULT_FAKE_TODAY,
                 due_date: None,
+                ..Default::default()
             }
         ], vec![], vec![
             UndoableHistoryElement::Prio(PrioHistoryElement{ original_index: 0, new_index: 1 })
@@ -522,14 +1199,16 @@ pub(crate) mod tests {
     fn test_undo_delete_invalid_original_index() {
         // given
         let use_case = given_undo_usecase_with(HistorizedBoard::new(vec![
-            Issue { description: Description::from("An issue"), state: State::Open,
+            Issue { description: Description::from("An issue"), state: State::from("Open"),
                 time_created: DEFAULT_FAKE_TODAY,
                 due_date: None,
+                ..Default::default()
             }
         ], vec![
-            Issue { description: Description::from("A deleted issue"), state: State::Review,
+            Issue { description: Description::from("A deleted issue"), state: State::from("Review"),
                 time_created: DEFAULT_FAKE_TODAY,
                 due_date: None,
+                ..Default::default()
             }
         ], vec![
             UndoableHistoryElement::Delete(DeleteHistoryElements{
@@ -549,17 +1228,20 @@ pub(crate) mod tests {
     fn test_undo_move_invalid_new_index() {
         // given
         let use_case = given_undo_usecase_with(HistorizedBoard::new(vec![
-            Issue { description: Description::from("An issue"), state: State::Done,
+            Issue { description: Description::from("An issue"), state: State::from("Done"),
                 time_created: DEFAULT_FAKE_TODAY,
                 due_date: None,
+                ..Default::default()
             }
         ], vec![], vec![
             UndoableHistoryElement::Move(MoveHistoryElements{
                 moves: vec![MoveHistoryElement{
                     original_index: 0,
-                    original_state: State::Open,
+                    original_state: State::from("Open"),
                     new_index: 123,
+                    forced: false,
                 }],
+                created_occurrences: 0,
             })
         ]));
 
@@ -574,13 +1256,15 @@ pub(crate) mod tests {
     #[test]
     fn test_undo_flush_not_enough_deleted_items() {
         let use_case = given_undo_usecase_with(HistorizedBoard::new(vec![], vec![
-            Issue { description: Description::from("First deleted issue"), state: State::Open,
+            Issue { description: Description::from("First deleted issue"), state: State::from("Open"),
                 time_created: DEFAULT_FAKE_TODAY,
                 due_date: None,
+                ..Default::default()
             },
-            Issue { description: Description::from("Second deleted issue"), state: State::Review,
+            Issue { description: Description::from("Second deleted issue"), state: State::from("Review"),
                 time_created: DEFAULT_FAKE_TODAY,
                 due_date: None,
+                ..Default::default()
             },
         ], vec![
             UndoableHistoryElement::Flush(FlushHistoryElement{
@@ -599,26 +1283,31 @@ pub(crate) mod tests {
     #[test]
     fn test_undo_flush() {
         let use_case = given_undo_usecase_with(HistorizedBoard::new(vec![
-            Issue { description: Description::from("An issue"), state: State::Open,
+            Issue { description: Description::from("An issue"), state: State::from("Open"),
                 time_created: DEFAULT_FAKE_TODAY,
                 due_date: None,
+                ..Default::default()
             }
         ], vec![
-            Issue { description: Description::from("First deleted issue"), state: State::Open,
+            Issue { description: Description::from("First deleted issue"), state: State::from("Open"),
                 time_created: DEFAULT_FAKE_TODAY,
                 due_date: None,
+                ..Default::default()
             },
-            Issue { description: Description::from("Second deleted issue"), state: State::Review,
+            Issue { description: Description::from("Second deleted issue"), state: State::from("Review"),
                 time_created: DEFAULT_FAKE_TODAY,
                 due_date: None,
+                ..Default::default()
             },
-            Issue { description: Description::from("Third deleted issue"), state: State::Open,
+            Issue { description: Description::from("Third deleted issue"), state: State::from("Open"),
                 time_created: DEFAULT_FAKE_TODAY,
                 due_date: None,
+                ..Default::default()
             },
-            Issue { description: Description::from("Fourth deleted issue"), state: State::Done,
+            Issue { description: Description::from("Fourth deleted issue"), state: State::from("Done"),
                 time_created: DEFAULT_FAKE_TODAY,
                 due_date: None,
+                ..Default::default()
             },
         ], vec![
             UndoableHistoryElement::Flush(FlushHistoryElement{
@@ -642,13 +1331,77 @@ pub(crate) mod tests {
         }
     }
 
+    #[test]
+    fn test_undo_tag() {
+        let use_case = given_undo_usecase_with(HistorizedBoard::new(vec![
+            Issue { description: Description::from("A tagged issue"), tags: vec![Tag::from("frontend")], ..Default::default() }
+        ], vec![], vec![
+            UndoableHistoryElement::Tag(TagHistoryElement{
+                index: 0,
+                original_tags: vec![Tag::from("backend"), Tag::from("urgent")],
+            })
+        ]));
+
+        // when
+        use_case.execute();
+
+        check_no_errors(&use_case);
+        let stored_board = get_stored_and_presented_board(&use_case);
+
+        check!(stored_board.get_with_index(0).tags == [Tag::from("backend"), Tag::from("urgent")]);
+        check!(stored_board.history.stack == []);
+    }
+
+    #[test]
+    fn test_undo_checklist() {
+        let use_case = given_undo_usecase_with(HistorizedBoard::new(vec![
+            Issue { description: Description::from("An issue with steps"), checklist: vec![ChecklistItem::new("write tests")], ..Default::default() }
+        ], vec![], vec![
+            UndoableHistoryElement::Checklist(ChecklistHistoryElement{
+                index: 0,
+                original_checklist: vec![],
+            })
+        ]));
+
+        // when
+        use_case.execute();
+
+        check_no_errors(&use_case);
+        let stored_board = get_stored_and_presented_board(&use_case);
+
+        check!(stored_board.get_with_index(0).checklist == []);
+        check!(stored_board.history.stack == []);
+    }
+
+    #[test]
+    fn test_undo_dependency() {
+        let use_case = given_undo_usecase_with(HistorizedBoard::new(vec![
+            Issue { description: Description::from("A blocked issue"), blocked_by: vec![ShortId(2), ShortId(3)], ..Default::default() }
+        ], vec![], vec![
+            UndoableHistoryElement::Dependency(DependencyHistoryElement{
+                index: 0,
+                original_blocked_by: vec![ShortId(2)],
+            })
+        ]));
+
+        // when
+        use_case.execute();
+
+        check_no_errors(&use_case);
+        let stored_board = get_stored_and_presented_board(&use_case);
+
+        check!(stored_board.get_with_index(0).blocked_by == [ShortId(2)]);
+        check!(stored_board.history.stack == []);
+    }
+
     #[test]
     fn test_undo_edit() {
         let use_case = given_undo_usecase_with(HistorizedBoard::new(vec![
-            Issue { description: Description::from("An edited issue"), state: State::Open, time_created: DEFAULT_FAKE_TODAY, due_date: None }
+            Issue { description: Description::from("An edited issue"), state: State::from("Open"), time_created: DEFAULT_FAKE_TODAY, due_date: None, body: Some(String::from("Edited notes")), ..Default::default() }
         ], vec![], vec![
             UndoableHistoryElement::Edit(EditHistoryElement{
                 original_description: String::from("An issue"),
+                original_body: None,
                 index: 0,
             })
         ]));
@@ -658,12 +1411,39 @@ pub(crate) mod tests {
 
         let stored_board = get_stored_and_presented_board(&use_case);
 
-        let description = &stored_board.entities().first().expect("Expected entity to be present").description;
-        check!(description == &Description::from("An issue"));
+        let issue = stored_board.entities().first().expect("Expected entity to be present");
+        check!(issue.description == Description::from("An issue"));
+        check!(issue.body == None);
 
         check!(stored_board.history.stack == []);
     }
 
+    #[test]
+    fn test_undo_removes_activity_event() {
+        let use_case = given_undo_usecase_with(HistorizedBoard::new(vec![
+            Issue {
+                description: Description::from("An issue"),
+                activity: vec![
+                    ActivityEvent::new(datetime!(2025-02-22 10:00 UTC), ActivityKind::Created),
+                    ActivityEvent::new(datetime!(2025-02-22 11:00 UTC), ActivityKind::Comment(String::from("waiting on infra"))),
+                ],
+                ..Default::default()
+            }
+        ], vec![], vec![
+            UndoableHistoryElement::Comment(CommentHistoryElement{
+                index: 0,
+            })
+        ]));
+
+        use_case.execute();
+
+        let stored_board = get_stored_and_presented_board(&use_case);
+        check!(stored_board.get_with_index(0).activity == vec![
+            ActivityEvent::new(datetime!(2025-02-22 10:00 UTC), ActivityKind::Created),
+        ]);
+        check!(stored_board.history.stack == []);
+    }
+
     fn check_priorities_unswapped(stored_board: &HistorizedBoard<Issue>) {
         for (index, expected_description) in [(0, "This was originally first"), (1, "This was originally second")] {
             let actual_description = stored_board.get(stored_board.find_entity_id_by_index(index).expect("entity to exist")).description.as_str();
@@ -674,9 +1454,10 @@ pub(crate) mod tests {
     fn given_swapped_entities() -> Vec<Issue> {
         ["This was originally second", "This was originally first"].map(|d| Issue {
             description: Description::from(d),
-            state: State::Open,
+            state: State::from("Open"),
             time_created: DEFAULT_FAKE_TODAY,
             due_date: None,
+            ..Default::default()
         }).to_vec()
     }
 
@@ -685,9 +1466,10 @@ pub(crate) mod tests {
             self.append_entity(
                 Issue{
                     description: Description::from("Additional Issue"),
-                    state: State::Open,
+                    state: State::from("Open"),
                     time_created: DEFAULT_FAKE_TODAY,
                     due_date: None,
+                    ..Default::default()
                 }
             );
             self.history.add(UndoableHistoryElement::Add);
@@ -711,7 +1493,7 @@ pub(crate) mod tests {
         }
 
         fn with_1_0_2_issues_deleted(mut self) -> Self {
-            self.find_entities_by_indices(&[1, 0, 2])
+            self.find_entities_by_refs(&[IssueRef::Index(1), IssueRef::Index(0), IssueRef::Index(2)])
                 .unwrap()
                 .into_iter()
                 .for_each(|id| self.mark_as_deleted(id));
@@ -737,15 +1519,17 @@ pub(crate) mod tests {
 
         fn with_1_moved_from_done_to_open(mut self) -> Self {
             let id = self.find_entity_id_by_index(1).unwrap();
-            self.get_mut(id).state = State::Open;
+            self.get_mut(id).state = State::from("Open");
             self.history.add(UndoableHistoryElement::Move(MoveHistoryElements{
                 moves: vec![
                     MoveHistoryElement {
                         new_index: 1,
+                        forced: false,
                         original_index: 1,
-                        original_state: State::Done,
+                        original_state: State::from("Done"),
                     }
-                ]
+                ],
+                created_occurrences: 0,
             }));
 
             self
@@ -753,16 +1537,18 @@ pub(crate) mod tests {
 
         fn with_most_priority_issue_moved_to_review(mut self) -> Self {
             let id = self.find_entity_id_by_index(0);
-            self.get_mut(id.unwrap()).state = State::Review;
+            self.get_mut(id.unwrap()).state = State::from("Review");
 
             self.history.add(UndoableHistoryElement::Move(MoveHistoryElements{
                 moves: vec![
                     MoveHistoryElement {
-                        original_state: State::Open,
+                        original_state: State::from("Open"),
                         new_index: 0,
+                        forced: false,
                         original_index: 0,
                     }
-                ]
+                ],
+                created_occurrences: 0,
             }));
 
             self
@@ -794,16 +1580,18 @@ pub(crate) mod tests {
 
         fn with_issue_moved_to_done(mut self) -> Self {
             let id = self.find_entity_id_by_index(2).unwrap();
-            self.get_mut(id).state = State::Done;
+            self.get_mut(id).state = State::from("Done");
             self.prio_top_in_category(id);
             self.history.add(UndoableHistoryElement::Move(MoveHistoryElements{
                 moves: vec![
                     MoveHistoryElement{
                         original_index: 2,
                         new_index: 1,
-                        original_state: State::Review,
+                        forced: false,
+                        original_state: State::from("Review"),
                     }
-                ]
+                ],
+                created_occurrences: 0,
             }));
 
             self
@@ -817,7 +1605,7 @@ pub(crate) mod tests {
         fn has_additional_issue_added_with_state_open(&self) -> &Self {
             let issue = self.get(self.find_entity_id_by_index(0).expect("Expected to have an issue"));
             assert_eq!(issue.description, Description::from("Additional Issue"), "Expected Additional Issue in first place");
-            assert_eq!(issue.state, State::Open, "Expected issue to be in Open state");
+            assert_eq!(issue.state, State::from("Open"), "Expected issue to be in Open state");
 
             self
         }
@@ -830,11 +1618,11 @@ pub(crate) mod tests {
 
     }
 
-    fn then_board_for(undo: &UndoUseCase<MemoryIssueStorage, NilPresenter>) -> HistorizedBoard<Issue> {
+    fn then_board_for(undo: &UndoUseCase<MemoryIssueStorage, NilPresenter, MemoryBoardRegistry, FakeTodayProvider>) -> HistorizedBoard<Issue> {
         undo.storage.load()
     }
 
-    fn given_undo_usecase_with(board: HistorizedBoard<Issue>) -> UndoUseCase<MemoryIssueStorage, NilPresenter> {
+    fn given_undo_usecase_with(board: HistorizedBoard<Issue>) -> UndoUseCase<MemoryIssueStorage, NilPresenter, MemoryBoardRegistry, FakeTodayProvider> {
         let storage = MemoryIssueStorage::default();
         storage.save(&board);
 